@@ -34,6 +34,8 @@ pub(crate) fn unpack_table_ref(table_ref: &TableRef) -> String {
         | TableRef::DatabaseSchemaTableAlias(_, _, tbl, _)
         | TableRef::SubQuery(_, tbl)
         | TableRef::ValuesList(_, tbl)
+        | TableRef::ValuesExprList(_, tbl)
         | TableRef::FunctionCall(_, tbl) => tbl.to_string(),
+        TableRef::Only(table_ref) => unpack_table_ref(table_ref),
     }
 }
@@ -66,6 +66,42 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
         })
         .collect::<Result<_, _>>()?;
 
+    let mut created_timestamp: Vec<(Ident, Type)> = Vec::new();
+    let mut updated_timestamp: Vec<(Ident, Type)> = Vec::new();
+    for field in fields.clone() {
+        for attr in field.attrs.iter().filter(|attr| attr.path().is_ident("pgorm")) {
+            attr.parse_nested_meta(|meta| {
+                if meta.path.is_ident("created_timestamp") {
+                    created_timestamp.push((format_field_ident(field.clone()), field.ty.clone()));
+                } else if meta.path.is_ident("updated_timestamp") {
+                    updated_timestamp.push((format_field_ident(field.clone()), field.ty.clone()));
+                } else {
+                    // Reads the value expression to advance the parse stream.
+                    let _: Option<Expr> = meta.value().and_then(|v| v.parse()).ok();
+                }
+
+                Ok(())
+            })?;
+        }
+    }
+
+    let set_auto_timestamps = if created_timestamp.is_empty() && updated_timestamp.is_empty() {
+        quote!()
+    } else {
+        let (created_field, created_ty): (Vec<Ident>, Vec<Type>) =
+            created_timestamp.into_iter().unzip();
+        let (updated_field, updated_ty): (Vec<Ident>, Vec<Type>) =
+            updated_timestamp.into_iter().unzip();
+        quote!(
+            fn set_auto_timestamps(&mut self, insert: bool) {
+                if insert {
+                    #(self.#created_field = pgorm::ActiveValue::set(<#created_ty as pgorm::AutoTimestamp>::now());)*
+                }
+                #(self.#updated_field = pgorm::ActiveValue::set(<#updated_ty as pgorm::AutoTimestamp>::now());)*
+            }
+        )
+    };
+
     let ty: Vec<Type> = fields.into_iter().map(|Field { ty, .. }| ty).collect();
 
     Ok(quote!(
@@ -157,6 +193,8 @@ fn derive_active_model(all_fields: IntoIter<Field>) -> syn::Result<TokenStream>
                     _ => panic!("This ActiveModel does not have this field"),
                 }
             }
+
+            #set_auto_timestamps
         }
     ))
 }
@@ -1,3 +1,4 @@
+use super::case_style::{CaseStyle, CaseStyleHelpers};
 use heck::ToSnakeCase;
 use proc_macro2::{self, TokenStream};
 use quote::{quote, quote_spanned};
@@ -20,6 +21,10 @@ fn impl_iden_for_unit_struct(
     let prepare = if must_be_valid_iden(new_iden) {
         quote! {
             fn prepare(&self, s: &mut dyn ::std::fmt::Write, q: pgorm::pgorm_query::Quote) {
+                if q.leaves_unquoted(#new_iden) {
+                    self.unquoted(s);
+                    return;
+                }
                 write!(s, "{}", q.left()).unwrap();
                 self.unquoted(s);
                 write!(s, "{}", q.right()).unwrap();
@@ -42,9 +47,11 @@ fn impl_iden_for_unit_struct(
 fn impl_iden_for_enum(
     ident: &proc_macro2::Ident,
     variants: Punctuated<Variant, syn::token::Comma>,
+    rename_all: Option<CaseStyle>,
 ) -> proc_macro2::TokenStream {
     let variants = variants.iter();
     let mut all_valid = true;
+    let mut name_pair: Vec<TokenStream> = Vec::new();
 
     let match_pair: Vec<TokenStream> = variants
         .map(|v| {
@@ -54,7 +61,11 @@ fn impl_iden_for_enum(
             } else {
                 var_ident
             };
-            let mut var_name = var_name.to_string().to_snake_case();
+            let mut var_name = match rename_all {
+                Some(case_style) => var_name.convert_case(Some(case_style)),
+                None => var_name.to_string().to_snake_case(),
+            };
+            all_valid &= must_be_valid_iden(var_name.as_str());
             v.attrs
                 .iter()
                 .filter(|attr| attr.path().is_ident("pgorm"))
@@ -74,6 +85,7 @@ fn impl_iden_for_enum(
                     })
                 })
                 .expect("something something");
+            name_pair.push(quote! { Self::#var_ident => #var_name });
             quote! { Self::#var_ident => write!(s, "{}", #var_name).unwrap() }
         })
         .collect();
@@ -83,6 +95,13 @@ fn impl_iden_for_enum(
     let prepare = if all_valid {
         quote! {
             fn prepare(&self, s: &mut dyn ::std::fmt::Write, q: pgorm::pgorm_query::Quote) {
+                let name = match self {
+                    #(#name_pair),*
+                };
+                if q.leaves_unquoted(name) {
+                    write!(s, "{}", name).unwrap();
+                    return;
+                }
                 write!(s, "{}", q.left()).unwrap();
                 self.unquoted(s);
                 write!(s, "{}", q.right()).unwrap();
@@ -108,7 +127,8 @@ fn impl_iden_for_enum(
 pub fn expand_derive_iden(input: DeriveInput) -> syn::Result<TokenStream> {
     let DeriveInput { ident, data, .. } = input;
 
-    let mut new_iden: TokenStream = ident.to_string().to_snake_case().parse().unwrap();
+    let mut new_iden: Option<String> = None;
+    let mut rename_all: Option<CaseStyle> = None;
     input
         .attrs
         .iter()
@@ -117,7 +137,9 @@ pub fn expand_derive_iden(input: DeriveInput) -> syn::Result<TokenStream> {
             attr.parse_nested_meta(|meta| {
                 if meta.path.is_ident("iden") {
                     let litstr: LitStr = meta.value()?.parse()?;
-                    new_iden = syn::parse_str::<TokenStream>(&litstr.value())?;
+                    new_iden = Some(litstr.value());
+                } else if meta.path.is_ident("rename_all") {
+                    rename_all = Some((&meta).try_into()?);
                 } else {
                     // Reads the value expression to advance the parse stream.
                     // Some parameters do not have any value,
@@ -134,16 +156,19 @@ pub fn expand_derive_iden(input: DeriveInput) -> syn::Result<TokenStream> {
             if variants.is_empty() {
                 Ok(TokenStream::new())
             } else {
-                Ok(impl_iden_for_enum(&ident, variants))
+                Ok(impl_iden_for_enum(&ident, variants, rename_all))
             }
         }
         syn::Data::Struct(DataStruct {
             fields: Fields::Unit,
             ..
-        }) => Ok(impl_iden_for_unit_struct(
-            &ident,
-            new_iden.to_string().as_str(),
-        )),
+        }) => {
+            let new_iden = new_iden.unwrap_or_else(|| match rename_all {
+                Some(case_style) => ident.convert_case(Some(case_style)),
+                None => ident.to_string().to_snake_case(),
+            });
+            Ok(impl_iden_for_unit_struct(&ident, new_iden.as_str()))
+        }
         _ => Ok(quote_spanned! {
             ident.span() => compile_error!("you can only derive DeriveIden on unit struct or enum");
         }),
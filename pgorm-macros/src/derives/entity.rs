@@ -14,6 +14,7 @@ struct DeriveEntity {
     relation_ident: syn::Ident,
     schema_name: Option<syn::Lit>,
     table_name: Option<syn::Lit>,
+    alias: Option<syn::Lit>,
 }
 
 impl DeriveEntity {
@@ -35,6 +36,7 @@ impl DeriveEntity {
 
         let table_name = sea_attr.table_name;
         let schema_name = sea_attr.schema_name;
+        let alias = sea_attr.alias;
 
         Ok(DeriveEntity {
             column_ident,
@@ -45,6 +47,7 @@ impl DeriveEntity {
             relation_ident,
             schema_name,
             table_name,
+            alias,
         })
     }
 
@@ -53,12 +56,14 @@ impl DeriveEntity {
         let expanded_impl_entity_trait = self.impl_entity_trait();
         let expanded_impl_iden = self.impl_iden();
         let expanded_impl_iden_static = self.impl_iden_static();
+        let expanded_alias = self.alias();
 
         TokenStream::from_iter([
             expanded_impl_entity_name,
             expanded_impl_entity_trait,
             expanded_impl_iden,
             expanded_impl_iden_static,
+            expanded_alias,
         ])
     }
 
@@ -140,6 +145,35 @@ impl DeriveEntity {
             }
         )
     }
+
+    fn alias(&self) -> TokenStream {
+        let alias = match &self.alias {
+            Some(alias) => alias,
+            None => return TokenStream::new(), // No alias, do not generate an alias type
+        };
+        let ident = &self.ident;
+        let alias_ident = format_ident!("{}Alias", ident);
+
+        quote!(
+            #[doc = " Generated by pgorm-macros"]
+            #[derive(Copy, Clone, Default, Debug)]
+            pub struct #alias_ident;
+
+            #[automatically_derived]
+            impl pgorm::Iden for #alias_ident {
+                fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+                    write!(s, "{}", pgorm::IdenStatic::as_str(self)).unwrap();
+                }
+            }
+
+            #[automatically_derived]
+            impl pgorm::IdenStatic for #alias_ident {
+                fn as_str(&self) -> &str {
+                    #alias
+                }
+            }
+        )
+    }
 }
 
 pub fn expand_derive_entity(input: syn::DeriveInput) -> syn::Result<TokenStream> {
@@ -15,6 +15,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
     let mut schema_name = quote! { None };
     let mut table_iden = false;
     let mut rename_all: Option<CaseStyle> = None;
+    let mut alias = None;
 
     attrs
         .iter()
@@ -33,6 +34,8 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     table_iden = true;
                 } else if meta.path.is_ident("rename_all") {
                     rename_all = Some((&meta).try_into()?);
+                } else if meta.path.is_ident("alias") {
+                    alias = Some(meta.value()?.parse::<Lit>()?);
                 } else {
                     // Reads the value expression to advance the parse stream.
                     // Some parameters, such as `primary_key`, do not have any value,
@@ -44,12 +47,14 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
             })
         })?;
 
+    let entity_alias = alias.map(|alias| quote! { #[pgorm(alias = #alias)] });
     let entity_def = table_name
         .as_ref()
         .map(|table_name| {
             quote! {
                 #[doc = " Generated by pgorm-macros"]
                 #[derive(Copy, Clone, Default, Debug, pgorm::prelude::DeriveEntity)]
+                #entity_alias
                 pub struct Entity;
 
                 #[automatically_derived]
@@ -111,6 +116,9 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     let mut indexed = false;
                     let mut ignore = false;
                     let mut unique = false;
+                    let mut unique_keys = Vec::new();
+                    let mut generated = None;
+                    let mut stored = None;
                     let mut sql_type = None;
                     let mut column_name = if let Some(case_style) = rename_all {
                         Some(field_name.convert_case(Some(case_style)))
@@ -125,7 +133,7 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
 
                     let mut enum_name = None;
                     let mut is_primary_key = false;
-                    // search for #[pgorm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique)]
+                    // search for #[pgorm(primary_key, auto_increment = false, column_type = "String(Some(255))", default_value = "new user", default_expr = "gen_random_uuid()", column_name = "name", enum_name = "Name", nullable, indexed, unique, unique_key = "uniq_a_b", generated = "a + b", stored, created_timestamp, updated_timestamp)]
                     for attr in field.attrs.iter() {
                         if !attr.path().is_ident("pgorm") {
                             continue;
@@ -197,6 +205,15 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 } else {
                                     return Err(meta.error(format!("Invalid save_as {:?}", lit)));
                                 }
+                            } else if meta.path.is_ident("generated") {
+                                let lit = meta.value()?.parse()?;
+                                if let Lit::Str(litstr) = lit {
+                                    generated = Some(litstr.value());
+                                } else {
+                                    return Err(meta.error(format!("Invalid generated {:?}", lit)));
+                                }
+                            } else if meta.path.is_ident("stored") {
+                                stored = Some(meta.path.clone());
                             } else if meta.path.is_ident("ignore") {
                                 ignore = true;
                             } else if meta.path.is_ident("primary_key") {
@@ -208,6 +225,13 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                                 indexed = true;
                             } else if meta.path.is_ident("unique") {
                                 unique = true;
+                            } else if meta.path.is_ident("unique_key") {
+                                let lit = meta.value()?.parse()?;
+                                if let Lit::Str(litstr) = lit {
+                                    unique_keys.push(litstr.value());
+                                } else {
+                                    return Err(meta.error(format!("Invalid unique_key {:?}", lit)));
+                                }
                             } else {
                                 // Reads the value expression to advance the parse stream.
                                 // Some parameters, such as `primary_key`, do not have any value,
@@ -219,6 +243,19 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                         })?;
                     }
 
+                    if let (Some(stored), None) = (&stored, &generated) {
+                        return Err(syn::Error::new_spanned(
+                            stored,
+                            "`stored` requires `generated`",
+                        ));
+                    }
+                    if let (None, Some(_)) = (&stored, &generated) {
+                        return Err(syn::Error::new_spanned(
+                            field,
+                            "`generated` requires `stored`, Postgres before 18 only supports stored generated columns",
+                        ));
+                    }
+
                     if let Some(enum_name) = enum_name {
                         field_name = enum_name;
                     }
@@ -291,9 +328,18 @@ pub fn expand_derive_entity_model(data: Data, attrs: Vec<Attribute>) -> syn::Res
                     if unique {
                         match_row = quote! { #match_row.unique() };
                     }
+                    for unique_key in unique_keys {
+                        match_row = quote! { #match_row.unique_key(#unique_key) };
+                    }
                     if let Some(default_value) = default_value {
                         match_row = quote! { #match_row.default_value(#default_value) };
                     }
+                    if let Some(generated) = generated {
+                        let stored = stored.is_some();
+                        match_row = quote! {
+                            #match_row.generated(pgorm::pgorm_query::Expr::cust(#generated), #stored)
+                        };
+                    }
                     if let Some(comment) = comment {
                         match_row = quote! { #match_row.comment(#comment) };
                     }
@@ -2,20 +2,78 @@ use self::util::GetMeta;
 use proc_macro2::{Ident, TokenStream};
 use quote::{ToTokens, format_ident, quote, quote_spanned};
 use syn::{
-    Data, DataStruct, Fields, Generics, Meta, ext::IdentExt, punctuated::Punctuated, token::Comma,
+    Data, DataStruct, Fields, GenericArgument, Generics, Meta, PathArguments, Type, ext::IdentExt,
+    punctuated::Punctuated, token::Comma,
 };
 
 pub struct FromQueryResultItem {
     pub skip: bool,
+    pub nested: Option<Nested>,
+    pub composite: bool,
     pub ident: Ident,
 }
+
+/// A field holding another [FromQueryResult](pgorm::FromQueryResult) struct, read
+/// from the same row
+pub enum Nested {
+    /// `T`, which must be present
+    Required(Type),
+    /// `Option<T>`, which is `None` when every column of `T` is `NULL`
+    Optional(Type),
+}
+
+impl Nested {
+    fn new(ty: Type) -> Self {
+        match option_inner(&ty) {
+            Some(inner) => Self::Optional(inner.clone()),
+            None => Self::Required(ty),
+        }
+    }
+}
+
+/// Extract `T` out of `Option<T>`
+fn option_inner(ty: &Type) -> Option<&Type> {
+    let Type::Path(path) = ty else {
+        return None;
+    };
+    let segment = path.path.segments.last()?;
+    if segment.ident != "Option" {
+        return None;
+    }
+    let PathArguments::AngleBracketed(args) = &segment.arguments else {
+        return None;
+    };
+    match args.args.first()? {
+        GenericArgument::Type(inner) => Some(inner),
+        _ => None,
+    }
+}
+
 impl ToTokens for FromQueryResultItem {
     fn to_tokens(&self, tokens: &mut TokenStream) {
-        let Self { ident, skip } = self;
+        let Self {
+            ident,
+            skip,
+            nested,
+            composite,
+        } = self;
         if *skip {
             tokens.extend(quote! {
                 #ident: std::default::Default::default(),
             });
+        } else if let Some(Nested::Required(ty)) = nested {
+            tokens.extend(quote! {
+                #ident: <#ty as pgorm::FromQueryResult>::from_query_result(row, pre)?,
+            });
+        } else if let Some(Nested::Optional(ty)) = nested {
+            tokens.extend(quote! {
+                #ident: <#ty as pgorm::FromQueryResult>::from_query_result_optional(row, pre)?,
+            });
+        } else if *composite {
+            let name = ident.unraw().to_string();
+            tokens.extend(quote! {
+                #ident: row.try_get_composite(pre, #name)?,
+            });
         } else {
             let name = ident.unraw().to_string();
             tokens.extend(quote! {
@@ -46,21 +104,59 @@ pub fn expand_derive_from_query_result(
 
     for parsed_field in fields.into_iter() {
         let mut skip = false;
+        let mut nested = false;
+        let mut composite = false;
         for attr in parsed_field.attrs.iter() {
             if !attr.path().is_ident("pgorm") {
                 continue;
             }
             if let Ok(list) = attr.parse_args_with(Punctuated::<Meta, Comma>::parse_terminated) {
                 for meta in list.iter() {
-                    skip = meta.exists("skip");
+                    skip |= meta.exists("skip");
+                    nested |= meta.exists("nested");
+                    composite |= meta.exists("composite");
                 }
             }
         }
+        if nested && composite {
+            return Err(syn::Error::new_spanned(
+                parsed_field.ident,
+                "a field can't be both `nested` and `composite`",
+            ));
+        }
         let ident = format_ident!("{}", parsed_field.ident.unwrap().to_string());
-        field.push(FromQueryResultItem { skip, ident });
+        let nested = nested.then(|| Nested::new(parsed_field.ty));
+        field.push(FromQueryResultItem {
+            skip,
+            nested,
+            composite,
+            ident,
+        });
     }
     let (impl_generics, ty_generics, where_clause) = generics.split_for_impl();
 
+    // The struct is absent when every column it reads, directly or through nested
+    // structs, is `NULL`
+    let mut is_null: Vec<TokenStream> = field
+        .iter()
+        .filter(|item| !item.skip)
+        .map(|item| {
+            let FromQueryResultItem { ident, nested, .. } = item;
+            match nested {
+                Some(Nested::Required(ty) | Nested::Optional(ty)) => quote! {
+                    <#ty as pgorm::FromQueryResult>::from_query_result_optional(row, pre)?.is_none()
+                },
+                None => {
+                    let name = ident.unraw().to_string();
+                    quote! { row.is_null(pre, #name)? }
+                }
+            }
+        })
+        .collect();
+    if is_null.is_empty() {
+        is_null.push(quote! { false });
+    }
+
     let foo = ident.to_string();
     Ok(quote!(
         #[automatically_derived]
@@ -71,6 +167,13 @@ pub fn expand_derive_from_query_result(
                     #(#field)*
                 })
             }
+
+            fn from_query_result_optional(row: &pgorm::QueryResult, pre: &str) -> std::result::Result<std::option::Option<Self>, pgorm::DbErr> {
+                if #(#is_null)&&* {
+                    return Ok(None);
+                }
+                Self::from_query_result(row, pre).map(Some)
+            }
         }
     ))
 }
@@ -1,4 +1,5 @@
-use proc_macro2::TokenStream;
+use heck::ToSnakeCase;
+use proc_macro2::{Span, TokenStream};
 use quote::{format_ident, quote, quote_spanned};
 
 use super::attributes::{derive_attr, field_attr};
@@ -84,9 +85,7 @@ impl DeriveRelation {
                     ))
                 }??;
 
-                let mut result = quote!(
-                    Self::#variant_ident => #entity_ident::#relation_type(#related_to)
-                );
+                let mut result = quote!(#entity_ident::#relation_type(#related_to));
 
                 if attr.from.is_some() {
                     let from =
@@ -144,7 +143,15 @@ impl DeriveRelation {
                         .ok_or_else(|| {
                             syn::Error::new_spanned(variant, "Missing value for 'on_condition'")
                         })??;
-                    result = quote! { #result.on_condition(|_, _| pgorm::pgorm_query::IntoCondition::into_condition(#on_condition)) };
+                    // A closure is given the names of the joined tables, which follow table aliases
+                    result = match syn::parse2::<syn::ExprClosure>(on_condition.clone()) {
+                        Ok(closure) => {
+                            let left = format_ident!("left", span = Span::mixed_site());
+                            let right = format_ident!("right", span = Span::mixed_site());
+                            quote! { #result.on_condition(|#left, #right| pgorm::pgorm_query::IntoCondition::into_condition((#closure)(#left, #right))) }
+                        }
+                        Err(_) => quote! { #result.on_condition(|_, _| pgorm::pgorm_query::IntoCondition::into_condition(#on_condition)) },
+                    };
                 }
 
                 if attr.fk_name.is_some() {
@@ -185,9 +192,19 @@ impl DeriveRelation {
                     result = quote! { #result.condition_type(#condition_type) };
                 }
 
-                result = quote! { #result.into() };
+                // A relation to the entity itself joins the same table twice, so the
+                // related side is aliased after the variant.
+                if *entity_ident == related_to.to_string() {
+                    let alias = variant_ident.to_string().to_snake_case();
+                    result = quote! {
+                        pgorm::entity::RelationDef::from(#result)
+                            .to_alias(pgorm::pgorm_query::Alias::new(#alias))
+                    };
+                } else {
+                    result = quote! { #result.into() };
+                }
 
-                Result::<_, syn::Error>::Ok(result)
+                Result::<_, syn::Error>::Ok(quote!(Self::#variant_ident => #result))
             })
             .collect::<Result<Vec<_>, _>>()?;
 
@@ -113,6 +113,9 @@ pub fn derive_entity(input: TokenStream) -> TokenStream {
 /// # impl ActiveModelBehavior for ActiveModel {}
 /// ```
 ///
+/// `#[pgorm(alias = "...")]` also generates an `EntityAlias` unit struct implementing
+/// `Iden` and `IdenStatic`, to be passed to `join_as` when joining the table to itself.
+///
 /// Entity should always have a primary key.
 /// Or, it will result in a compile error.
 /// See <https://github.com/pgorm-rs/pgorm/issues/485> for details.
@@ -581,6 +584,15 @@ pub fn derive_active_enum(input: TokenStream) -> TokenStream {
 ///
 /// ### Attributes
 /// - `skip`: Will not try to pull this field from the query result. And set it to the default value of the type.
+/// - `nested`: Read this field, itself a `FromQueryResult` struct, from the same columns of the row.
+///   An `Option` of it is `None` when all of its columns are `NULL`, as on the missing side of a `LEFT JOIN`.
+/// - `composite`: Decode this column, of a composite type, through the `FromSql` implementation of the
+///   field's type, such as one derived with `postgres-types`. It can't be combined with `nested`.
+///
+/// The derived `from_query_result_optional` returns `Ok(None)` only when every column the
+/// struct reads is `NULL`. A missing column, or a row with only some of them `NULL`, is an
+/// `Err`, where the default implementation of the trait returns `Ok(None)` for any error.
+/// A struct whose fields are all `skip` is always `Some`.
 ///
 /// ### Usage
 ///
@@ -593,6 +605,13 @@ pub fn derive_active_enum(input: TokenStream) -> TokenStream {
 ///     num_of_fruits: i32,
 ///     #[pgorm(skip)]
 ///     skip_me: i32,
+///     #[pgorm(nested)]
+///     vendor: Option<Vendor>,
+/// }
+///
+/// #[derive(Debug, FromQueryResult)]
+/// struct Vendor {
+///     vendor_name: String,
 /// }
 /// ```
 #[cfg(feature = "derive")]
@@ -635,6 +654,15 @@ pub fn derive_from_query_result(input: TokenStream) -> TokenStream {
 ///     CakeExpanded,
 /// }
 /// ```
+///
+/// `on_delete` and `on_update` take the name of a `ForeignKeyAction` variant, e.g.
+/// `on_delete = "Cascade"`, and are emitted on the foreign key that `Schema` creates
+/// for the relation.
+///
+/// `on_condition` adds an expression to the `ON` clause whenever the relation is joined.
+/// Written as a closure, it is given the names of the left and right joined tables, which
+/// keeps it correct under table aliases, e.g.
+/// `on_condition = r#"|_, right| Expr::col((right, super::address::Column::Kind)).eq("primary")"#`.
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveRelation, attributes(pgorm))]
 pub fn derive_relation(input: TokenStream) -> TokenStream {
@@ -901,6 +929,28 @@ pub fn derive_active_enum_display(input: TokenStream) -> TokenStream {
 /// assert_eq!(MyClass::Text.to_string(), "text");
 /// assert_eq!(MyOther.to_string(), "my_other");
 /// ```
+///
+/// Names are snake_case by default. `rename_all` applies another case style to the enum or
+/// struct name and every variant, one of `camelCase`, `PascalCase`, `kebab-case`, `snake_case`,
+/// `SCREAMING_SNAKE_CASE`, `SCREAMING-KEBAB-CASE`, `lowercase` or `UPPERCASE`. `iden` on a
+/// variant still takes precedence.
+///
+/// ```rust
+/// use pgorm::{DeriveIden, Iden};
+///
+/// #[derive(DeriveIden)]
+/// #[pgorm(rename_all = "camelCase")]
+/// pub enum UserProfile {
+///     Table,
+///     FirstName,
+///     #[pgorm(iden = "surname")]
+///     LastName,
+/// }
+///
+/// assert_eq!(UserProfile::Table.to_string(), "userProfile");
+/// assert_eq!(UserProfile::FirstName.to_string(), "firstName");
+/// assert_eq!(UserProfile::LastName.to_string(), "surname");
+/// ```
 #[cfg(feature = "derive")]
 #[proc_macro_derive(DeriveIden, attributes(pgorm))]
 pub fn derive_iden(input: TokenStream) -> TokenStream {
@@ -389,8 +389,9 @@ impl RecyclingMethod {
 
 /// Configuration object for a [`Manager`].
 ///
-/// This currently only makes it possible to specify which [`RecyclingMethod`]
-/// should be used when retrieving existing objects from the [`Pool`].
+/// This makes it possible to specify which [`RecyclingMethod`] should be used
+/// when retrieving existing objects from the [`Pool`], and how old a
+/// connection may get before it is replaced.
 ///
 /// [`Manager`]: super::Manager
 #[derive(Clone, Debug, Default, serde::Serialize, serde::Deserialize)]
@@ -401,6 +402,18 @@ pub struct ManagerConfig {
     /// Tag
     #[serde(skip_serializing_if = "Option::is_none")]
     pub tag: Option<String>,
+
+    /// Maximum age of a connection. Older connections are closed instead of
+    /// being recycled the next time they are retrieved from the [`Pool`].
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub max_lifetime: Option<Duration>,
+
+    /// Maximum time a connection may sit idle in the [`Pool`]. Connections idle for
+    /// longer are closed instead of being recycled the next time they are retrieved.
+    /// The time is counted from when the connection was last returned to the pool, so
+    /// it doesn't include the time it was in use.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub idle_timeout: Option<Duration>,
 }
 
 static DEFAULT_TAG_COUNT: AtomicUsize = AtomicUsize::new(0);
@@ -34,6 +34,7 @@ use std::{
         Arc, Mutex, RwLock, Weak,
         atomic::{AtomicUsize, Ordering},
     },
+    time::Instant,
 };
 
 use config::Tag;
@@ -62,18 +63,60 @@ pub use self::config::{
 pub use self::generic_client::GenericClient;
 
 pub use deadpool::managed::reexports::*;
-deadpool::managed_reexports!(
-    "tokio_postgres",
-    Manager,
-    managed::Object<Manager>,
-    Error,
-    ConfigError
-);
+deadpool::managed_reexports!("tokio_postgres", Manager, Client, Error, ConfigError);
 
 type BoxFuture<'a, T> = Pin<Box<dyn Future<Output = T> + Send + 'a>>;
 
-/// Type alias for [`Object`]
-pub type Client = Object;
+/// A [`ClientWrapper`] checked out of a [`Pool`], which goes back to the pool when dropped
+#[derive(Debug)]
+pub struct Client {
+    // Only held to record when the connection goes back to the pool, on drop. It's dropped
+    // before `object`, so the time is set before another task can check the connection out.
+    #[allow(dead_code)]
+    returned: ReturnGuard,
+    object: Object,
+}
+
+impl Client {
+    /// Take the [`ClientWrapper`] out of its [`Pool`], like [`Object::take`]
+    #[must_use]
+    pub fn take(this: Self) -> ClientWrapper {
+        Object::take(this.object)
+    }
+}
+
+impl From<Object> for Client {
+    fn from(object: Object) -> Self {
+        Self {
+            returned: ReturnGuard(Arc::clone(&object.idle_since)),
+            object,
+        }
+    }
+}
+
+impl Deref for Client {
+    type Target = ClientWrapper;
+
+    fn deref(&self) -> &ClientWrapper {
+        &self.object
+    }
+}
+
+impl DerefMut for Client {
+    fn deref_mut(&mut self) -> &mut ClientWrapper {
+        &mut self.object
+    }
+}
+
+/// Records when a [`Client`] goes back to its [`Pool`], for [`ManagerConfig::idle_timeout`]
+#[derive(Debug)]
+struct ReturnGuard(Arc<Mutex<Instant>>);
+
+impl Drop for ReturnGuard {
+    fn drop(&mut self) {
+        *self.0.lock().unwrap() = Instant::now();
+    }
+}
 
 type RecycleResult = managed::RecycleResult<Error>;
 type RecycleError = managed::RecycleError<Error>;
@@ -174,11 +217,18 @@ impl managed::Manager for Manager {
         Ok(client_wrapper)
     }
 
-    async fn recycle(&self, client: &mut ClientWrapper, _: &Metrics) -> RecycleResult {
+    async fn recycle(&self, client: &mut ClientWrapper, metrics: &Metrics) -> RecycleResult {
         if client.is_closed() {
             tracing::warn!(target: "deadpool.postgres", "Connection could not be recycled: Connection closed");
             return Err(RecycleError::message("Connection closed"));
         }
+        if matches!(self.config.max_lifetime, Some(max_lifetime) if metrics.age() >= max_lifetime) {
+            return Err(RecycleError::message("Connection exceeded max lifetime"));
+        }
+        if matches!(self.config.idle_timeout, Some(idle_timeout) if client.idle_since.lock().unwrap().elapsed() >= idle_timeout)
+        {
+            return Err(RecycleError::message("Connection exceeded idle timeout"));
+        }
         match self.config.recycling_method.query() {
             Some(sql) => match client.simple_query(sql).await {
                 Ok(_) => Ok(()),
@@ -438,6 +488,9 @@ pub struct ClientWrapper {
 
     /// [`StatementCache`] of this client.
     pub statement_cache: Arc<StatementCache>,
+
+    /// When the client last went back to the [`Pool`].
+    idle_since: Arc<Mutex<Instant>>,
 }
 
 impl ClientWrapper {
@@ -449,6 +502,7 @@ impl ClientWrapper {
             client,
             conn_task,
             statement_cache: Arc::new(StatementCache::new()),
+            idle_since: Arc::new(Mutex::new(Instant::now())),
         }
     }
 
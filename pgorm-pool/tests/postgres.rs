@@ -164,7 +164,7 @@ async fn recycling_methods() {
     for recycling_method in recycling_methods {
         cfg.pg.manager = Some(ManagerConfig {
             recycling_method,
-            tag: Default::default(),
+            ..Default::default()
         });
         let pool = cfg.pg.create_pool(tokio_postgres::NoTls).unwrap();
         for _ in 0usize..20usize {
@@ -176,6 +176,54 @@ async fn recycling_methods() {
     }
 }
 
+#[tokio::test]
+async fn max_lifetime() {
+    let mut cfg = Config::from_env();
+    cfg.pg.manager = Some(ManagerConfig {
+        max_lifetime: Some(Duration::from_millis(100)),
+        ..Default::default()
+    });
+    let pool = cfg.pg.create_pool(tokio_postgres::NoTls).unwrap();
+    let backend_pid = || async {
+        let client = pool.get().await.unwrap();
+        let rows = client.query("SELECT pg_backend_pid()", &[]).await.unwrap();
+        rows[0].get::<_, i32>(0)
+    };
+    let pid = backend_pid().await;
+    assert_eq!(backend_pid().await, pid);
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_ne!(backend_pid().await, pid);
+}
+
+#[tokio::test]
+async fn idle_timeout() {
+    let mut cfg = Config::from_env();
+    cfg.pg.manager = Some(ManagerConfig {
+        idle_timeout: Some(Duration::from_millis(100)),
+        ..Default::default()
+    });
+    let pool = cfg.pg.create_pool(tokio_postgres::NoTls).unwrap();
+    let backend_pid = || async {
+        let client = pool.get().await.unwrap();
+        let rows = client.query("SELECT pg_backend_pid()", &[]).await.unwrap();
+        rows[0].get::<_, i32>(0)
+    };
+    let pid = backend_pid().await;
+    for _ in 0..3 {
+        tokio::time::sleep(Duration::from_millis(50)).await;
+        assert_eq!(backend_pid().await, pid);
+    }
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    assert_ne!(backend_pid().await, pid);
+
+    // Time spent checked out doesn't count as idle
+    let pid = backend_pid().await;
+    let client = pool.get().await.unwrap();
+    tokio::time::sleep(Duration::from_millis(150)).await;
+    drop(client);
+    assert_eq!(backend_pid().await, pid);
+}
+
 fn _use_generic_client(_client: &impl tokio_postgres::GenericClient) {
     // nop
 }
@@ -222,7 +270,8 @@ impl Env {
     }
     pub fn set(&mut self, name: &str, value: &str) {
         self.backup.insert(name.to_string(), env::var(name).ok());
-        env::set_var(name, value);
+        // SAFETY: the tests touching the environment only read it on this thread
+        unsafe { env::set_var(name, value) };
     }
 }
 
@@ -230,9 +279,10 @@ impl Drop for Env {
     fn drop(&mut self) {
         for (name, value) in self.backup.iter() {
             println!("setting {} = {:?}", name, value);
+            // SAFETY: see `Env::set`
             match value {
-                Some(val) => env::set_var(name.as_str(), val),
-                None => env::remove_var(name.as_str()),
+                Some(val) => unsafe { env::set_var(name.as_str(), val) },
+                None => unsafe { env::remove_var(name.as_str()) },
             }
         }
     }
@@ -3,8 +3,10 @@
 use crate::*;
 
 mod query_builder;
+mod server_version;
 
 pub use self::query_builder::*;
+pub use self::server_version::*;
 
 #[derive(Debug, PartialEq)]
 pub enum Oper {
@@ -56,7 +58,10 @@ impl Oper {
     pub(crate) fn is_is(&self) -> bool {
         matches!(
             self,
-            Oper::BinOper(BinOper::Is) | Oper::BinOper(BinOper::IsNot)
+            Oper::BinOper(BinOper::Is)
+                | Oper::BinOper(BinOper::IsNot)
+                | Oper::BinOper(BinOper::IsDistinctFrom)
+                | Oper::BinOper(BinOper::IsNotDistinctFrom)
         )
     }
 
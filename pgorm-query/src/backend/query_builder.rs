@@ -1,4 +1,5 @@
 use crate::{
+    error::{Error, Result},
     extension::{
         ExtensionCreateStatement, ExtensionDropStatement, TypeAlterAddOpt, TypeAlterOpt,
         TypeAlterStatement, TypeAs, TypeCreateStatement, TypeDropOpt, TypeDropStatement, TypeRef,
@@ -7,14 +8,152 @@ use crate::{
 };
 use std::ops::Deref;
 
-const QUOTE: Quote = Quote(b'"', b'"');
-
 #[derive(Debug, Clone, Copy)]
 pub struct QueryBuilder;
 
+/// A [`QueryBuilder`] with options, started with [`QueryBuilder::new`]. Statements are built
+/// with it like with a [`QueryBuilder`], which is a [`ConfiguredQueryBuilder`] left at the
+/// defaults.
+#[derive(Debug, Clone, Copy, Default)]
+pub struct ConfiguredQueryBuilder {
+    unquoted_identifiers: bool,
+    server_version: Option<ServerVersion>,
+}
+
+static DEFAULT_QUERY_BUILDER: ConfiguredQueryBuilder = ConfiguredQueryBuilder::new();
+
 impl QueryBuilder {
+    /// A builder quoting every identifier and targeting the latest server version, to be
+    /// configured
+    #[allow(clippy::new_ret_no_self)]
+    pub const fn new() -> ConfiguredQueryBuilder {
+        ConfiguredQueryBuilder::new()
+    }
+}
+
+impl Deref for QueryBuilder {
+    type Target = ConfiguredQueryBuilder;
+
+    fn deref(&self) -> &ConfiguredQueryBuilder {
+        &DEFAULT_QUERY_BUILDER
+    }
+}
+
+impl From<QueryBuilder> for ConfiguredQueryBuilder {
+    fn from(_: QueryBuilder) -> Self {
+        Self::new()
+    }
+}
+
+impl ConfiguredQueryBuilder {
+    const fn new() -> Self {
+        ConfiguredQueryBuilder {
+            unquoted_identifiers: false,
+            server_version: None,
+        }
+    }
+
+    /// Write identifiers matching `[a-z_][a-z0-9_]*` without quotes, for schemas created with
+    /// unquoted names. Identifiers with other characters, and reserved words such as `user`
+    /// or `order`, are still quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .columns([Glyph::Id, Glyph::Aspect])
+    ///     .column(Alias::new("order"))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder::new().unquoted_identifiers(true)),
+    ///     r#"SELECT id, aspect, "order" FROM glyph"#
+    /// );
+    /// ```
+    pub const fn unquoted_identifiers(self, unquoted_identifiers: bool) -> Self {
+        ConfiguredQueryBuilder {
+            unquoted_identifiers,
+            ..self
+        }
+    }
+
+    /// Target a specific server version. Building a statement that uses a feature the version
+    /// lacks falls back to SQL the server accepts, e.g. `NULLS NOT DISTINCT` before
+    /// [`ServerVersion::NULLS_NOT_DISTINCT`] is left out, so the unique index treats nulls as
+    /// distinct.
+    ///
+    /// # Warning
+    ///
+    /// The fallback silently weakens the statement. Use `try_build` to get
+    /// [`Error::UnsupportedByServerVersion`] instead.
+    ///
+    /// `MERGE` and `FETCH .. WITH TIES`, which also need Postgres 15, can't be built by this
+    /// crate, so there's nothing to gate for them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{error::Error, tests_cfg::*, *};
+    ///
+    /// let index = Index::create()
+    ///     .name("idx-glyph-aspect")
+    ///     .table(Glyph::Table)
+    ///     .col(Glyph::Aspect)
+    ///     .unique()
+    ///     .nulls_not_distinct()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     index.try_build(QueryBuilder::new().server_version(ServerVersion::new(14, 9))),
+    ///     Err(Error::UnsupportedByServerVersion {
+    ///         feature: "NULLS NOT DISTINCT",
+    ///         required: ServerVersion::NULLS_NOT_DISTINCT,
+    ///         target: ServerVersion::new(14, 9),
+    ///     })
+    /// );
+    /// ```
+    pub const fn server_version(self, version: ServerVersion) -> Self {
+        ConfiguredQueryBuilder {
+            server_version: Some(version),
+            ..self
+        }
+    }
+
+    /// The targeted server version, `None` for the latest
+    pub const fn target_version(&self) -> Option<ServerVersion> {
+        self.server_version
+    }
+
+    /// Whether the targeted server version is `version` or later
+    pub const fn supports(&self, version: ServerVersion) -> bool {
+        match self.server_version {
+            Some(target) => target.at_least(version),
+            None => true,
+        }
+    }
+
+    /// Fail with [`Error::UnsupportedByServerVersion`] if the targeted server version is
+    /// older than `version`, which `feature` requires
+    pub(crate) fn check_version(
+        &self,
+        version: ServerVersion,
+        feature: &'static str,
+    ) -> Result<()> {
+        match self.server_version {
+            Some(target) if !target.at_least(version) => Err(Error::UnsupportedByServerVersion {
+                feature,
+                required: version,
+                target,
+            }),
+            _ => Ok(()),
+        }
+    }
+
     const fn quote(&self) -> Quote {
-        QUOTE
+        Quote(b'"', b'"', self.unquoted_identifiers)
     }
 
     pub const fn placeholder(&self) -> (&str, bool) {
@@ -27,7 +166,7 @@ impl QueryBuilder {
                 let simple_expr = expr.clone().cast_as(SeaRc::clone(type_name));
                 self.prepare_simple_expr_common(&simple_expr, sql);
             }
-            _ => QueryBuilder::prepare_simple_expr_common(self, simple_expr, sql),
+            _ => Self::prepare_simple_expr_common(self, simple_expr, sql),
         }
     }
 
@@ -83,14 +222,15 @@ impl QueryBuilder {
         sql.push_param(value.clone(), self as _);
     }
 
+    /// Write a string literal. A string with a backslash or control character is written as
+    /// an escape string, `E'..'`, and any other as a standard conforming string, in which a
+    /// single quote is escaped by doubling it.
     fn write_string_quoted(&self, string: &str, buffer: &mut String) {
-        let escaped = self.escape_string(string);
-        let string = if escaped.find('\\').is_some() {
-            "E'".to_owned() + &escaped + "'"
+        if string.contains(['\\', '\0', '\x08', '\x09', '\x1a', '\n', '\r']) {
+            write!(buffer, "E'{}'", self.escape_string(string)).unwrap()
         } else {
-            "'".to_owned() + &escaped + "'"
-        };
-        write!(buffer, "{string}").unwrap()
+            write!(buffer, "'{}'", self.escape_standard_string(string)).unwrap()
+        }
     }
 
     fn write_bytes(&self, bytes: &[u8], buffer: &mut String) {
@@ -170,7 +310,7 @@ impl QueryBuilder {
             }
         }
 
-        self.prepare_on_conflict(&insert.on_conflict, sql);
+        self.prepare_on_conflict(&insert.on_conflict, insert.table.as_deref(), sql);
 
         self.prepare_returning(&insert.returning, sql);
     }
@@ -222,6 +362,10 @@ impl QueryBuilder {
                 false
             });
             self.prepare_index_hints(select, sql);
+            if let Some(sample) = &select.table_sample {
+                write!(sql, " ").unwrap();
+                self.prepare_table_sample(sample, sql);
+            }
         }
 
         if !select.join.is_empty() {
@@ -233,15 +377,22 @@ impl QueryBuilder {
 
         self.prepare_condition(&select.r#where, "WHERE", sql);
 
-        if !select.groups.is_empty() {
+        if !select.groups.is_empty() || !select.grouping.is_empty() {
             write!(sql, " GROUP BY ").unwrap();
-            select.groups.iter().fold(true, |first, expr| {
+            let first = select.groups.iter().fold(true, |first, expr| {
                 if !first {
                     write!(sql, ", ").unwrap()
                 }
                 self.prepare_simple_expr(expr, sql);
                 false
             });
+            select.grouping.iter().fold(first, |first, spec| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_grouping_spec(spec, sql);
+                false
+            });
         }
 
         self.prepare_condition(&select.having, "HAVING", sql);
@@ -320,6 +471,17 @@ impl QueryBuilder {
             false
         });
 
+        if !update.from.is_empty() {
+            write!(sql, " FROM ").unwrap();
+            update.from.iter().fold(true, |first, table_ref| {
+                if !first {
+                    write!(sql, ", ").unwrap()
+                }
+                self.prepare_table_ref(table_ref, sql);
+                false
+            });
+        }
+
         self.prepare_output(&update.returning, sql);
 
         self.prepare_condition(&update.r#where, "WHERE", sql);
@@ -377,6 +539,38 @@ impl QueryBuilder {
         self.prepare_returning(&delete.returning, sql);
     }
 
+    /// Translate [`ValuesStatement`] into SQL statement.
+    pub(crate) fn prepare_values_statement(
+        &self,
+        values: &ValuesStatement,
+        sql: &mut dyn SqlWriter,
+    ) {
+        // Unlike in a FROM list, the columns of a bare VALUES have no context to infer the type
+        // of a parameter from, and would all resolve to text. The first row decides the column
+        // types, so its non-text values are cast to the type they are bound as.
+        write!(sql, "VALUES ").unwrap();
+        values.rows.iter().enumerate().for_each(|(i, row)| {
+            if i != 0 {
+                write!(sql, ", ").unwrap();
+            }
+            write!(sql, "(").unwrap();
+            row.clone().into_iter().enumerate().for_each(|(j, value)| {
+                if j != 0 {
+                    write!(sql, ", ").unwrap();
+                }
+                match self.value_type(&value).filter(|_| i == 0) {
+                    Some(ty) => {
+                        write!(sql, "CAST(").unwrap();
+                        self.prepare_value(&value, sql);
+                        write!(sql, " AS {ty})").unwrap();
+                    }
+                    None => self.prepare_value(&value, sql),
+                }
+            });
+            write!(sql, ")").unwrap();
+        });
+    }
+
     /// Translate ORDER BY expression in [`DeleteStatement`].
     fn prepare_delete_order_by(&self, delete: &DeleteStatement, sql: &mut dyn SqlWriter) {
         if !delete.orders.is_empty() {
@@ -407,6 +601,16 @@ impl QueryBuilder {
             SimpleExpr::Tuple(exprs) => {
                 self.prepare_tuple(exprs, sql);
             }
+            SimpleExpr::Array(exprs) => {
+                write!(sql, "ARRAY[").unwrap();
+                for (i, expr) in exprs.iter().enumerate() {
+                    if i != 0 {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_simple_expr(expr, sql);
+                }
+                write!(sql, "]").unwrap();
+            }
             SimpleExpr::Unary(op, expr) => {
                 self.prepare_un_oper(op, sql);
                 write!(sql, " ").unwrap();
@@ -502,6 +706,9 @@ impl QueryBuilder {
             SimpleExpr::Constant(val) => {
                 self.prepare_constant(val, sql);
             }
+            SimpleExpr::TypeName(column_type) => {
+                self.prepare_type_name(column_type, sql);
+            }
         }
     }
 
@@ -529,6 +736,27 @@ impl QueryBuilder {
     /// Translate [`IndexHint`] into SQL statement.
     fn prepare_index_hints(&self, _select: &SelectStatement, _sql: &mut dyn SqlWriter) {}
 
+    /// Translate [`TableSample`] into SQL statement.
+    fn prepare_table_sample(&self, sample: &TableSample, sql: &mut dyn SqlWriter) {
+        write!(
+            sql,
+            "TABLESAMPLE {} (",
+            match sample.method {
+                SampleMethod::Bernoulli => "BERNOULLI",
+                SampleMethod::System => "SYSTEM",
+            }
+        )
+        .unwrap();
+        // The sampling methods take a `real` percentage, unlike the `double precision` seed
+        self.prepare_value(&(sample.percentage as f32).into(), sql);
+        write!(sql, ")").unwrap();
+        if let Some(seed) = sample.seed {
+            write!(sql, " REPEATABLE (").unwrap();
+            self.prepare_value(&seed.into(), sql);
+            write!(sql, ")").unwrap();
+        }
+    }
+
     /// Translate [`LockType`] into SQL statement.
     fn prepare_select_lock(&self, lock: &LockClause, sql: &mut dyn SqlWriter) {
         write!(
@@ -586,6 +814,43 @@ impl QueryBuilder {
         };
     }
 
+    /// Translate [`GroupingSpec`] into SQL statement.
+    fn prepare_grouping_spec(&self, spec: &GroupingSpec, sql: &mut dyn SqlWriter) {
+        match spec {
+            GroupingSpec::Rollup(exprs) => {
+                write!(sql, "ROLLUP ").unwrap();
+                self.prepare_grouping_set(exprs, sql);
+            }
+            GroupingSpec::Cube(exprs) => {
+                write!(sql, "CUBE ").unwrap();
+                self.prepare_grouping_set(exprs, sql);
+            }
+            GroupingSpec::GroupingSets(sets) => {
+                write!(sql, "GROUPING SETS (").unwrap();
+                sets.iter().fold(true, |first, exprs| {
+                    if !first {
+                        write!(sql, ", ").unwrap()
+                    }
+                    self.prepare_grouping_set(exprs, sql);
+                    false
+                });
+                write!(sql, ")").unwrap();
+            }
+        }
+    }
+
+    fn prepare_grouping_set(&self, exprs: &[SimpleExpr], sql: &mut dyn SqlWriter) {
+        write!(sql, "(").unwrap();
+        exprs.iter().fold(true, |first, expr| {
+            if !first {
+                write!(sql, ", ").unwrap()
+            }
+            self.prepare_simple_expr(expr, sql);
+            false
+        });
+        write!(sql, ")").unwrap();
+    }
+
     /// Translate [`JoinExpr`] into SQL statement.
     fn prepare_join_expr(&self, join_expr: &JoinExpr, sql: &mut dyn SqlWriter) {
         self.prepare_join_type(&join_expr.join, sql);
@@ -620,12 +885,29 @@ impl QueryBuilder {
                 write!(sql, " AS ").unwrap();
                 alias.prepare(sql.as_writer(), self.quote());
             }
+            TableRef::ValuesExprList(rows, alias) => {
+                write!(sql, "(VALUES ").unwrap();
+                rows.iter().fold(true, |first, row| {
+                    if !first {
+                        write!(sql, ", ").unwrap();
+                    }
+                    self.prepare_tuple(row, sql);
+                    false
+                });
+                write!(sql, ")").unwrap();
+                write!(sql, " AS ").unwrap();
+                alias.prepare(sql.as_writer(), self.quote());
+            }
             TableRef::FunctionCall(func, alias) => {
                 self.prepare_function_name(&func.func, sql);
                 self.prepare_function_arguments(func, sql);
                 write!(sql, " AS ").unwrap();
                 alias.prepare(sql.as_writer(), self.quote());
             }
+            TableRef::Only(table_ref) => {
+                write!(sql, "ONLY ").unwrap();
+                self.prepare_table_ref(table_ref, sql);
+            }
             _ => self.prepare_table_ref_iden(table_ref, sql),
         }
     }
@@ -678,6 +960,8 @@ impl QueryBuilder {
                 BinOper::NotLike => "NOT LIKE",
                 BinOper::Is => "IS",
                 BinOper::IsNot => "IS NOT",
+                BinOper::IsDistinctFrom => "IS DISTINCT FROM",
+                BinOper::IsNotDistinctFrom => "IS NOT DISTINCT FROM",
                 BinOper::In => "IN",
                 BinOper::NotIn => "NOT IN",
                 BinOper::Between => "BETWEEN",
@@ -713,11 +997,15 @@ impl QueryBuilder {
                 BinOper::StrictWordSimilarityDistance => "<<<->",
                 BinOper::GetJsonField => "->",
                 BinOper::CastJsonField => "->>",
+                BinOper::DeleteJsonPath => "#-",
                 BinOper::Regex => "~",
                 BinOper::RegexCaseInsensitive => "~*",
                 BinOper::EuclideanDistance => "<->",
                 BinOper::NegativeInnerProduct => "<#>",
                 BinOper::CosineDistance => "<=>",
+                BinOper::AtTimeZone => "AT TIME ZONE",
+                BinOper::SubnetOf => "<<",
+                BinOper::SupernetOf => ">>",
             }
         )
         .unwrap();
@@ -807,6 +1095,9 @@ impl QueryBuilder {
                     Function::Any => "ANY",
                     Function::Some => "SOME",
                     Function::All => "ALL",
+                    Function::Extract(_) => "EXTRACT",
+                    Function::DateTrunc => "DATE_TRUNC",
+                    Function::MacaddrTrunc => "TRUNC",
                 }
             )
             .unwrap();
@@ -815,11 +1106,23 @@ impl QueryBuilder {
 
     fn prepare_function_arguments(&self, func: &FunctionCall, sql: &mut dyn SqlWriter) {
         write!(sql, "(").unwrap();
+        if let Function::Extract(part) = &func.func {
+            self.prepare_date_part(part, sql);
+            write!(sql, " FROM ").unwrap();
+        }
         for (i, expr) in func.args.iter().enumerate() {
             if i != 0 {
                 write!(sql, ", ").unwrap();
             }
-            if func.mods[i].distinct {
+            let mod_ = &func.mods[i];
+            if let Some(name) = &mod_.name {
+                name.unquoted(sql.as_writer());
+                write!(sql, " => ").unwrap();
+            }
+            if mod_.variadic {
+                write!(sql, "VARIADIC ").unwrap();
+            }
+            if mod_.distinct {
                 write!(sql, "DISTINCT ").unwrap();
             }
             self.prepare_simple_expr(expr, sql);
@@ -827,6 +1130,39 @@ impl QueryBuilder {
         write!(sql, ")").unwrap();
     }
 
+    /// Translate [`DatePart`] into SQL statement.
+    fn prepare_date_part(&self, part: &DatePart, sql: &mut dyn SqlWriter) {
+        write!(
+            sql,
+            "{}",
+            match part {
+                DatePart::Century => "CENTURY",
+                DatePart::Day => "DAY",
+                DatePart::Decade => "DECADE",
+                DatePart::Dow => "DOW",
+                DatePart::Doy => "DOY",
+                DatePart::Epoch => "EPOCH",
+                DatePart::Hour => "HOUR",
+                DatePart::IsoDow => "ISODOW",
+                DatePart::IsoYear => "ISOYEAR",
+                DatePart::Julian => "JULIAN",
+                DatePart::Microseconds => "MICROSECONDS",
+                DatePart::Millennium => "MILLENNIUM",
+                DatePart::Milliseconds => "MILLISECONDS",
+                DatePart::Minute => "MINUTE",
+                DatePart::Month => "MONTH",
+                DatePart::Quarter => "QUARTER",
+                DatePart::Second => "SECOND",
+                DatePart::Timezone => "TIMEZONE",
+                DatePart::TimezoneHour => "TIMEZONE_HOUR",
+                DatePart::TimezoneMinute => "TIMEZONE_MINUTE",
+                DatePart::Week => "WEEK",
+                DatePart::Year => "YEAR",
+            }
+        )
+        .unwrap();
+    }
+
     pub(crate) fn prepare_with_query(&self, query: &WithQuery, sql: &mut dyn SqlWriter) {
         self.prepare_with_clause(&query.with_clause, sql);
         self.prepare_query_statement(query.query.as_ref().unwrap().deref(), sql);
@@ -847,53 +1183,61 @@ impl QueryBuilder {
     ) {
         if with_clause.recursive {
             if let Some(search) = &with_clause.search {
-                write!(
-                    sql,
-                    "SEARCH {} FIRST BY ",
-                    match &search.order.as_ref().unwrap() {
-                        SearchOrder::BREADTH => "BREADTH",
-                        SearchOrder::DEPTH => "DEPTH",
-                    }
-                )
-                .unwrap();
-
-                self.prepare_simple_expr(&search.expr.as_ref().unwrap().expr, sql);
-
-                write!(sql, " SET ").unwrap();
-
-                search
-                    .expr
-                    .as_ref()
-                    .unwrap()
-                    .alias
-                    .as_ref()
-                    .unwrap()
-                    .prepare(sql.as_writer(), self.quote());
-                write!(sql, " ").unwrap();
+                self.prepare_with_search(search, sql);
             }
             if let Some(cycle) = &with_clause.cycle {
-                write!(sql, "CYCLE ").unwrap();
-
-                self.prepare_simple_expr(cycle.expr.as_ref().unwrap(), sql);
-
-                write!(sql, " SET ").unwrap();
-
-                cycle
-                    .set_as
-                    .as_ref()
-                    .unwrap()
-                    .prepare(sql.as_writer(), self.quote());
-                write!(sql, " USING ").unwrap();
-                cycle
-                    .using
-                    .as_ref()
-                    .unwrap()
-                    .prepare(sql.as_writer(), self.quote());
-                write!(sql, " ").unwrap();
+                self.prepare_with_cycle(cycle, sql);
             }
         }
     }
 
+    fn prepare_with_search(&self, search: &Search, sql: &mut dyn SqlWriter) {
+        write!(
+            sql,
+            "SEARCH {} FIRST BY ",
+            match &search.order.as_ref().unwrap() {
+                SearchOrder::BREADTH => "BREADTH",
+                SearchOrder::DEPTH => "DEPTH",
+            }
+        )
+        .unwrap();
+
+        self.prepare_simple_expr(&search.expr.as_ref().unwrap().expr, sql);
+
+        write!(sql, " SET ").unwrap();
+
+        search
+            .expr
+            .as_ref()
+            .unwrap()
+            .alias
+            .as_ref()
+            .unwrap()
+            .prepare(sql.as_writer(), self.quote());
+        write!(sql, " ").unwrap();
+    }
+
+    fn prepare_with_cycle(&self, cycle: &Cycle, sql: &mut dyn SqlWriter) {
+        write!(sql, "CYCLE ").unwrap();
+
+        self.prepare_simple_expr(cycle.expr.as_ref().unwrap(), sql);
+
+        write!(sql, " SET ").unwrap();
+
+        cycle
+            .set_as
+            .as_ref()
+            .unwrap()
+            .prepare(sql.as_writer(), self.quote());
+        write!(sql, " USING ").unwrap();
+        cycle
+            .using
+            .as_ref()
+            .unwrap()
+            .prepare(sql.as_writer(), self.quote());
+        write!(sql, " ").unwrap();
+    }
+
     fn prepare_with_clause_common_tables(&self, with_clause: &WithClause, sql: &mut dyn SqlWriter) {
         let mut cte_first = true;
         assert_ne!(
@@ -918,6 +1262,14 @@ impl QueryBuilder {
             cte_first = false;
 
             self.prepare_with_query_clause_common_table(cte, sql);
+            if with_clause.recursive {
+                if let Some(search) = &cte.search {
+                    self.prepare_with_search(search, sql);
+                }
+                if let Some(cycle) = &cte.cycle {
+                    self.prepare_with_cycle(cycle, sql);
+                }
+            }
         }
     }
 
@@ -1147,9 +1499,7 @@ impl QueryBuilder {
             Value::Float(Some(v)) => write!(s, "{v}").unwrap(),
             Value::Double(Some(v)) => write!(s, "{v}").unwrap(),
             Value::String(Some(v)) => self.write_string_quoted(v, &mut s),
-            Value::Char(Some(v)) => {
-                self.write_string_quoted(std::str::from_utf8(&[*v as u8]).unwrap(), &mut s)
-            }
+            Value::Char(Some(v)) => self.write_string_quoted(v.encode_utf8(&mut [0; 4]), &mut s),
             Value::Bytes(Some(v)) => self.write_bytes(v, &mut s),
             Value::Json(Some(v)) => self.write_string_quoted(&v.to_string(), &mut s),
             Value::ChronoDate(Some(v)) => write!(s, "'{}'", v.format("%Y-%m-%d")).unwrap(),
@@ -1168,15 +1518,14 @@ impl QueryBuilder {
             }
             Value::Decimal(Some(v)) => write!(s, "{v}").unwrap(),
             Value::Uuid(Some(v)) => write!(s, "'{v}'").unwrap(),
-            Value::Array(_, Some(v)) => write!(
-                s,
-                "ARRAY [{}]",
-                v.iter()
-                    .map(|element| self.value_to_string(element))
-                    .collect::<Vec<String>>()
-                    .join(",")
-            )
-            .unwrap(),
+            Value::Array(_, Some(elements)) if elements.is_empty() => {
+                write!(s, "ARRAY[]::").unwrap();
+                self.prepare_column_type(&v.column_type(), &mut s);
+            }
+            Value::Array(_, Some(v)) => {
+                write!(s, "ARRAY ").unwrap();
+                self.write_array_elements(v, &mut s);
+            }
             Value::Vector(Some(v)) => {
                 write!(s, "'[").unwrap();
                 for (i, &element) in v.as_slice().iter().enumerate() {
@@ -1193,18 +1542,85 @@ impl QueryBuilder {
         s
     }
 
+    /// Write the bracketed elements of an array constructor. The sub-arrays of a
+    /// multidimensional array are nested brackets, which must all have the same length.
+    fn write_array_elements(&self, elements: &[Value], s: &mut String) {
+        write!(s, "[").unwrap();
+        for (i, element) in elements.iter().enumerate() {
+            if i != 0 {
+                write!(s, ",").unwrap();
+            }
+            match element {
+                Value::Array(_, Some(inner)) => self.write_array_elements(inner, s),
+                Value::Bool(Some(b)) => write!(s, "{b}").unwrap(),
+                _ => s.push_str(&self.value_to_string(element)),
+            }
+        }
+        write!(s, "]").unwrap();
+    }
+
+    /// The type a value is bound as by the driver, or `None` for text and vector values, whose
+    /// parameters Postgres infers as text and from the `vector` extension respectively
+    fn value_type(&self, value: &Value) -> Option<String> {
+        match value {
+            Value::String(_) | Value::Char(_) | Value::Vector(_) => None,
+            _ => {
+                let mut ty = String::new();
+                self.prepare_column_type(&value.column_type(), &mut ty);
+                Some(ty)
+            }
+        }
+    }
+
     #[doc(hidden)]
     /// Write ON CONFLICT expression
-    fn prepare_on_conflict(&self, on_conflict: &Option<OnConflict>, sql: &mut dyn SqlWriter) {
+    fn prepare_on_conflict(
+        &self,
+        on_conflict: &Option<OnConflict>,
+        table: Option<&TableRef>,
+        sql: &mut dyn SqlWriter,
+    ) {
         if let Some(on_conflict) = on_conflict {
             self.prepare_on_conflict_keywords(sql);
             self.prepare_on_conflict_target(&on_conflict.targets, sql);
             self.prepare_on_conflict_condition(&on_conflict.target_where, sql);
             self.prepare_on_conflict_action(&on_conflict.action, sql);
-            self.prepare_on_conflict_condition(&on_conflict.action_where, sql);
+            if on_conflict.changed_columns.is_empty() {
+                self.prepare_on_conflict_condition(&on_conflict.action_where, sql);
+            } else {
+                let mut action_where = on_conflict.action_where.clone();
+                action_where.add_condition(
+                    self.on_conflict_changed_condition(&on_conflict.changed_columns, table),
+                );
+                self.prepare_on_conflict_condition(&action_where, sql);
+            }
         }
     }
 
+    /// Any of `columns` of the existing row, qualified by `table`, differing from the
+    /// inserted value
+    fn on_conflict_changed_condition(
+        &self,
+        columns: &[DynIden],
+        table: Option<&TableRef>,
+    ) -> Condition {
+        columns.iter().fold(Condition::any(), |cond, col| {
+            let current = match table {
+                Some(
+                    TableRef::Table(table)
+                    | TableRef::TableAlias(_, table)
+                    | TableRef::SchemaTableAlias(_, _, table)
+                    | TableRef::DatabaseSchemaTableAlias(_, _, _, table),
+                ) => ColumnRef::TableColumn(table.clone(), col.clone()),
+                Some(TableRef::SchemaTable(schema, table)) => {
+                    ColumnRef::SchemaTableColumn(schema.clone(), table.clone(), col.clone())
+                }
+                _ => ColumnRef::Column(col.clone()),
+            };
+            cond.add(Expr::col(current).is_distinct_from(Expr::excluded(col.clone())))
+        })
+    }
+
     #[doc(hidden)]
     /// Write ON CONFLICT target
     fn prepare_on_conflict_target(
@@ -1671,13 +2087,25 @@ impl QueryBuilder {
                 ColumnType::Cidr => "cidr".into(),
                 ColumnType::Inet => "inet".into(),
                 ColumnType::MacAddr => "macaddr".into(),
-                ColumnType::Year => unimplemented!("Year is not available in Postgres."),
+                ColumnType::Year => "smallint".into(),
                 ColumnType::LTree => "ltree".into(),
             }
         )
         .unwrap()
     }
 
+    /// The type as written in a cast, with the name of an enum quoted
+    fn prepare_type_name(&self, column_type: &ColumnType, sql: &mut dyn SqlWriter) {
+        match column_type {
+            ColumnType::Enum { name, .. } => name.prepare(sql.as_writer(), self.quote()),
+            ColumnType::Array(elem_type) => {
+                self.prepare_type_name(elem_type, sql);
+                write!(sql, "[]").unwrap();
+            }
+            _ => self.prepare_column_type(column_type, sql),
+        }
+    }
+
     fn column_spec_auto_increment_keyword(&self) -> &str {
         ""
     }
@@ -1759,7 +2187,7 @@ impl QueryBuilder {
                                 write!(sql, "ALTER COLUMN ").unwrap();
                                 column_def.name.prepare(sql.as_writer(), self.quote());
                                 write!(sql, " SET DEFAULT ").unwrap();
-                                QueryBuilder::prepare_simple_expr(self, v, sql);
+                                Self::prepare_simple_expr(self, v, sql);
                             }
                             ColumnSpec::UniqueKey => {
                                 write!(sql, "ADD UNIQUE (").unwrap();
@@ -1789,6 +2217,27 @@ impl QueryBuilder {
                     write!(sql, "DROP COLUMN ").unwrap();
                     column_name.prepare(sql.as_writer(), self.quote());
                 }
+                TableAlterOption::SetDefault(column_name, value) => {
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_name.prepare(sql.as_writer(), self.quote());
+                    write!(sql, " SET DEFAULT ").unwrap();
+                    Self::prepare_simple_expr(self, value, sql);
+                }
+                TableAlterOption::DropDefault(column_name) => {
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_name.prepare(sql.as_writer(), self.quote());
+                    write!(sql, " DROP DEFAULT").unwrap();
+                }
+                TableAlterOption::SetNotNull(column_name) => {
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_name.prepare(sql.as_writer(), self.quote());
+                    write!(sql, " SET NOT NULL").unwrap();
+                }
+                TableAlterOption::DropNotNull(column_name) => {
+                    write!(sql, "ALTER COLUMN ").unwrap();
+                    column_name.prepare(sql.as_writer(), self.quote());
+                    write!(sql, " DROP NOT NULL").unwrap();
+                }
                 TableAlterOption::DropForeignKey(name) => {
                     let mut foreign_key = TableForeignKey::new();
                     foreign_key.name(name.to_string());
@@ -1808,6 +2257,16 @@ impl QueryBuilder {
                         Mode::TableAlter,
                     );
                 }
+                TableAlterOption::RenameConstraint(from_name, to_name) => {
+                    write!(sql, "RENAME CONSTRAINT ").unwrap();
+                    from_name.prepare(sql.as_writer(), self.quote());
+                    write!(sql, " TO ").unwrap();
+                    to_name.prepare(sql.as_writer(), self.quote());
+                }
+                TableAlterOption::ValidateConstraint(name) => {
+                    write!(sql, "VALIDATE CONSTRAINT ").unwrap();
+                    name.prepare(sql.as_writer(), self.quote());
+                }
             }
             false
         });
@@ -1907,7 +2366,7 @@ impl QueryBuilder {
             ColumnSpec::NotNull => write!(sql, "NOT NULL").unwrap(),
             ColumnSpec::Default(value) => {
                 write!(sql, "DEFAULT ").unwrap();
-                QueryBuilder::prepare_simple_expr(self, value, sql);
+                Self::prepare_simple_expr(self, value, sql);
             }
             ColumnSpec::AutoIncrement => {
                 write!(sql, "{}", self.column_spec_auto_increment_keyword()).unwrap()
@@ -2006,7 +2465,7 @@ impl QueryBuilder {
     /// Translate the check constraint into SQL statement
     pub(crate) fn prepare_check_constraint(&self, check: &SimpleExpr, sql: &mut dyn SqlWriter) {
         write!(sql, "CHECK (").unwrap();
-        QueryBuilder::prepare_simple_expr(self, check, sql);
+        Self::prepare_simple_expr(self, check, sql);
         write!(sql, ")").unwrap();
     }
 
@@ -2018,7 +2477,7 @@ impl QueryBuilder {
         sql: &mut dyn SqlWriter,
     ) {
         write!(sql, "GENERATED ALWAYS AS (").unwrap();
-        QueryBuilder::prepare_simple_expr(self, gen_, sql);
+        Self::prepare_simple_expr(self, gen_, sql);
         write!(sql, ")").unwrap();
         if stored {
             write!(sql, " STORED").unwrap();
@@ -2059,7 +2518,7 @@ impl QueryBuilder {
 
         self.prepare_index_prefix(create, sql);
 
-        if create.nulls_not_distinct {
+        if create.nulls_not_distinct && self.supports(ServerVersion::NULLS_NOT_DISTINCT) {
             write!(sql, "NULLS NOT DISTINCT ").unwrap();
         }
 
@@ -2099,7 +2558,7 @@ impl QueryBuilder {
         write!(sql, " ").unwrap();
         self.prepare_index_columns(&create.index.columns, sql);
 
-        if create.nulls_not_distinct {
+        if create.nulls_not_distinct && self.supports(ServerVersion::NULLS_NOT_DISTINCT) {
             write!(sql, " NULLS NOT DISTINCT").unwrap();
         }
     }
@@ -2298,6 +2757,10 @@ impl QueryBuilder {
             write!(sql, " ON UPDATE ").unwrap();
             self.prepare_foreign_key_action(foreign_key_action, sql);
         }
+
+        if create.foreign_key.not_valid && mode != Mode::Creation {
+            write!(sql, " NOT VALID").unwrap();
+        }
     }
 
     fn prepare_table_ref_fk_stmt(&self, table_ref: &TableRef, sql: &mut dyn SqlWriter) {
@@ -2349,7 +2812,7 @@ impl QueryBuilder {
 
     // ESCAPE
 
-    /// Escape a SQL string literal
+    /// Escape a SQL string literal with backslashes, for an escape string `E'..'`
     pub fn escape_string(&self, string: &str) -> String {
         string
             .replace('\\', "\\\\")
@@ -2363,6 +2826,12 @@ impl QueryBuilder {
             .replace('\r', "\\r")
     }
 
+    /// Escape a SQL string literal for a standard conforming string `'..'`, in which a
+    /// backslash is an ordinary character and a single quote is written twice
+    pub fn escape_standard_string(&self, string: &str) -> String {
+        string.replace('\'', "''")
+    }
+
     /// Unescape a SQL string literal
     pub fn unescape_string(&self, string: &str) -> String {
         let mut escape = false;
@@ -2433,8 +2902,13 @@ impl QueryBuilder {
                 write!(sql, " AS ").unwrap();
                 alias.prepare(sql.as_writer(), self.quote());
             }
+            TableRef::Only(table_ref) => {
+                write!(sql, "ONLY ").unwrap();
+                self.prepare_table_ref_iden(table_ref, sql);
+            }
             TableRef::SubQuery(_, _)
             | TableRef::ValuesList(_, _)
+            | TableRef::ValuesExprList(_, _)
             | TableRef::FunctionCall(_, _) => {
                 panic!("TableRef with values is not support")
             }
@@ -2677,6 +3151,8 @@ fn is_pg_comparison(b: &BinOper) -> bool {
             | BinOper::WordSimilarity
             | BinOper::StrictWordSimilarity
             | BinOper::Matches
+            | BinOper::SubnetOf
+            | BinOper::SupernetOf
     )
 }
 
@@ -2685,13 +3161,18 @@ fn is_ilike(b: &BinOper) -> bool {
 }
 
 impl SubQueryStatement {
-    pub(crate) fn prepare_statement(&self, query_builder: &QueryBuilder, sql: &mut dyn SqlWriter) {
+    pub(crate) fn prepare_statement(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
         use SubQueryStatement::*;
         match self {
             SelectStatement(stmt) => query_builder.prepare_select_statement(stmt, sql),
             InsertStatement(stmt) => query_builder.prepare_insert_statement(stmt, sql),
             UpdateStatement(stmt) => query_builder.prepare_update_statement(stmt, sql),
             DeleteStatement(stmt) => query_builder.prepare_delete_statement(stmt, sql),
+            ValuesStatement(stmt) => query_builder.prepare_values_statement(stmt, sql),
             WithStatement(stmt) => query_builder.prepare_with_query(stmt, sql),
         }
     }
@@ -2705,15 +3186,19 @@ pub(crate) fn common_inner_expr_well_known_greater_precedence(
         // We only consider the case where an inner expression is contained in either a
         // unary or binary expression (with an outer_oper).
         // We do not need to wrap with parentheses:
-        // Columns, tuples (already wrapped), constants, function calls, values,
-        // keywords, subqueries (already wrapped), case (already wrapped)
+        // Columns, tuples and arrays (already wrapped), constants, function calls, values,
+        // keywords, subqueries (already wrapped), case (already wrapped),
+        // type names, enum casts (rendered as `CAST(..)`)
         SimpleExpr::Column(_)
         | SimpleExpr::Tuple(_)
+        | SimpleExpr::Array(_)
         | SimpleExpr::Constant(_)
         | SimpleExpr::FunctionCall(_)
         | SimpleExpr::Value(_)
         | SimpleExpr::Keyword(_)
         | SimpleExpr::Case(_)
+        | SimpleExpr::TypeName(_)
+        | SimpleExpr::AsEnum(_, _)
         | SimpleExpr::SubQuery(_, _) => true,
         SimpleExpr::Binary(_, inner_oper, _) => {
             let inner_oper: Oper = (*inner_oper).into();
@@ -0,0 +1,74 @@
+use std::fmt;
+
+/// The version of the Postgres server a [`QueryBuilder`](super::QueryBuilder) targets.
+///
+/// From Postgres 10 on a version is `major.minor`, where the minor number is the patch
+/// release; before it, the major version took two numbers, e.g. 9.6.24.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct ServerVersion {
+    pub major: u32,
+    pub minor: u32,
+    pub patch: u32,
+}
+
+impl ServerVersion {
+    /// The first version supporting `NULLS NOT DISTINCT` on unique indexes and constraints
+    pub const NULLS_NOT_DISTINCT: ServerVersion = ServerVersion::new(15, 0);
+
+    pub const fn new(major: u32, minor: u32) -> Self {
+        Self::with_patch(major, minor, 0)
+    }
+
+    /// A version before Postgres 10, e.g. `ServerVersion::with_patch(9, 6, 24)`
+    pub const fn with_patch(major: u32, minor: u32, patch: u32) -> Self {
+        Self {
+            major,
+            minor,
+            patch,
+        }
+    }
+
+    /// Parse the value of the `server_version_num` setting, e.g. `150004` for 15.4, or
+    /// `90624` for 9.6.24 before Postgres 10
+    ///
+    /// ```
+    /// use pgorm_query::ServerVersion;
+    ///
+    /// assert_eq!(
+    ///     ServerVersion::from_version_num(150004),
+    ///     ServerVersion::new(15, 4)
+    /// );
+    /// assert_eq!(
+    ///     ServerVersion::from_version_num(90624),
+    ///     ServerVersion::with_patch(9, 6, 24)
+    /// );
+    /// ```
+    pub const fn from_version_num(num: u32) -> Self {
+        if num < 100_000 {
+            Self::with_patch(num / 10000, num / 100 % 100, num % 100)
+        } else {
+            Self::new(num / 10000, num % 10000)
+        }
+    }
+
+    /// Whether this version is `version` or later
+    pub const fn at_least(self, version: ServerVersion) -> bool {
+        if self.major != version.major {
+            return self.major > version.major;
+        }
+        if self.minor != version.minor {
+            return self.minor > version.minor;
+        }
+        self.patch >= version.patch
+    }
+}
+
+impl fmt::Display for ServerVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        if self.major < 10 {
+            write!(f, "{}.{}.{}", self.major, self.minor, self.patch)
+        } else {
+            write!(f, "{}.{}", self.major, self.minor)
+        }
+    }
+}
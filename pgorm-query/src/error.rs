@@ -1,5 +1,7 @@
 //! Error types used in pgorm-query.
 
+use crate::ServerVersion;
+
 /// Result type for pgorm-query
 pub type Result<T> = std::result::Result<T, Error>;
 
@@ -7,6 +9,16 @@ pub type Result<T> = std::result::Result<T, Error>;
 pub enum Error {
     /// Column and value vector having different length
     ColValNumMismatch { col_len: usize, val_len: usize },
+    /// WITH clause without any common table expression
+    EmptyWithClause,
+    /// Recursive WITH clause with more than one common table expression
+    RecursiveWithClauseCteCount(usize),
+    /// A feature the targeted server version doesn't support
+    UnsupportedByServerVersion {
+        feature: &'static str,
+        required: ServerVersion,
+        target: ServerVersion,
+    },
 }
 
 impl std::error::Error for Error {}
@@ -18,6 +30,19 @@ impl std::fmt::Display for Error {
                 f,
                 "Columns and values length mismatch: {col_len} != {val_len}"
             ),
+            Self::EmptyWithClause => write!(f, "WITH clause has no common table expression"),
+            Self::RecursiveWithClauseCteCount(len) => write!(
+                f,
+                "Recursive WITH clause must have a single common table expression, not {len}"
+            ),
+            Self::UnsupportedByServerVersion {
+                feature,
+                required,
+                target,
+            } => write!(
+                f,
+                "{feature} requires Postgres {required}, targeting {target}"
+            ),
         }
     }
 }
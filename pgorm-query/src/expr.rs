@@ -4,7 +4,7 @@
 //!
 //! [`SimpleExpr`] is the expression common among select fields, where clauses and many other places.
 
-use crate::{func::*, query::*, types::*, value::*};
+use crate::{ColumnType, func::*, query::*, types::*, value::*};
 
 /// Helper to build a [`SimpleExpr`].
 #[derive(Debug, Clone)]
@@ -23,6 +23,7 @@ pub struct Expr {
 pub enum SimpleExpr {
     Column(ColumnRef),
     Tuple(Vec<SimpleExpr>),
+    Array(Vec<SimpleExpr>),
     Unary(UnOper, Box<SimpleExpr>),
     FunctionCall(FunctionCall),
     Binary(Box<SimpleExpr>, BinOper, Box<SimpleExpr>),
@@ -35,6 +36,7 @@ pub enum SimpleExpr {
     AsEnum(DynIden, Box<SimpleExpr>),
     Case(Box<CaseStatement>),
     Constant(Value),
+    TypeName(ColumnType),
 }
 
 pub(crate) mod private {
@@ -133,6 +135,23 @@ impl Expr {
     ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."size_w" = 1"#
     /// );
     /// ```
+    ///
+    /// A `(schema, table, column)` triple qualifies the column with its schema:
+    ///
+    /// ```
+    /// use pgorm_query::*;
+    ///
+    /// let query = Query::select()
+    ///     .column(Alias::new("id"))
+    ///     .from((Alias::new("analytics"), Alias::new("events")))
+    ///     .and_where(Expr::col((Alias::new("analytics"), Alias::new("events"), Alias::new("id"))).gt(1))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "id" FROM "analytics"."events" WHERE "analytics"."events"."id" > 1"#
+    /// );
+    /// ```
     pub fn col<T>(n: T) -> Self
     where
         T: IntoColumnRef,
@@ -140,6 +159,40 @@ impl Expr {
         Self::new_with_left(n.into_column_ref())
     }
 
+    /// Express the value an `INSERT` tried to write to column `n`, as `"excluded"."n"`, for
+    /// the `ON CONFLICT DO UPDATE` action to combine with the existing row.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns([Glyph::Id, Glyph::Aspect])
+    ///     .values_panic([1.into(), 2.into()])
+    ///     .on_conflict(
+    ///         OnConflict::column(Glyph::Id)
+    ///             .value(
+    ///                 Glyph::Aspect,
+    ///                 Expr::col((Glyph::Table, Glyph::Aspect)).add(Expr::excluded(Glyph::Aspect)),
+    ///             )
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"INSERT INTO "glyph" ("id", "aspect") VALUES (1, 2) ON CONFLICT ("id") DO UPDATE SET "aspect" = "glyph"."aspect" + "excluded"."aspect""#
+    /// );
+    /// ```
+    pub fn excluded<T>(n: T) -> Self
+    where
+        T: IntoIden,
+    {
+        Self::col((Alias::new("excluded").into_iden(), n.into_iden()))
+    }
+
     /// Wraps tuple of `SimpleExpr`, can be used for tuple comparison
     ///
     /// # Examples
@@ -169,6 +222,35 @@ impl Expr {
         ))
     }
 
+    /// Build an array from a list of expressions, `ARRAY[..]`. Unlike an array [`Value`],
+    /// the elements may be any expression, such as columns or arithmetic on them.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::array([
+    ///         Expr::col(Glyph::Aspect).add(1),
+    ///         Expr::col(Glyph::Image).into(),
+    ///     ]))
+    ///     .from(Glyph::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT ARRAY["aspect" + 1, "image"] FROM "glyph""#
+    /// );
+    /// ```
+    pub fn array<I, T>(exprs: I) -> SimpleExpr
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<SimpleExpr>,
+    {
+        SimpleExpr::Array(exprs.into_iter().map(Into::into).collect())
+    }
+
     /// Express a [`Value`], returning a [`Expr`].
     ///
     /// # Examples
@@ -353,6 +435,55 @@ impl Expr {
         )
     }
 
+    /// Express any custom expression with [`Value`], each value cast to its [`ColumnType`] with `::`.
+    /// Use this when comparing against a column whose type Postgres cannot infer for a bare
+    /// parameter, such as an enum. The name of an enum type is quoted.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let color = ColumnType::Enum {
+    ///     name: Alias::new("color").into_iden(),
+    ///     variants: vec![],
+    /// };
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::cust_typed(
+    ///         r#""color" = $1 AND "aspect" > $2"#,
+    ///         [(Value::from("red"), color), (Value::from(1), ColumnType::Double)],
+    ///     ))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" WHERE "color" = 'red'::"color" AND "aspect" > 1::double precision"#
+    /// );
+    /// ```
+    pub fn cust_typed<T, V, I>(s: T, v: I) -> SimpleExpr
+    where
+        T: Into<String>,
+        V: Into<Value>,
+        I: IntoIterator<Item = (V, ColumnType)>,
+    {
+        SimpleExpr::CustomWithExpr(
+            s.into(),
+            v.into_iter()
+                .map(|(v, col_type)| {
+                    SimpleExpr::CustomWithExpr(
+                        "$1::$2".to_owned(),
+                        vec![
+                            Into::<Value>::into(v).into(),
+                            SimpleExpr::TypeName(col_type),
+                        ],
+                    )
+                })
+                .collect(),
+        )
+    }
+
     /// Express any custom expression with [`SimpleExpr`]. Use this if your expression needs other expression.
     ///
     /// # Examples
@@ -865,7 +996,7 @@ impl Expr {
     ///
     /// assert_eq!(
     ///     query.to_string(QueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" LIKE E'Ours\'%'"#
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" LIKE 'Ours''%'"#
     /// );
     /// ```
     ///
@@ -990,6 +1121,41 @@ impl Expr {
         self.binary(BinOper::IsNot, v)
     }
 
+    /// Express a `IS DISTINCT FROM` expression, a `<>` treating `NULL` as a comparable value.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).is_distinct_from(Expr::col(Char::SizeH)))
+    ///     .and_where(Expr::col(Char::FontId).is_not_distinct_from(Option::<i32>::None))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "character" FROM "character" WHERE "size_w" IS DISTINCT FROM "size_h" AND "font_id" IS NOT DISTINCT FROM NULL"#
+    /// );
+    /// ```
+    pub fn is_distinct_from<V>(self, v: V) -> SimpleExpr
+    where
+        V: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::IsDistinctFrom, v)
+    }
+
+    /// Express a `IS NOT DISTINCT FROM` expression, a `=` treating `NULL` as a comparable
+    /// value, see [`Expr::is_distinct_from`].
+    pub fn is_not_distinct_from<V>(self, v: V) -> SimpleExpr
+    where
+        V: Into<SimpleExpr>,
+    {
+        self.binary(BinOper::IsNotDistinctFrom, v)
+    }
+
     /// Create any binary operation
     ///
     /// # Examples
@@ -1439,6 +1605,25 @@ impl Expr {
     }
 
     /// Express a `ALL` sub-query expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).gt(Expr::all(
+    ///         Query::select().column(Char::SizeH).from(Char::Table).take(),
+    ///     )))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "size_w" > ALL(SELECT "size_h" FROM "character")"#
+    /// );
+    /// ```
     pub fn all(sel: SelectStatement) -> SimpleExpr {
         SimpleExpr::SubQuery(
             Some(SubQueryOper::All),
@@ -1537,7 +1722,7 @@ impl Expr {
         SimpleExpr::FunctionCall(func)
     }
 
-    /// Keyword `CURRENT_TIMESTAMP`.
+    /// Keyword `CURRENT_DATE`.
     ///
     /// # Examples
     ///
@@ -1555,7 +1740,7 @@ impl Expr {
         Expr::new_with_left(Keyword::CurrentDate)
     }
 
-    /// Keyword `CURRENT_TIMESTAMP`.
+    /// Keyword `CURRENT_TIME`.
     ///
     /// # Examples
     ///
@@ -1591,6 +1776,28 @@ impl Expr {
         Expr::new_with_left(Keyword::CurrentTimestamp)
     }
 
+    /// Function `NOW()`, the start time of the current transaction.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Id)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Alias::new("created_at")).lt(Expr::now()))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "id" FROM "character" WHERE "created_at" < NOW()"#
+    /// );
+    /// ```
+    pub fn now() -> Expr {
+        Expr::new_with_left(Func::cust(Alias::new("NOW")))
+    }
+
     /// Custom keyword.
     ///
     /// # Examples
@@ -1732,7 +1939,7 @@ impl Expr {
     ///
     /// assert_eq!(
     ///     query.to_string(QueryBuilder),
-    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" ILIKE E'Ours\'%'"#
+    ///     r#"SELECT "character", "size_w", "size_h" FROM "character" WHERE "character"."character" ILIKE 'Ours''%'"#
     /// );
     /// ```
     pub fn ilike<L>(self, like: L) -> SimpleExpr
@@ -1799,6 +2006,116 @@ impl Expr {
     {
         self.bin_op(BinOper::CastJsonField, right)
     }
+
+    /// Express a postgres removal of the JSON field or array element at a path (`#-`).
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Font::Table)
+    ///     .value(Font::Variant, Expr::col(Font::Variant).delete_json_path(vec!["a".to_owned(), "b".to_owned()]))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"UPDATE "font" SET "variant" = "variant" #- ARRAY ['a','b']"#
+    /// );
+    /// ```
+    pub fn delete_json_path<T>(self, path: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_op(BinOper::DeleteJsonPath, path)
+    }
+
+    /// Express a postgres `AT TIME ZONE` expression. A `timestamp` is taken to be in the
+    /// given zone and becomes a `timestamptz`; a `timestamptz` is converted to the local
+    /// time of the given zone and becomes a `timestamp`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Expr::col(Char::CreatedAt).at_time_zone("UTC"))
+    ///     .from(Char::Table)
+    ///     .and_where(
+    ///         Expr::expr(Expr::col(Char::CreatedAt).at_time_zone("Europe/Paris"))
+    ///             .lt("2024-01-01 09:00:00"),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     [
+    ///         r#"SELECT "created_at" AT TIME ZONE 'UTC' FROM "character""#,
+    ///         r#"WHERE ("created_at" AT TIME ZONE 'Europe/Paris') < '2024-01-01 09:00:00'"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn at_time_zone<T>(self, zone: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_op(BinOper::AtTimeZone, zone)
+    }
+
+    /// Express a postgres `<<` on `inet`/`cidr`: the network is strictly contained within
+    /// the given one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Id)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::col(Alias::new("address")).subnet_of(Expr::val("10.0.0.0/8")))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "id" FROM "font" WHERE "address" << '10.0.0.0/8'"#
+    /// );
+    /// ```
+    pub fn subnet_of<T>(self, network: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_op(BinOper::SubnetOf, network)
+    }
+
+    /// Express a postgres `>>` on `inet`/`cidr`: the network strictly contains the given
+    /// one.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{*, tests_cfg::*};
+    ///
+    /// let query = Query::select()
+    ///     .column(Font::Id)
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::col(Alias::new("network")).supernet_of(Expr::val("10.1.2.3")))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "id" FROM "font" WHERE "network" >> '10.1.2.3'"#
+    /// );
+    /// ```
+    pub fn supernet_of<T>(self, network: T) -> SimpleExpr
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_op(BinOper::SupernetOf, network)
+    }
 }
 
 impl From<Expr> for SimpleExpr {
@@ -2206,4 +2523,32 @@ impl SimpleExpr {
     {
         self.bin_op(BinOper::Concatenate, right)
     }
+
+    /// Express a postgres `AT TIME ZONE` expression.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(
+    ///         Expr::col(Char::CreatedAt)
+    ///             .add(Expr::cust("INTERVAL '1 day'"))
+    ///             .at_time_zone("UTC"),
+    ///     )
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT ("created_at" + (INTERVAL '1 day')) AT TIME ZONE 'UTC' FROM "character""#
+    /// );
+    /// ```
+    pub fn at_time_zone<T>(self, zone: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.bin_op(BinOper::AtTimeZone, zone)
+    }
 }
@@ -1,6 +1,6 @@
 use core::fmt;
 
-use crate::{DynIden, Iden, IntoIden, PgInterval, QueryBuilder, SqlWriter};
+use crate::{ConfiguredQueryBuilder, DynIden, Iden, IntoIden, PgInterval, SqlWriter};
 
 /// Creates a new "CREATE or DROP EXTENSION" statement for PostgreSQL
 ///
@@ -183,22 +183,22 @@ impl ExtensionDropStatement {
 macro_rules! impl_extension_statement_builder {
     ( $struct_name: ident, $func_name: ident ) => {
         impl $struct_name {
-            pub fn build_ref(&self, extension_builder: &QueryBuilder) -> String {
+            pub fn build_ref(&self, extension_builder: &ConfiguredQueryBuilder) -> String {
                 let mut sql = String::with_capacity(256);
                 self.build_collect_ref(extension_builder, &mut sql)
             }
 
             pub fn build_collect(
                 &self,
-                extension_builder: QueryBuilder,
+                extension_builder: impl Into<ConfiguredQueryBuilder>,
                 sql: &mut dyn SqlWriter,
             ) -> String {
-                self.build_collect_ref(&extension_builder, sql)
+                self.build_collect_ref(&extension_builder.into(), sql)
             }
 
             pub fn build_collect_ref(
                 &self,
-                extension_builder: &QueryBuilder,
+                extension_builder: &ConfiguredQueryBuilder,
                 sql: &mut dyn SqlWriter,
             ) -> String {
                 extension_builder.$func_name(self, sql);
@@ -206,8 +206,11 @@ macro_rules! impl_extension_statement_builder {
             }
 
             /// Build corresponding SQL statement and return SQL string
-            pub fn to_string(&self, extension_builder: QueryBuilder) -> String {
-                self.build_ref(&extension_builder)
+            pub fn to_string(
+                &self,
+                extension_builder: impl Into<ConfiguredQueryBuilder>,
+            ) -> String {
+                self.build_ref(&extension_builder.into())
             }
         }
     };
@@ -811,22 +814,22 @@ impl TypeAlterOpt {
 macro_rules! impl_type_statement_builder {
     ( $struct_name: ident, $func_name: ident ) => {
         impl $struct_name {
-            pub fn build_ref(&self, type_builder: &QueryBuilder) -> String {
+            pub fn build_ref(&self, type_builder: &ConfiguredQueryBuilder) -> String {
                 let mut sql = String::with_capacity(256);
                 self.build_collect_ref(type_builder, &mut sql)
             }
 
             pub fn build_collect(
                 &self,
-                type_builder: QueryBuilder,
+                type_builder: impl Into<ConfiguredQueryBuilder>,
                 sql: &mut dyn SqlWriter,
             ) -> String {
-                self.build_collect_ref(&type_builder, sql)
+                self.build_collect_ref(&type_builder.into(), sql)
             }
 
             pub fn build_collect_ref(
                 &self,
-                type_builder: &QueryBuilder,
+                type_builder: &ConfiguredQueryBuilder,
                 sql: &mut dyn SqlWriter,
             ) -> String {
                 type_builder.$func_name(self, sql);
@@ -834,8 +837,8 @@ macro_rules! impl_type_statement_builder {
             }
 
             /// Build corresponding SQL statement and return SQL string
-            pub fn to_string(&self, type_builder: QueryBuilder) -> String {
-                self.build_ref(&type_builder)
+            pub fn to_string(&self, type_builder: impl Into<ConfiguredQueryBuilder>) -> String {
+                self.build_ref(&type_builder.into())
             }
         }
     };
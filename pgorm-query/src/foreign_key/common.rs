@@ -10,6 +10,7 @@ pub struct TableForeignKey {
     pub(crate) ref_columns: Vec<DynIden>,
     pub(crate) on_delete: Option<ForeignKeyAction>,
     pub(crate) on_update: Option<ForeignKeyAction>,
+    pub(crate) not_valid: bool,
 }
 
 /// Foreign key on update & on delete actions
@@ -85,6 +86,12 @@ impl TableForeignKey {
         self
     }
 
+    /// Add the constraint as `NOT VALID`, skipping the check of existing rows
+    pub fn not_valid(&mut self) -> &mut Self {
+        self.not_valid = true;
+        self
+    }
+
     pub fn get_ref_table(&self) -> Option<&TableRef> {
         self.ref_table.as_ref()
     }
@@ -108,6 +115,10 @@ impl TableForeignKey {
         self.on_update
     }
 
+    pub fn is_not_valid(&self) -> bool {
+        self.not_valid
+    }
+
     pub fn take(&mut self) -> Self {
         Self {
             name: self.name.take(),
@@ -117,6 +128,7 @@ impl TableForeignKey {
             ref_columns: std::mem::take(&mut self.ref_columns),
             on_delete: self.on_delete.take(),
             on_update: self.on_update.take(),
+            not_valid: std::mem::take(&mut self.not_valid),
         }
     }
 }
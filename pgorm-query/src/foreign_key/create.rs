@@ -1,6 +1,8 @@
 use inherent::inherent;
 
-use crate::{ForeignKeyAction, QueryBuilder, SchemaStatementBuilder, TableForeignKey, types::*};
+use crate::{
+    ConfiguredQueryBuilder, ForeignKeyAction, SchemaStatementBuilder, TableForeignKey, types::*,
+};
 
 /// Create a foreign key constraint for an existing table. Unsupported by Sqlite
 ///
@@ -170,6 +172,34 @@ impl ForeignKeyCreateStatement {
         self
     }
 
+    /// Add the constraint as `NOT VALID`, skipping the check of existing rows
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{*, tests_cfg::*};
+    ///
+    /// let foreign_key = ForeignKey::create()
+    ///     .name("FK_character_font")
+    ///     .from(Char::Table, Char::FontId)
+    ///     .to(Font::Table, Font::Id)
+    ///     .not_valid()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     foreign_key.to_string(QueryBuilder),
+    ///     [
+    ///         r#"ALTER TABLE "character" ADD CONSTRAINT "FK_character_font""#,
+    ///         r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id") NOT VALID"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn not_valid(&mut self) -> &mut Self {
+        self.foreign_key.not_valid();
+        self
+    }
+
     pub fn get_foreign_key(&self) -> &TableForeignKey {
         &self.foreign_key
     }
@@ -183,17 +213,19 @@ impl ForeignKeyCreateStatement {
 
 #[inherent]
 impl SchemaStatementBuilder for ForeignKeyCreateStatement {
-    pub fn build(&self, schema_builder: QueryBuilder) -> String {
+    pub fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        schema_builder.prepare_foreign_key_create_statement(self, &mut sql);
+        schema_builder
+            .into()
+            .prepare_foreign_key_create_statement(self, &mut sql);
         sql
     }
 
-    pub fn build_any(&self, schema_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String {
         let mut sql = String::with_capacity(256);
         schema_builder.prepare_foreign_key_create_statement(self, &mut sql);
         sql
     }
 
-    pub fn to_string(&self, schema_builder: QueryBuilder) -> String;
+    pub fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
@@ -1,6 +1,6 @@
 use inherent::inherent;
 
-use crate::{QueryBuilder, SchemaStatementBuilder, TableForeignKey, types::*};
+use crate::{ConfiguredQueryBuilder, SchemaStatementBuilder, TableForeignKey, types::*};
 
 /// Drop a foreign key constraint for an existing table
 ///
@@ -52,17 +52,19 @@ impl ForeignKeyDropStatement {
 
 #[inherent]
 impl SchemaStatementBuilder for ForeignKeyDropStatement {
-    pub fn build(&self, schema_builder: QueryBuilder) -> String {
+    pub fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        schema_builder.prepare_foreign_key_drop_statement(self, &mut sql);
+        schema_builder
+            .into()
+            .prepare_foreign_key_drop_statement(self, &mut sql);
         sql
     }
 
-    pub fn build_any(&self, schema_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String {
         let mut sql = String::with_capacity(256);
         schema_builder.prepare_foreign_key_drop_statement(self, &mut sql);
         sql
     }
 
-    pub fn to_string(&self, schema_builder: QueryBuilder) -> String;
+    pub fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
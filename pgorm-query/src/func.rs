@@ -1,6 +1,6 @@
 //! For calling built-in SQL functions.
 
-use crate::{expr::*, types::*};
+use crate::{ColumnType, expr::*, types::*};
 
 /// Functions
 #[derive(Debug, Clone, PartialEq)]
@@ -34,6 +34,36 @@ pub enum Function {
     Any,
     Some,
     All,
+    Extract(DatePart),
+    DateTrunc,
+    MacaddrTrunc,
+}
+
+/// Field of a date, time or interval value, as taken by `EXTRACT`
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum DatePart {
+    Century,
+    Day,
+    Decade,
+    Dow,
+    Doy,
+    Epoch,
+    Hour,
+    IsoDow,
+    IsoYear,
+    Julian,
+    Microseconds,
+    Millennium,
+    Milliseconds,
+    Minute,
+    Month,
+    Quarter,
+    Second,
+    Timezone,
+    TimezoneHour,
+    TimezoneMinute,
+    Week,
+    Year,
 }
 
 /// Function call.
@@ -44,9 +74,11 @@ pub struct FunctionCall {
     pub(crate) mods: Vec<FuncArgMod>,
 }
 
-#[derive(Debug, Default, Copy, Clone, PartialEq)]
+#[derive(Debug, Default, Clone, PartialEq)]
 pub struct FuncArgMod {
     pub distinct: bool,
+    pub variadic: bool,
+    pub name: Option<DynIden>,
 }
 
 impl FunctionCall {
@@ -66,6 +98,65 @@ impl FunctionCall {
         self.arg_with(arg, Default::default())
     }
 
+    /// Append a named argument to the function call, written as `name => value`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::cust(Alias::new("make_interval")).named_arg(Alias::new("days"), 5))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT make_interval(days => 5)"#
+    /// );
+    /// ```
+    pub fn named_arg<N, T>(self, name: N, arg: T) -> Self
+    where
+        N: IntoIden,
+        T: Into<SimpleExpr>,
+    {
+        self.arg_with(
+            arg,
+            FuncArgMod {
+                name: Some(name.into_iden()),
+                ..Default::default()
+            },
+        )
+    }
+
+    /// Append an array to the variadic parameter of the function call, written as `VARIADIC value`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::cust(Alias::new("concat_ws")).arg(",").variadic_arg(Expr::cust("ARRAY['a', 'b']")))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT concat_ws(',', VARIADIC ARRAY['a', 'b'])"#
+    /// );
+    /// ```
+    pub fn variadic_arg<T>(self, arg: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.arg_with(
+            arg,
+            FuncArgMod {
+                variadic: true,
+                ..Default::default()
+            },
+        )
+    }
+
     pub(crate) fn arg_with<T>(mut self, arg: T, mod_: FuncArgMod) -> Self
     where
         T: Into<SimpleExpr>,
@@ -285,7 +376,13 @@ impl Func {
     where
         T: Into<SimpleExpr>,
     {
-        FunctionCall::new(Function::Count).arg_with(expr, FuncArgMod { distinct: true })
+        FunctionCall::new(Function::Count).arg_with(
+            expr,
+            FuncArgMod {
+                distinct: true,
+                ..Default::default()
+            },
+        )
     }
 
     /// Call `CHAR_LENGTH` function.
@@ -366,6 +463,65 @@ impl Func {
         ))
     }
 
+    /// Call `CAST` function with a [`ColumnType`], written as the type name a column of that
+    /// type is created with.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{*, tests_cfg::*};
+    ///
+    /// assert_eq!(
+    ///     Query::select()
+    ///         .expr(Func::cast_as_type(
+    ///             Expr::col(Character::Id),
+    ///             ColumnType::Text
+    ///         ))
+    ///         .from(Character::Table)
+    ///         .to_string(QueryBuilder),
+    ///     r#"SELECT CAST("id" AS text) FROM "character""#
+    /// );
+    /// ```
+    pub fn cast_as_type<V>(expr: V, column_type: ColumnType) -> FunctionCall
+    where
+        V: Into<SimpleExpr>,
+    {
+        let expr: SimpleExpr = expr.into();
+        FunctionCall::new(Function::Cast)
+            .arg(expr.binary(BinOper::As, SimpleExpr::TypeName(column_type)))
+    }
+
+    /// Call `CAST` function casting to an array of a custom element type, such as an enum.
+    /// The type name is quoted like any other identifier; cast to arrays of built-in types
+    /// with [`Func::cast_as_type`] and [`ColumnType::Array`].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{*, tests_cfg::*};
+    ///
+    /// assert_eq!(
+    ///     Query::select()
+    ///         .expr(Func::cast_as_array(
+    ///             Expr::val("{Bold,Italic}"),
+    ///             Alias::new("FontStyle")
+    ///         ))
+    ///         .to_string(QueryBuilder),
+    ///     r#"SELECT CAST('{Bold,Italic}' AS "FontStyle"[])"#
+    /// );
+    /// ```
+    pub fn cast_as_array<V, I>(expr: V, iden: I) -> FunctionCall
+    where
+        V: Into<SimpleExpr>,
+        I: IntoIden,
+    {
+        let elem_type = ColumnType::Enum {
+            name: iden.into_iden(),
+            variants: Vec::new(),
+        };
+        Self::cast_as_type(expr, ColumnType::Array(RcOrArc::new(elem_type)))
+    }
+
     /// Call `COALESCE` function.
     ///
     /// # Examples
@@ -859,4 +1015,86 @@ impl Func {
     pub fn gen_random_uuid() -> FunctionCall {
         FunctionCall::new(Function::GenRandomUUID)
     }
+
+    /// Call `EXTRACT` function, taking a field out of a date, time or interval.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::extract(DatePart::Year, Expr::col(Char::CreatedAt)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT EXTRACT(YEAR FROM "created_at") FROM "character""#
+    /// );
+    /// ```
+    pub fn extract<T>(part: DatePart, expr: T) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::Extract(part)).arg(expr)
+    }
+
+    /// Call `DATE_TRUNC` function, truncating a timestamp or interval to the given unit.
+    ///
+    /// The unit is written inline as a string literal rather than bound as a parameter,
+    /// so the same call can be repeated in `GROUP BY` and still match the select list.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::date_trunc("day", Expr::col(Char::CreatedAt)))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT DATE_TRUNC('day', "created_at") FROM "character""#
+    /// );
+    /// assert_eq!(
+    ///     query.build(QueryBuilder).0,
+    ///     r#"SELECT DATE_TRUNC('day', "created_at") FROM "character""#
+    /// );
+    /// ```
+    pub fn date_trunc<U, T>(unit: U, expr: T) -> FunctionCall
+    where
+        U: Into<String>,
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::DateTrunc)
+            .args([SimpleExpr::Constant(unit.into().into()), expr.into()])
+    }
+
+    /// Call `TRUNC` on a `macaddr`, setting the last 3 bytes to zero. The remaining prefix
+    /// identifies the manufacturer.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .expr(Func::macaddr_trunc(Expr::col(Alias::new("mac"))))
+    ///     .from(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT TRUNC("mac") FROM "character""#
+    /// );
+    /// ```
+    pub fn macaddr_trunc<T>(expr: T) -> FunctionCall
+    where
+        T: Into<SimpleExpr>,
+    {
+        FunctionCall::new(Function::MacaddrTrunc).arg(expr)
+    }
 }
@@ -1,6 +1,8 @@
 use inherent::inherent;
 
-use crate::{QueryBuilder, SchemaStatementBuilder, types::*};
+use crate::{
+    ConfiguredQueryBuilder, SchemaStatementBuilder, ServerVersion, error::Result, types::*,
+};
 
 use super::common::*;
 
@@ -206,6 +208,26 @@ impl IndexCreateStatement {
         &self.index
     }
 
+    /// Build the statement like [`SchemaStatementBuilder::build`], but fail instead of
+    /// falling back if it uses a feature the targeted server version lacks, see
+    /// [`ConfiguredQueryBuilder::server_version`].
+    pub fn try_build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> Result<String> {
+        let schema_builder = schema_builder.into();
+        self.check_server_version(&schema_builder)?;
+        Ok(self.build(schema_builder))
+    }
+
+    pub(crate) fn check_server_version(
+        &self,
+        schema_builder: &ConfiguredQueryBuilder,
+    ) -> Result<()> {
+        if self.nulls_not_distinct {
+            schema_builder
+                .check_version(ServerVersion::NULLS_NOT_DISTINCT, "NULLS NOT DISTINCT")?;
+        }
+        Ok(())
+    }
+
     pub fn take(&mut self) -> Self {
         Self {
             table: self.table.take(),
@@ -221,17 +243,19 @@ impl IndexCreateStatement {
 
 #[inherent]
 impl SchemaStatementBuilder for IndexCreateStatement {
-    pub fn build(&self, schema_builder: QueryBuilder) -> String {
+    pub fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        schema_builder.prepare_index_create_statement(self, &mut sql);
+        schema_builder
+            .into()
+            .prepare_index_create_statement(self, &mut sql);
         sql
     }
 
-    pub fn build_any(&self, schema_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String {
         let mut sql = String::with_capacity(256);
         schema_builder.prepare_index_create_statement(self, &mut sql);
         sql
     }
 
-    pub fn to_string(&self, schema_builder: QueryBuilder) -> String;
+    pub fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
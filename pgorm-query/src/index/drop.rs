@@ -1,6 +1,6 @@
 use inherent::inherent;
 
-use crate::{QueryBuilder, SchemaStatementBuilder, TableIndex, types::*};
+use crate::{ConfiguredQueryBuilder, SchemaStatementBuilder, TableIndex, types::*};
 
 /// Drop an index for an existing table
 ///
@@ -58,17 +58,19 @@ impl IndexDropStatement {
 
 #[inherent]
 impl SchemaStatementBuilder for IndexDropStatement {
-    pub fn build(&self, schema_builder: QueryBuilder) -> String {
+    pub fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        schema_builder.prepare_index_drop_statement(self, &mut sql);
+        schema_builder
+            .into()
+            .prepare_index_drop_statement(self, &mut sql);
         sql
     }
 
-    pub fn build_any(&self, schema_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String {
         let mut sql = String::with_capacity(256);
         schema_builder.prepare_index_drop_statement(self, &mut sql);
         sql
     }
 
-    pub fn to_string(&self, schema_builder: QueryBuilder) -> String;
+    pub fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
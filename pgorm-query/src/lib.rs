@@ -325,7 +325,7 @@
 //! ```rust
 //! # use pgorm_query::*;
 //! # trait ExampleSchemaBuilder {
-//! fn build(&self, schema_builder: QueryBuilder) -> String;
+//! fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 //! # }
 //! ```
 //!
@@ -334,9 +334,9 @@
 //! ```rust
 //! # use pgorm_query::*;
 //! # trait ExampleQueryBuilder {
-//! fn build(&self, query_builder: QueryBuilder) -> (String, Values);
+//! fn build(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> (String, Values);
 //!
-//! fn to_string(&self, query_builder: QueryBuilder) -> String;
+//! fn to_string(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 //! # }
 //! ```
 //!
@@ -640,6 +640,7 @@ pub mod extension;
 pub mod foreign_key;
 pub mod func;
 pub mod index;
+pub mod prelude;
 pub mod prepare;
 pub mod query;
 pub mod schema;
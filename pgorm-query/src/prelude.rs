@@ -0,0 +1,32 @@
+//! Commonly used items, for a single glob import.
+//!
+//! ```
+//! use pgorm_query::prelude::*;
+//!
+//! let query = Query::select()
+//!     .column(Asterisk)
+//!     .from(Alias::new("character"))
+//!     .cond_where(
+//!         Cond::any()
+//!             .add(Expr::col(Alias::new("font_id")).is_null())
+//!             .add(Expr::col(Alias::new("size_w")).gt(Func::abs(Expr::val(-3)))),
+//!     )
+//!     .order_by(Alias::new("id"), Order::Desc)
+//!     .to_owned();
+//!
+//! let (sql, values) = query.build(QueryBuilder);
+//! assert_eq!(
+//!     sql,
+//!     r#"SELECT * FROM "character" WHERE "font_id" IS NULL OR "size_w" > ABS($1) ORDER BY "id" DESC"#
+//! );
+//! assert_eq!(values, Values(vec![Value::Int(Some(-3))]));
+//! ```
+
+pub use crate::{
+    Alias, Asterisk, CaseStatement, ColumnDef, ColumnRef, ColumnType, Cond, Condition,
+    ConditionalStatement, DeleteStatement, DynIden, Expr, ForeignKey, ForeignKeyAction, Func, Iden,
+    IdenStatic, Index, InsertStatement, IntoColumnRef, IntoCondition, IntoIden, IntoTableRef,
+    JoinType, Keyword, LockType, NullOrdering, OnConflict, Order, OrderedStatement, Query,
+    QueryBuilder, QueryStatementBuilder, QueryStatementWriter, SchemaStatementBuilder, SeaRc,
+    SelectStatement, SimpleExpr, Table, TableRef, UpdateStatement, Value, Values, WithClause,
+};
@@ -4,13 +4,13 @@ use crate::*;
 pub use std::fmt::Write;
 
 pub trait SqlWriter: Write + ToString {
-    fn push_param(&mut self, value: Value, query_builder: &QueryBuilder);
+    fn push_param(&mut self, value: Value, query_builder: &ConfiguredQueryBuilder);
 
     fn as_writer(&mut self) -> &mut dyn Write;
 }
 
 impl SqlWriter for String {
-    fn push_param(&mut self, value: Value, query_builder: &QueryBuilder) {
+    fn push_param(&mut self, value: Value, query_builder: &ConfiguredQueryBuilder) {
         self.push_str(&query_builder.value_to_string(&value))
     }
 
@@ -60,7 +60,7 @@ impl std::fmt::Display for SqlWriterValues {
 }
 
 impl SqlWriter for SqlWriterValues {
-    fn push_param(&mut self, value: Value, _: &QueryBuilder) {
+    fn push_param(&mut self, value: Value, _: &ConfiguredQueryBuilder) {
         self.counter += 1;
         if self.numbered {
             let counter = self.counter;
@@ -76,7 +76,61 @@ impl SqlWriter for SqlWriterValues {
     }
 }
 
-pub fn inject_parameters<I>(sql: &str, params: I, query_builder: &QueryBuilder) -> String
+/// A statement built once into SQL, to be executed repeatedly with different values.
+///
+/// Built by [`QueryStatementWriter::compile`](crate::QueryStatementWriter::compile).
+#[derive(Debug, Clone, PartialEq)]
+pub struct CompiledQuery {
+    sql: String,
+    values: Values,
+}
+
+impl CompiledQuery {
+    pub fn new(sql: String, values: Values) -> Self {
+        Self { sql, values }
+    }
+
+    /// The SQL with numbered placeholders
+    pub fn sql(&self) -> &str {
+        &self.sql
+    }
+
+    /// The values the statement was compiled with, in placeholder order
+    pub fn values(&self) -> &Values {
+        &self.values
+    }
+
+    /// Values to execute the SQL with, in placeholder order.
+    ///
+    /// Fails if the number of values differs from the number of placeholders, or if a value
+    /// is not of the same type as the one it replaces; a NULL of that type is accepted.
+    pub fn bind<I>(&self, values: I) -> Result<Values, ValueTypeErr>
+    where
+        I: IntoIterator,
+        I::Item: Into<Value>,
+    {
+        let values: Vec<Value> = values.into_iter().map(Into::into).collect();
+        if values.len() != self.values.0.len() {
+            return Err(ValueTypeErr);
+        }
+        for (old, new) in self.values.iter().zip(values.iter()) {
+            let same_type = match (old, new) {
+                (Value::Array(old, _), Value::Array(new, _)) => old == new,
+                _ => std::mem::discriminant(old) == std::mem::discriminant(new),
+            };
+            if !same_type {
+                return Err(ValueTypeErr);
+            }
+        }
+        Ok(Values(values))
+    }
+
+    pub fn into_parts(self) -> (String, Values) {
+        (self.sql, self.values)
+    }
+}
+
+pub fn inject_parameters<I>(sql: &str, params: I, query_builder: &ConfiguredQueryBuilder) -> String
 where
     I: IntoIterator<Item = Value>,
 {
@@ -115,11 +169,62 @@ where
     output.into_iter().collect()
 }
 
+/// Shift every `$N` placeholder in `sql` by `offset`, leaving quoted strings and
+/// identifiers untouched, so that fragments can be concatenated into one statement.
+///
+/// ```
+/// use pgorm_query::renumber_placeholders;
+///
+/// assert_eq!(
+///     renumber_placeholders("a = $1 AND b = $2", 3),
+///     "a = $4 AND b = $5"
+/// );
+/// ```
+pub fn renumber_placeholders(sql: &str, offset: usize) -> String {
+    let tokenizer = Tokenizer::new(sql);
+    let tokens: Vec<Token> = tokenizer.iter().collect();
+    let mut output = String::with_capacity(sql.len());
+    let mut i = 0;
+    while i < tokens.len() {
+        let token = &tokens[i];
+        if let (Token::Punctuation(mark), Some(Token::Unquoted(next))) = (token, tokens.get(i + 1))
+        {
+            if mark == "$" {
+                if let Ok(num) = next.parse::<usize>() {
+                    write!(output, "${}", num + offset).unwrap();
+                    i += 2;
+                    continue;
+                }
+            }
+        }
+        write!(output, "{token}").unwrap();
+        i += 1;
+    }
+    output
+}
+
 #[cfg(test)]
 mod tests_postgres {
     use super::*;
     use pretty_assertions::assert_eq;
 
+    #[test]
+    fn compiled_query_bind() {
+        let compiled = CompiledQuery::new(
+            "WHERE A = $1 AND C = $2".to_owned(),
+            Values(vec![1.into(), "D".into()]),
+        );
+        assert_eq!(
+            compiled
+                .bind([Value::from(2), Value::String(None)])
+                .unwrap(),
+            Values(vec![2.into(), Value::String(None)])
+        );
+        assert!(compiled.bind([2]).is_err());
+        assert!(compiled.bind([2, 3]).is_err());
+        assert!(compiled.bind([Value::from(2i64), "D".into()]).is_err());
+    }
+
     #[test]
     fn inject_parameters_5() {
         assert_eq!(
@@ -148,7 +253,23 @@ mod tests_postgres {
     fn inject_parameters_7() {
         assert_eq!(
             inject_parameters("WHERE A = $1", ["B'C".into()], &QueryBuilder),
-            "WHERE A = E'B\\'C'"
+            "WHERE A = 'B''C'"
+        );
+    }
+
+    #[test]
+    fn renumber_placeholders_1() {
+        assert_eq!(
+            renumber_placeholders("WHERE A = $1 AND C = $2", 3),
+            "WHERE A = $4 AND C = $5"
+        );
+    }
+
+    #[test]
+    fn renumber_placeholders_2() {
+        assert_eq!(
+            renumber_placeholders(r#"WHERE "a$1" = '$1' AND b = $1"#, 3),
+            r#"WHERE "a$1" = '$1' AND b = $4"#
         );
     }
 }
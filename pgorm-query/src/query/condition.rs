@@ -596,6 +596,37 @@ impl IntoCondition for Condition {
     }
 }
 
+/// A closure building on an empty [`Condition::all`]
+///
+/// ```
+/// use pgorm_query::{tests_cfg::*, *};
+///
+/// let query = Query::select()
+///     .column(Glyph::Id)
+///     .from(Glyph::Table)
+///     .cond_where(|cond: Condition| {
+///         cond.add(Expr::col(Glyph::Aspect).gt(1)).add(
+///             Condition::any()
+///                 .add(Expr::col(Glyph::Id).eq(1))
+///                 .add(Expr::col(Glyph::Id).eq(2)),
+///         )
+///     })
+///     .to_owned();
+///
+/// assert_eq!(
+///     query.to_string(QueryBuilder),
+///     r#"SELECT "id" FROM "glyph" WHERE "aspect" > 1 AND ("id" = 1 OR "id" = 2)"#
+/// );
+/// ```
+impl<F> IntoCondition for F
+where
+    F: FnOnce(Condition) -> Condition,
+{
+    fn into_condition(self) -> Condition {
+        self(Condition::all())
+    }
+}
+
 impl ConditionHolder {
     pub fn new() -> Self {
         Self::default()
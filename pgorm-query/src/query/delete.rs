@@ -1,7 +1,7 @@
 use crate::{
     QueryStatementBuilder, QueryStatementWriter, ReturningClause, SimpleExpr, SubQueryStatement,
     WithClause, WithQuery,
-    backend::QueryBuilder,
+    backend::ConfiguredQueryBuilder,
     prepare::*,
     query::{OrderedStatement, condition::*},
     types::*,
@@ -182,7 +182,11 @@ impl DeleteStatement {
 
 #[inherent]
 impl QueryStatementBuilder for DeleteStatement {
-    pub fn build_collect_any_into(&self, query_builder: &QueryBuilder, sql: &mut dyn SqlWriter) {
+    pub fn build_collect_any_into(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
         query_builder.prepare_delete_statement(self, sql);
     }
 
@@ -190,23 +194,31 @@ impl QueryStatementBuilder for DeleteStatement {
         SubQueryStatement::DeleteStatement(self)
     }
 
-    pub fn build_any(&self, query_builder: &QueryBuilder) -> (String, Values);
+    pub fn build_any(&self, query_builder: &ConfiguredQueryBuilder) -> (String, Values);
     pub fn build_collect_any(
         &self,
-        query_builder: &QueryBuilder,
+        query_builder: &ConfiguredQueryBuilder,
         sql: &mut dyn SqlWriter,
     ) -> String;
 }
 
 #[inherent]
 impl QueryStatementWriter for DeleteStatement {
-    pub fn build_collect_into(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) {
-        query_builder.prepare_delete_statement(self, sql);
+    pub fn build_collect_into(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.into().prepare_delete_statement(self, sql);
     }
 
-    pub fn build_collect(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) -> String;
-    pub fn build(&self, query_builder: QueryBuilder) -> (String, Values);
-    pub fn to_string(&self, query_builder: QueryBuilder) -> String;
+    pub fn build_collect(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+    pub fn build(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> (String, Values);
+    pub fn to_string(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
 
 #[inherent]
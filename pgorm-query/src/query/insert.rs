@@ -1,7 +1,7 @@
 use crate::{
     OnConflict, QueryStatementBuilder, QueryStatementWriter, ReturningClause, SelectStatement,
-    SimpleExpr, SubQueryStatement, Values, WithClause, WithQuery, backend::QueryBuilder, error::*,
-    prepare::*, types::*,
+    SimpleExpr, SubQueryStatement, Values, WithClause, WithQuery, backend::ConfiguredQueryBuilder,
+    error::*, prepare::*, types::*,
 };
 use inherent::inherent;
 
@@ -447,7 +447,11 @@ impl InsertStatement {
 
 #[inherent]
 impl QueryStatementBuilder for InsertStatement {
-    pub fn build_collect_any_into(&self, query_builder: &QueryBuilder, sql: &mut dyn SqlWriter) {
+    pub fn build_collect_any_into(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
         query_builder.prepare_insert_statement(self, sql);
     }
 
@@ -455,21 +459,29 @@ impl QueryStatementBuilder for InsertStatement {
         SubQueryStatement::InsertStatement(self)
     }
 
-    pub fn build_any(&self, query_builder: &QueryBuilder) -> (String, Values);
+    pub fn build_any(&self, query_builder: &ConfiguredQueryBuilder) -> (String, Values);
     pub fn build_collect_any(
         &self,
-        query_builder: &QueryBuilder,
+        query_builder: &ConfiguredQueryBuilder,
         sql: &mut dyn SqlWriter,
     ) -> String;
 }
 
 #[inherent]
 impl QueryStatementWriter for InsertStatement {
-    pub fn build_collect_into(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) {
-        query_builder.prepare_insert_statement(self, sql);
+    pub fn build_collect_into(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.into().prepare_insert_statement(self, sql);
     }
 
-    pub fn build_collect(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) -> String;
-    pub fn build(&self, query_builder: QueryBuilder) -> (String, Values);
-    pub fn to_string(&self, query_builder: QueryBuilder) -> String;
+    pub fn build_collect(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+    pub fn build(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> (String, Values);
+    pub fn to_string(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
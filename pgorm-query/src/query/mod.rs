@@ -6,6 +6,7 @@
 //! - Query Insert, see [`InsertStatement`]
 //! - Query Update, see [`UpdateStatement`]
 //! - Query Delete, see [`DeleteStatement`]
+//! - Query Values, see [`ValuesStatement`]
 
 mod case;
 mod condition;
@@ -17,6 +18,7 @@ mod returning;
 mod select;
 mod traits;
 mod update;
+mod values;
 mod window;
 mod with;
 
@@ -30,9 +32,12 @@ pub use returning::*;
 pub use select::*;
 pub use traits::*;
 pub use update::*;
+pub use values::*;
 pub use window::*;
 pub use with::*;
 
+use crate::IntoValueTuple;
+
 /// Shorthand for constructing any table query
 #[derive(Debug, Clone)]
 pub struct Query;
@@ -52,6 +57,7 @@ pub enum SubQueryStatement {
     InsertStatement(InsertStatement),
     UpdateStatement(UpdateStatement),
     DeleteStatement(DeleteStatement),
+    ValuesStatement(ValuesStatement),
     WithStatement(WithQuery),
 }
 
@@ -76,6 +82,15 @@ impl Query {
         DeleteStatement::new()
     }
 
+    /// Construct [`ValuesStatement`] from constant rows
+    pub fn values<I, V>(rows: I) -> ValuesStatement
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoValueTuple,
+    {
+        ValuesStatement::new().rows(rows).to_owned()
+    }
+
     /// Construct [`WithClause`]
     pub fn with() -> WithClause {
         WithClause::new()
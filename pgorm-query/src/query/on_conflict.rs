@@ -6,6 +6,7 @@ pub struct OnConflict {
     pub(crate) target_where: ConditionHolder,
     pub(crate) action: Option<OnConflictAction>,
     pub(crate) action_where: ConditionHolder,
+    pub(crate) changed_columns: Vec<DynIden>,
 }
 
 /// Represents ON CONFLICT (upsert) targets
@@ -64,6 +65,7 @@ impl OnConflict {
             target_where: ConditionHolder::new(),
             action: None,
             action_where: ConditionHolder::new(),
+            changed_columns: Vec::new(),
         }
     }
 
@@ -279,6 +281,47 @@ impl OnConflict {
         self
     }
 
+    /// Set ON CONFLICT update columns like [`OnConflict::update_columns`], but only update the
+    /// existing row if one of them differs from the inserted value. The columns are compared
+    /// with `IS DISTINCT FROM`, so `NULL` equals `NULL`, qualified by the table inserted into.
+    /// A skipped update fires no triggers and does not return the row with `RETURNING`.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::insert()
+    ///     .into_table(Glyph::Table)
+    ///     .columns([Glyph::Id, Glyph::Aspect, Glyph::Image])
+    ///     .values_panic([1.into(), 2.into(), 3.into()])
+    ///     .on_conflict(
+    ///         OnConflict::column(Glyph::Id)
+    ///             .update_columns_if_changed([Glyph::Aspect, Glyph::Image])
+    ///             .to_owned(),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     [
+    ///         r#"INSERT INTO "glyph" ("id", "aspect", "image") VALUES (1, 2, 3)"#,
+    ///         r#"ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect", "image" = "excluded"."image""#,
+    ///         r#"WHERE "glyph"."aspect" IS DISTINCT FROM "excluded"."aspect" OR "glyph"."image" IS DISTINCT FROM "excluded"."image""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn update_columns_if_changed<C, I>(&mut self, columns: I) -> &mut Self
+    where
+        C: IntoIden,
+        I: IntoIterator<Item = C>,
+    {
+        let columns: Vec<DynIden> = columns.into_iter().map(IntoIden::into_iden).collect();
+        self.changed_columns.extend(columns.iter().cloned());
+        self.update_columns(columns)
+    }
+
     /// Set ON CONFLICT update exprs
     ///
     /// # Examples
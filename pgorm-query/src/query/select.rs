@@ -1,7 +1,7 @@
 use crate::{
     FunctionCall, QueryStatementBuilder, QueryStatementWriter, SubQueryStatement, WindowStatement,
     WithClause, WithQuery,
-    backend::QueryBuilder,
+    backend::ConfiguredQueryBuilder,
     expr::*,
     prepare::*,
     query::{OrderedStatement, condition::*},
@@ -39,6 +39,7 @@ pub struct SelectStatement {
     pub(crate) join: Vec<JoinExpr>,
     pub(crate) r#where: ConditionHolder,
     pub(crate) groups: Vec<SimpleExpr>,
+    pub(crate) grouping: Vec<GroupingSpec>,
     pub(crate) having: ConditionHolder,
     pub(crate) unions: Vec<(UnionType, SelectStatement)>,
     pub(crate) orders: Vec<OrderExpr>,
@@ -46,6 +47,7 @@ pub struct SelectStatement {
     pub(crate) offset: Option<Value>,
     pub(crate) lock: Option<LockClause>,
     pub(crate) window: Option<(DynIden, WindowStatement)>,
+    pub(crate) table_sample: Option<Box<TableSample>>,
 }
 
 /// List of distinct keywords that can be used in select statement
@@ -57,6 +59,17 @@ pub enum SelectDistinct {
     DistinctOn(Vec<ColumnRef>),
 }
 
+/// Grouping specification in the `GROUP BY` clause, written after the plain group by expressions
+#[derive(Debug, Clone, PartialEq)]
+pub enum GroupingSpec {
+    /// `ROLLUP (a, b)`
+    Rollup(Vec<SimpleExpr>),
+    /// `CUBE (a, b)`
+    Cube(Vec<SimpleExpr>),
+    /// `GROUPING SETS ((a, b), (a), ())`
+    GroupingSets(Vec<Vec<SimpleExpr>>),
+}
+
 /// Window type in [`SelectExpr`]
 #[derive(Debug, Clone, PartialEq)]
 pub enum WindowSelectType {
@@ -108,6 +121,22 @@ pub struct LockClause {
     pub(crate) behavior: Option<LockBehavior>,
 }
 
+/// Sampling methods of the `TABLESAMPLE` clause
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum SampleMethod {
+    /// Picks each row with the given probability
+    Bernoulli,
+    /// Picks whole blocks of rows, faster but less random
+    System,
+}
+
+#[derive(Debug, Clone, PartialEq)]
+pub struct TableSample {
+    pub(crate) method: SampleMethod,
+    pub(crate) percentage: f64,
+    pub(crate) seed: Option<f64>,
+}
+
 /// List of union types that can be used in union clause
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum UnionType {
@@ -145,6 +174,7 @@ impl SelectStatement {
             join: std::mem::take(&mut self.join),
             r#where: std::mem::replace(&mut self.r#where, ConditionHolder::new()),
             groups: std::mem::take(&mut self.groups),
+            grouping: std::mem::take(&mut self.grouping),
             having: std::mem::replace(&mut self.having, ConditionHolder::new()),
             unions: std::mem::take(&mut self.unions),
             orders: std::mem::take(&mut self.orders),
@@ -152,6 +182,7 @@ impl SelectStatement {
             offset: self.offset.take(),
             lock: self.lock.take(),
             window: self.window.take(),
+            table_sample: self.table_sample.take(),
         }
     }
 
@@ -261,6 +292,66 @@ impl SelectStatement {
         self
     }
 
+    /// Clear the `WHERE` conditions, leaving `HAVING` untouched
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let base = Query::select()
+    ///     .column(Glyph::Aspect)
+    ///     .from(Glyph::Table)
+    ///     .and_where(Expr::col(Glyph::Aspect).gt(2))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     base.clone().clear_where().to_string(QueryBuilder),
+    ///     r#"SELECT "aspect" FROM "glyph""#
+    /// );
+    /// ```
+    pub fn clear_where(&mut self) -> &mut Self {
+        self.r#where = ConditionHolder::new();
+        self
+    }
+
+    /// Invert the order by list, swapping `ASC` and `DESC` and, where given, `NULLS FIRST` and
+    /// `NULLS LAST`, so rows come back in exactly the opposite order. An
+    /// [`Order::Field`] has no direction and is kept as it is.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Glyph::Id)
+    ///     .from(Glyph::Table)
+    ///     .order_by(Glyph::Aspect, Order::Asc)
+    ///     .order_by_with_nulls(Glyph::Image, Order::Desc, NullOrdering::First)
+    ///     .reverse_order()
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "id" FROM "glyph" ORDER BY "aspect" DESC, "image" ASC NULLS LAST"#
+    /// );
+    /// ```
+    pub fn reverse_order(&mut self) -> &mut Self {
+        for order_expr in self.orders.iter_mut() {
+            order_expr.order = match std::mem::replace(&mut order_expr.order, Order::Asc) {
+                Order::Asc => Order::Desc,
+                Order::Desc => Order::Asc,
+                order @ Order::Field(_) => order,
+            };
+            order_expr.nulls = order_expr.nulls.as_ref().map(|nulls| match nulls {
+                NullOrdering::First => NullOrdering::Last,
+                NullOrdering::Last => NullOrdering::First,
+            });
+        }
+        self
+    }
+
     /// Add an expression to the select expression list.
     ///
     /// # Examples
@@ -326,6 +417,8 @@ impl SelectStatement {
     }
 
     /// Select distinct
+    ///
+    /// Overrides any previous [`SelectStatement::distinct_on`].
     pub fn distinct(&mut self) -> &mut Self {
         self.distinct = Some(SelectDistinct::Distinct);
         self
@@ -333,6 +426,9 @@ impl SelectStatement {
 
     /// Select distinct on for *POSTGRES ONLY*
     ///
+    /// Overrides any previous [`SelectStatement::distinct`]. An empty column list
+    /// removes the DISTINCT clause.
+    ///
     /// # Examples
     ///
     /// ```
@@ -737,6 +833,30 @@ impl SelectStatement {
         self.from_from(tbl_ref.into_table_ref())
     }
 
+    /// From table, leaving out rows of inheriting tables and partitions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from_only(Char::Table)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "character" FROM ONLY "character""#
+    /// );
+    /// ```
+    pub fn from_only<R>(&mut self, tbl_ref: R) -> &mut Self
+    where
+        R: IntoTableRef,
+    {
+        self.from_from(tbl_ref.into_table_ref().only())
+    }
+
     /// Shorthand for selecting from a constant value list.
     /// Panics on an empty values list.
     ///
@@ -1486,6 +1606,130 @@ impl SelectStatement {
         self
     }
 
+    /// Add a grouping specification to the group by clause.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Char::Table)
+    ///     .column(Char::FontId)
+    ///     .expr(Expr::col(Char::Id).count())
+    ///     .add_grouping(GroupingSpec::Rollup(vec![Expr::col(Char::FontId).into()]))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "font_id", COUNT("id") FROM "character" GROUP BY ROLLUP ("font_id")"#
+    /// );
+    /// ```
+    pub fn add_grouping(&mut self, spec: GroupingSpec) -> &mut Self {
+        self.grouping.push(spec);
+        self
+    }
+
+    /// Group by `ROLLUP (...)`, producing a group for each prefix of the columns, down to the
+    /// grand total.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Char::Table)
+    ///     .columns([Char::FontId, Char::SizeW])
+    ///     .expr(Expr::col(Char::Id).count())
+    ///     .group_by_rollup([Char::FontId, Char::SizeW])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY ROLLUP ("font_id", "size_w")"#
+    /// );
+    /// ```
+    pub fn group_by_rollup<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.add_grouping(GroupingSpec::Rollup(
+            cols.into_iter()
+                .map(|c| SimpleExpr::Column(c.into_column_ref()))
+                .collect(),
+        ))
+    }
+
+    /// Group by `CUBE (...)`, producing a group for every subset of the columns.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Char::Table)
+    ///     .columns([Char::FontId, Char::SizeW])
+    ///     .expr(Expr::col(Char::Id).count())
+    ///     .group_by_cube([Char::FontId, Char::SizeW])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY CUBE ("font_id", "size_w")"#
+    /// );
+    /// ```
+    pub fn group_by_cube<T, I>(&mut self, cols: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        I: IntoIterator<Item = T>,
+    {
+        self.add_grouping(GroupingSpec::Cube(
+            cols.into_iter()
+                .map(|c| SimpleExpr::Column(c.into_column_ref()))
+                .collect(),
+        ))
+    }
+
+    /// Group by `GROUPING SETS (...)`, producing a group for each listed set of columns. An
+    /// empty set groups all rows together.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .from(Char::Table)
+    ///     .columns([Char::FontId, Char::SizeW])
+    ///     .expr(Expr::col(Char::Id).count())
+    ///     .group_by_grouping_sets([vec![Char::FontId, Char::SizeW], vec![Char::FontId], vec![]])
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "font_id", "size_w", COUNT("id") FROM "character" GROUP BY GROUPING SETS (("font_id", "size_w"), ("font_id"), ())"#
+    /// );
+    /// ```
+    pub fn group_by_grouping_sets<T, S, I>(&mut self, sets: I) -> &mut Self
+    where
+        T: IntoColumnRef,
+        S: IntoIterator<Item = T>,
+        I: IntoIterator<Item = S>,
+    {
+        self.add_grouping(GroupingSpec::GroupingSets(
+            sets.into_iter()
+                .map(|set| {
+                    set.into_iter()
+                        .map(|c| SimpleExpr::Column(c.into_column_ref()))
+                        .collect()
+                })
+                .collect(),
+        ))
+    }
+
     /// Having condition, expressed with [`any!`](crate::any) and [`all!`](crate::all).
     ///
     /// # Examples
@@ -1580,6 +1824,11 @@ impl SelectStatement {
         self
     }
 
+    /// Clear the `LIMIT`, leaving any `OFFSET` in place. Same as [`SelectStatement::reset_limit`].
+    pub fn clear_limit(&mut self) -> &mut Self {
+        self.reset_limit()
+    }
+
     /// Offset number of returned rows.
     ///
     /// # Examples
@@ -1610,6 +1859,69 @@ impl SelectStatement {
         self
     }
 
+    /// Sample about `percentage` percent of the rows of the last table in the `FROM` clause
+    /// with `TABLESAMPLE`, before any `WHERE` condition applies. The percentage is bound as
+    /// a parameter, so the server rejects one outside 0 to 100, including NaN.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .table_sample(SampleMethod::Bernoulli, 2.5)
+    ///     .and_where(Expr::col(Char::FontId).eq(5))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "character" FROM "character" TABLESAMPLE BERNOULLI (2.5) WHERE "font_id" = 5"#
+    /// );
+    /// ```
+    pub fn table_sample(&mut self, method: SampleMethod, percentage: f64) -> &mut Self {
+        self.table_sample = Some(Box::new(TableSample {
+            method,
+            percentage,
+            seed: None,
+        }));
+        self
+    }
+
+    /// Sample the rows like [`SelectStatement::table_sample`], picking the same rows for the
+    /// same `seed` as long as the table is unchanged
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .table_sample_repeatable(SampleMethod::System, 10.0, 42.0)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"SELECT "character" FROM "character" TABLESAMPLE SYSTEM (10) REPEATABLE (42)"#
+    /// );
+    /// ```
+    pub fn table_sample_repeatable(
+        &mut self,
+        method: SampleMethod,
+        percentage: f64,
+        seed: f64,
+    ) -> &mut Self {
+        self.table_sample = Some(Box::new(TableSample {
+            method,
+            percentage,
+            seed: Some(seed),
+        }));
+        self
+    }
+
     /// Row locking (if supported).
     ///
     /// # Examples
@@ -1934,7 +2246,11 @@ impl SelectStatement {
 
 #[inherent]
 impl QueryStatementBuilder for SelectStatement {
-    pub fn build_collect_any_into(&self, query_builder: &QueryBuilder, sql: &mut dyn SqlWriter) {
+    pub fn build_collect_any_into(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
         query_builder.prepare_select_statement(self, sql);
     }
 
@@ -1942,23 +2258,31 @@ impl QueryStatementBuilder for SelectStatement {
         SubQueryStatement::SelectStatement(self)
     }
 
-    pub fn build_any(&self, query_builder: &QueryBuilder) -> (String, Values);
+    pub fn build_any(&self, query_builder: &ConfiguredQueryBuilder) -> (String, Values);
     pub fn build_collect_any(
         &self,
-        query_builder: &QueryBuilder,
+        query_builder: &ConfiguredQueryBuilder,
         sql: &mut dyn SqlWriter,
     ) -> String;
 }
 
 #[inherent]
 impl QueryStatementWriter for SelectStatement {
-    pub fn build_collect_into(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) {
-        query_builder.prepare_select_statement(self, sql);
+    pub fn build_collect_into(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.into().prepare_select_statement(self, sql);
     }
 
-    pub fn build_collect(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) -> String;
-    pub fn build(&self, query_builder: QueryBuilder) -> (String, Values);
-    pub fn to_string(&self, query_builder: QueryBuilder) -> String;
+    pub fn build_collect(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+    pub fn build(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> (String, Values);
+    pub fn to_string(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
 
 #[inherent]
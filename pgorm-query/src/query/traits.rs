@@ -1,10 +1,13 @@
 use std::fmt::Debug;
 
-use crate::{SqlWriter, SqlWriterValues, SubQueryStatement, backend::QueryBuilder, value::Values};
+use crate::{
+    CompiledQuery, SqlWriter, SqlWriterValues, SubQueryStatement, backend::ConfiguredQueryBuilder,
+    value::Values,
+};
 
 pub trait QueryStatementBuilder: Debug {
     /// Build corresponding SQL statement for certain database backend and collect query parameters into a vector
-    fn build_any(&self, query_builder: &QueryBuilder) -> (String, Values) {
+    fn build_any(&self, query_builder: &ConfiguredQueryBuilder) -> (String, Values) {
         let (placeholder, numbered) = query_builder.placeholder();
         let mut sql = SqlWriterValues::new(placeholder, numbered);
         self.build_collect_any_into(query_builder, &mut sql);
@@ -12,13 +15,21 @@ pub trait QueryStatementBuilder: Debug {
     }
 
     /// Build corresponding SQL statement for certain database backend and collect query parameters
-    fn build_collect_any(&self, query_builder: &QueryBuilder, sql: &mut dyn SqlWriter) -> String {
+    fn build_collect_any(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) -> String {
         self.build_collect_any_into(query_builder, sql);
         sql.to_string()
     }
 
     /// Build corresponding SQL statement into the SqlWriter for certain database backend and collect query parameters
-    fn build_collect_any_into(&self, query_builder: &QueryBuilder, sql: &mut dyn SqlWriter);
+    fn build_collect_any_into(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    );
 
     fn into_sub_query_statement(self) -> SubQueryStatement;
 }
@@ -44,9 +55,9 @@ pub trait QueryStatementWriter: QueryStatementBuilder {
     ///     r#"SELECT "aspect" FROM "glyph" WHERE COALESCE("aspect", 0) > 2 ORDER BY "image" DESC, "glyph"."aspect" ASC"#
     /// );
     /// ```
-    fn to_string(&self, query_builder: QueryBuilder) -> String {
+    fn to_string(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        self.build_collect_any_into(&query_builder, &mut sql);
+        self.build_collect_any_into(&query_builder.into(), &mut sql);
         sql
     }
 
@@ -74,7 +85,8 @@ pub trait QueryStatementWriter: QueryStatementBuilder {
     ///     Values(vec![Value::Int(Some(0)), Value::Int(Some(2))])
     /// );
     /// ```
-    fn build(&self, query_builder: QueryBuilder) -> (String, Values) {
+    fn build(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> (String, Values) {
+        let query_builder = query_builder.into();
         let (placeholder, numbered) = query_builder.placeholder();
         let mut sql = SqlWriterValues::new(placeholder, numbered);
         self.build_collect_into(query_builder, &mut sql);
@@ -110,10 +122,47 @@ pub trait QueryStatementWriter: QueryStatementBuilder {
     ///     Values(vec![Value::Int(Some(0)), Value::Int(Some(2))])
     /// );
     /// ```
-    fn build_collect(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) -> String {
+    fn build_collect(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) -> String {
         self.build_collect_into(query_builder, sql);
         sql.to_string()
     }
 
-    fn build_collect_into(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter);
+    fn build_collect_into(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    );
+
+    /// Build the SQL statement once, so it can be executed again with other values
+    /// without rebuilding it
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{*, tests_cfg::*};
+    ///
+    /// let compiled = Query::select()
+    ///     .column(Char::Character)
+    ///     .from(Char::Table)
+    ///     .and_where(Expr::col(Char::SizeW).gt(10))
+    ///     .and_where(Expr::col(Char::Character).like("A%"))
+    ///     .compile(QueryBuilder);
+    ///
+    /// assert_eq!(
+    ///     compiled.sql(),
+    ///     r#"SELECT "character" FROM "character" WHERE "size_w" > $1 AND "character" LIKE $2"#
+    /// );
+    /// assert_eq!(
+    ///     compiled.bind([Value::from(20), "B%".into()]).unwrap(),
+    ///     Values(vec![20.into(), "B%".into()])
+    /// );
+    /// ```
+    fn compile(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> CompiledQuery {
+        let (sql, values) = self.build(query_builder);
+        CompiledQuery::new(sql, values)
+    }
 }
@@ -1,7 +1,7 @@
 use crate::{
     QueryStatementBuilder, QueryStatementWriter, ReturningClause, SubQueryStatement, WithClause,
     WithQuery,
-    backend::QueryBuilder,
+    backend::ConfiguredQueryBuilder,
     expr::*,
     prepare::*,
     query::{OrderedStatement, condition::*},
@@ -32,6 +32,7 @@ use inherent::inherent;
 pub struct UpdateStatement {
     pub(crate) table: Option<Box<TableRef>>,
     pub(crate) values: Vec<(DynIden, Box<SimpleExpr>)>,
+    pub(crate) from: Vec<TableRef>,
     pub(crate) r#where: ConditionHolder,
     pub(crate) orders: Vec<OrderExpr>,
     pub(crate) limit: Option<Value>,
@@ -58,6 +59,33 @@ impl UpdateStatement {
         self
     }
 
+    /// Specify which table to update, leaving out rows of inheriting tables
+    /// and partitions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table_only(Glyph::Table)
+    ///     .value(Glyph::Aspect, 1.23)
+    ///     .and_where(Expr::col(Glyph::Id).eq(1))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"UPDATE ONLY "glyph" SET "aspect" = 1.23 WHERE "id" = 1"#
+    /// );
+    /// ```
+    #[allow(clippy::wrong_self_convention)]
+    pub fn table_only<T>(&mut self, tbl_ref: T) -> &mut Self
+    where
+        T: IntoTableRef,
+    {
+        self.table(tbl_ref.into_table_ref().only())
+    }
+
     /// Update column values. To set multiple column-value pairs at once.
     ///
     /// # Examples
@@ -118,6 +146,73 @@ impl UpdateStatement {
         self
     }
 
+    /// Join other tables through `FROM`, whose columns can then be used in the
+    /// assigned values and the conditions.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(Glyph::Image, Expr::col((Font::Table, Font::Name)))
+    ///     .from(Font::Table)
+    ///     .and_where(Expr::col((Glyph::Table, Glyph::Id)).equals((Font::Table, Font::Id)))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"UPDATE "glyph" SET "image" = "font"."name" FROM "font" WHERE "glyph"."id" = "font"."id""#
+    /// );
+    /// ```
+    pub fn from<R>(&mut self, tbl_ref: R) -> &mut Self
+    where
+        R: IntoTableRef,
+    {
+        self.from.push(tbl_ref.into_table_ref());
+        self
+    }
+
+    /// Join a `VALUES` list through `FROM`, to assign different values to each row
+    /// in one statement. Its columns are named `column1`, `column2` and so on.
+    ///
+    /// # Panics
+    ///
+    /// Panics on an empty values list.
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let query = Query::update()
+    ///     .table(Glyph::Table)
+    ///     .value(Glyph::Image, Expr::col((Alias::new("v"), Alias::new("column2"))))
+    ///     .from_values([(1, "a"), (2, "b")], Alias::new("v"))
+    ///     .and_where(
+    ///         Expr::col((Glyph::Table, Glyph::Id))
+    ///             .equals((Alias::new("v"), Alias::new("column1"))),
+    ///     )
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     r#"UPDATE "glyph" SET "image" = "v"."column2" FROM (VALUES (1, 'a'), (2, 'b')) AS "v" WHERE "glyph"."id" = "v"."column1""#
+    /// );
+    /// ```
+    pub fn from_values<I, V, A>(&mut self, value_tuples: I, alias: A) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoValueTuple,
+        A: IntoIden,
+    {
+        let value_tuples: Vec<ValueTuple> = value_tuples
+            .into_iter()
+            .map(|vt| vt.into_value_tuple())
+            .collect();
+        assert!(!value_tuples.is_empty());
+        self.from(TableRef::ValuesList(value_tuples, alias.into_iden()))
+    }
+
     /// Limit number of updated rows.
     pub fn limit(&mut self, limit: u64) -> &mut Self {
         self.limit = Some(limit.into());
@@ -241,7 +336,11 @@ impl UpdateStatement {
 
 #[inherent]
 impl QueryStatementBuilder for UpdateStatement {
-    pub fn build_collect_any_into(&self, query_builder: &QueryBuilder, sql: &mut dyn SqlWriter) {
+    pub fn build_collect_any_into(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
         query_builder.prepare_update_statement(self, sql);
     }
 
@@ -249,23 +348,31 @@ impl QueryStatementBuilder for UpdateStatement {
         SubQueryStatement::UpdateStatement(self)
     }
 
-    pub fn build_any(&self, query_builder: &QueryBuilder) -> (String, Values);
+    pub fn build_any(&self, query_builder: &ConfiguredQueryBuilder) -> (String, Values);
     pub fn build_collect_any(
         &self,
-        query_builder: &QueryBuilder,
+        query_builder: &ConfiguredQueryBuilder,
         sql: &mut dyn SqlWriter,
     ) -> String;
 }
 
 #[inherent]
 impl QueryStatementWriter for UpdateStatement {
-    pub fn build_collect_into(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) {
-        query_builder.prepare_update_statement(self, sql);
+    pub fn build_collect_into(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.into().prepare_update_statement(self, sql);
     }
 
-    pub fn build_collect(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) -> String;
-    pub fn build(&self, query_builder: QueryBuilder) -> (String, Values);
-    pub fn to_string(&self, query_builder: QueryBuilder) -> String;
+    pub fn build_collect(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+    pub fn build(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> (String, Values);
+    pub fn to_string(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
 
 #[inherent]
@@ -0,0 +1,121 @@
+use crate::{
+    QueryStatementBuilder, QueryStatementWriter, SubQueryStatement, WithClause, WithQuery,
+    backend::ConfiguredQueryBuilder, prepare::*, value::*,
+};
+use inherent::inherent;
+
+/// A standalone `VALUES` list of constant rows, usable on its own or as the body of a
+/// common table expression
+///
+/// Postgres takes the column types from the first row, so its values, other than text, are
+/// cast to the type they are bound as.
+///
+/// # Examples
+///
+/// ```
+/// use pgorm_query::*;
+///
+/// let query = Query::values([(1, "a"), (2, "b")]);
+///
+/// assert_eq!(
+///     query.to_string(QueryBuilder),
+///     r#"VALUES (CAST(1 AS integer), 'a'), (2, 'b')"#
+/// );
+/// ```
+#[derive(Default, Debug, Clone, PartialEq)]
+pub struct ValuesStatement {
+    pub(crate) rows: Vec<ValueTuple>,
+}
+
+impl ValuesStatement {
+    /// Construct a new [`ValuesStatement`]. It must be given at least one row before
+    /// being built.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Add a row. Every row must have the same number of values.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::*;
+    ///
+    /// let query = ValuesStatement::new()
+    ///     .row((1, "a"))
+    ///     .row((2, "b"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     query.build(QueryBuilder),
+    ///     (
+    ///         r#"VALUES (CAST($1 AS integer), $2), ($3, $4)"#.to_owned(),
+    ///         Values(vec![1.into(), "a".into(), 2.into(), "b".into()])
+    ///     )
+    /// );
+    /// ```
+    pub fn row<V>(&mut self, row: V) -> &mut Self
+    where
+        V: IntoValueTuple,
+    {
+        self.rows.push(row.into_value_tuple());
+        self
+    }
+
+    /// Add rows from an iterator, variation of [`ValuesStatement::row`].
+    pub fn rows<I, V>(&mut self, rows: I) -> &mut Self
+    where
+        I: IntoIterator<Item = V>,
+        V: IntoValueTuple,
+    {
+        self.rows
+            .extend(rows.into_iter().map(|row| row.into_value_tuple()));
+        self
+    }
+
+    /// Create a [WithQuery] by specifying a [WithClause] to execute this query with.
+    pub fn with(self, clause: WithClause) -> WithQuery {
+        clause.query(self)
+    }
+}
+
+#[inherent]
+impl QueryStatementBuilder for ValuesStatement {
+    pub fn build_collect_any_into(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.prepare_values_statement(self, sql);
+    }
+
+    pub fn into_sub_query_statement(self) -> SubQueryStatement {
+        SubQueryStatement::ValuesStatement(self)
+    }
+
+    pub fn build_any(&self, query_builder: &ConfiguredQueryBuilder) -> (String, Values);
+    pub fn build_collect_any(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+}
+
+#[inherent]
+impl QueryStatementWriter for ValuesStatement {
+    pub fn build_collect_into(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.into().prepare_values_statement(self, sql);
+    }
+
+    pub fn build_collect(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+    pub fn build(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> (String, Values);
+    pub fn to_string(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> String;
+}
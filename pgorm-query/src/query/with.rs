@@ -1,7 +1,8 @@
 use crate::{
     ColumnRef, DynIden, IntoIden, QueryStatementBuilder, QueryStatementWriter, SelectExpr,
     SelectStatement, SimpleExpr, SqlWriter, SubQueryStatement, TableRef, Values,
-    {Alias, QueryBuilder},
+    error::{Error, Result},
+    {Alias, ConfiguredQueryBuilder},
 };
 use inherent::inherent;
 
@@ -52,6 +53,8 @@ pub struct CommonTableExpression {
     pub(crate) cols: Vec<DynIden>,
     pub(crate) query: Option<Box<SubQueryStatement>>,
     pub(crate) materialized: Option<bool>,
+    pub(crate) search: Option<Search>,
+    pub(crate) cycle: Option<Cycle>,
 }
 
 impl CommonTableExpression {
@@ -100,6 +103,9 @@ impl CommonTableExpression {
 
     /// Set the query generating the CTE content. The query's result must match the defined
     /// columns.
+    ///
+    /// Besides a select, the query may be an insert, update or delete with a `RETURNING` clause,
+    /// whose returned rows become the CTE content.
     pub fn query<Q>(&mut self, query: Q) -> &mut Self
     where
         Q: QueryStatementBuilder,
@@ -108,6 +114,109 @@ impl CommonTableExpression {
         self
     }
 
+    /// Traverse a recursive CTE breadth first, tracking the path with `expr`, and add the
+    /// ordering column `alias` to its rows.
+    ///
+    /// This setting is only meaningful in a recursive [WithClause].
+    pub fn search_breadth_first<E, A>(&mut self, expr: E, alias: A) -> &mut Self
+    where
+        E: Into<SimpleExpr>,
+        A: IntoIden,
+    {
+        self.search = Some(Search::new_from_order_and_expr(
+            SearchOrder::BREADTH,
+            SelectExpr {
+                expr: expr.into(),
+                alias: Some(alias.into_iden()),
+                window: None,
+            },
+        ));
+        self
+    }
+
+    /// Traverse a recursive CTE depth first, tracking the path with `expr`, and add the
+    /// ordering column `alias` to its rows.
+    ///
+    /// This setting is only meaningful in a recursive [WithClause].
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::*;
+    ///
+    /// let cte = CommonTableExpression::new()
+    ///     .table_name(Alias::new("tree"))
+    ///     .query(
+    ///         Query::select()
+    ///             .column(Alias::new("id"))
+    ///             .from(Alias::new("node"))
+    ///             .union(
+    ///                 UnionType::All,
+    ///                 Query::select()
+    ///                     .column((Alias::new("node"), Alias::new("id")))
+    ///                     .from(Alias::new("node"))
+    ///                     .inner_join(
+    ///                         Alias::new("tree"),
+    ///                         Expr::col((Alias::new("node"), Alias::new("parent_id")))
+    ///                             .equals((Alias::new("tree"), Alias::new("id"))),
+    ///                     )
+    ///                     .to_owned(),
+    ///             )
+    ///             .to_owned(),
+    ///     )
+    ///     .search_depth_first(Expr::col(Alias::new("id")), Alias::new("ordercol"))
+    ///     .cycle(Expr::col(Alias::new("id")), Alias::new("is_cycle"), Alias::new("path"))
+    ///     .to_owned();
+    ///
+    /// let query = Query::select()
+    ///     .column(Asterisk)
+    ///     .from(Alias::new("tree"))
+    ///     .to_owned()
+    ///     .with(WithClause::new().recursive(true).cte(cte).to_owned());
+    ///
+    /// assert_eq!(
+    ///     query.to_string(QueryBuilder),
+    ///     [
+    ///         r#"WITH RECURSIVE "tree" AS (SELECT "id" FROM "node" UNION ALL"#,
+    ///         r#"(SELECT "node"."id" FROM "node" INNER JOIN "tree" ON "node"."parent_id" = "tree"."id"))"#,
+    ///         r#"SEARCH DEPTH FIRST BY "id" SET "ordercol""#,
+    ///         r#"CYCLE "id" SET "is_cycle" USING "path""#,
+    ///         r#"SELECT * FROM "tree""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    pub fn search_depth_first<E, A>(&mut self, expr: E, alias: A) -> &mut Self
+    where
+        E: Into<SimpleExpr>,
+        A: IntoIden,
+    {
+        self.search = Some(Search::new_from_order_and_expr(
+            SearchOrder::DEPTH,
+            SelectExpr {
+                expr: expr.into(),
+                alias: Some(alias.into_iden()),
+                window: None,
+            },
+        ));
+        self
+    }
+
+    /// Stop a recursive CTE from walking a cycle: rows are identified by `expr`, `set_as` names
+    /// the boolean column marking a row reached again, and `using` names the column holding
+    /// the path walked so far.
+    ///
+    /// This setting is only meaningful in a recursive [WithClause].
+    pub fn cycle<E, S, U>(&mut self, expr: E, set_as: S, using: U) -> &mut Self
+    where
+        E: Into<SimpleExpr>,
+        S: IntoIden,
+        U: IntoIden,
+    {
+        self.cycle = Some(Cycle::new_from_expr_set_using(expr, set_as, using));
+        self
+    }
+
     /// Create a CTE from a [SelectStatement] if the selections are named columns then this will
     /// return a [CommonTableExpression] that has the column names set. The [Self::table_name] is
     /// set if the [SelectStatement] from clause contains at least one table.
@@ -570,8 +679,44 @@ impl WithQuery {
     }
 }
 
+impl WithQuery {
+    /// Build the query like [`QueryStatementWriter::build`], but fail instead of panicking if the
+    /// [WithClause] has no [CommonTableExpression], or more than one while recursive.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{error::Error, *};
+    ///
+    /// let query = Query::select()
+    ///     .column(Asterisk)
+    ///     .from(Alias::new("cte"))
+    ///     .to_owned()
+    ///     .with(WithClause::new());
+    ///
+    /// assert_eq!(query.try_build(QueryBuilder), Err(Error::EmptyWithClause));
+    /// ```
+    pub fn try_build(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+    ) -> Result<(String, Values)> {
+        let len = self.with_clause.cte_expressions.len();
+        if len == 0 {
+            return Err(Error::EmptyWithClause);
+        }
+        if self.with_clause.recursive && len > 1 {
+            return Err(Error::RecursiveWithClauseCteCount(len));
+        }
+        Ok(self.build(query_builder))
+    }
+}
+
 impl QueryStatementBuilder for WithQuery {
-    fn build_collect_any_into(&self, query_builder: &QueryBuilder, sql: &mut dyn SqlWriter) {
+    fn build_collect_any_into(
+        &self,
+        query_builder: &ConfiguredQueryBuilder,
+        sql: &mut dyn SqlWriter,
+    ) {
         query_builder.prepare_with_query(self, sql);
     }
 
@@ -582,11 +727,19 @@ impl QueryStatementBuilder for WithQuery {
 
 #[inherent]
 impl QueryStatementWriter for WithQuery {
-    pub fn build_collect_into(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) {
-        query_builder.prepare_with_query(self, sql);
-    }
-
-    pub fn build_collect(&self, query_builder: QueryBuilder, sql: &mut dyn SqlWriter) -> String;
-    pub fn build(&self, query_builder: QueryBuilder) -> (String, Values);
-    pub fn to_string(&self, query_builder: QueryBuilder) -> String;
+    pub fn build_collect_into(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) {
+        query_builder.into().prepare_with_query(self, sql);
+    }
+
+    pub fn build_collect(
+        &self,
+        query_builder: impl Into<ConfiguredQueryBuilder>,
+        sql: &mut dyn SqlWriter,
+    ) -> String;
+    pub fn build(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> (String, Values);
+    pub fn to_string(&self, query_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
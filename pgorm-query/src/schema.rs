@@ -1,6 +1,6 @@
 //! Schema definition & alternations statements
 
-use crate::{ForeignKeyStatement, IndexStatement, QueryBuilder, TableStatement};
+use crate::{ConfiguredQueryBuilder, ForeignKeyStatement, IndexStatement, TableStatement};
 
 #[derive(Debug, Clone)]
 pub enum SchemaStatement {
@@ -11,13 +11,13 @@ pub enum SchemaStatement {
 
 pub trait SchemaStatementBuilder {
     /// Build corresponding SQL statement for certain database backend and return SQL string
-    fn build(&self, schema_builder: QueryBuilder) -> String;
+    fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 
     /// Build corresponding SQL statement for certain database backend and return SQL string
-    fn build_any(&self, schema_builder: &QueryBuilder) -> String;
+    fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String;
 
     /// Build corresponding SQL statement for certain database backend and return SQL string
-    fn to_string(&self, schema_builder: QueryBuilder) -> String {
+    fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         self.build(schema_builder)
     }
 }
@@ -1,6 +1,6 @@
 use crate::{
-    ColumnDef, IntoColumnDef, SchemaStatementBuilder, TableForeignKey, backend::QueryBuilder,
-    types::*,
+    ColumnDef, IntoColumnDef, SchemaStatementBuilder, SimpleExpr, TableForeignKey,
+    backend::ConfiguredQueryBuilder, types::*,
 };
 use inherent::inherent;
 
@@ -46,8 +46,14 @@ pub enum TableAlterOption {
     ModifyColumn(ColumnDef),
     RenameColumn(DynIden, DynIden),
     DropColumn(DynIden),
+    SetDefault(DynIden, SimpleExpr),
+    DropDefault(DynIden),
+    SetNotNull(DynIden),
+    DropNotNull(DynIden),
     AddForeignKey(TableForeignKey),
     DropForeignKey(DynIden),
+    RenameConstraint(DynIden, DynIden),
+    ValidateConstraint(DynIden),
 }
 
 impl TableAlterStatement {
@@ -209,6 +215,106 @@ impl TableAlterStatement {
         self.add_alter_option(TableAlterOption::DropColumn(col_name.into_iden()))
     }
 
+    /// Set the default value of a column, without touching its type
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .set_default(Font::Language, "en")
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(QueryBuilder),
+    ///     r#"ALTER TABLE "font" ALTER COLUMN "language" SET DEFAULT 'en'"#
+    /// );
+    /// ```
+    pub fn set_default<T, V>(&mut self, col_name: T, value: V) -> &mut Self
+    where
+        T: IntoIden,
+        V: Into<SimpleExpr>,
+    {
+        self.add_alter_option(TableAlterOption::SetDefault(
+            col_name.into_iden(),
+            value.into(),
+        ))
+    }
+
+    /// Drop the default value of a column
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .drop_default(Font::Language)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(QueryBuilder),
+    ///     r#"ALTER TABLE "font" ALTER COLUMN "language" DROP DEFAULT"#
+    /// );
+    /// ```
+    pub fn drop_default<T>(&mut self, col_name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.add_alter_option(TableAlterOption::DropDefault(col_name.into_iden()))
+    }
+
+    /// Mark a column as `NOT NULL`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .set_not_null(Font::Language)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(QueryBuilder),
+    ///     r#"ALTER TABLE "font" ALTER COLUMN "language" SET NOT NULL"#
+    /// );
+    /// ```
+    pub fn set_not_null<T>(&mut self, col_name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.add_alter_option(TableAlterOption::SetNotNull(col_name.into_iden()))
+    }
+
+    /// Allow a column to be `NULL`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Font::Table)
+    ///     .drop_not_null(Font::Language)
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(QueryBuilder),
+    ///     r#"ALTER TABLE "font" ALTER COLUMN "language" DROP NOT NULL"#
+    /// );
+    /// ```
+    pub fn drop_not_null<T>(&mut self, col_name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.add_alter_option(TableAlterOption::DropNotNull(col_name.into_iden()))
+    }
+
     /// Add a foreign key to existing table
     ///
     /// # Examples
@@ -292,6 +398,58 @@ impl TableAlterStatement {
         self.add_alter_option(TableAlterOption::DropForeignKey(name.into_iden()))
     }
 
+    /// Rename a constraint of an existing table
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Character::Table)
+    ///     .rename_constraint(Alias::new("FK_character_font"), Alias::new("FK_char_font"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(QueryBuilder),
+    ///     r#"ALTER TABLE "character" RENAME CONSTRAINT "FK_character_font" TO "FK_char_font""#
+    /// );
+    /// ```
+    pub fn rename_constraint<T, R>(&mut self, from_name: T, to_name: R) -> &mut Self
+    where
+        T: IntoIden,
+        R: IntoIden,
+    {
+        self.add_alter_option(TableAlterOption::RenameConstraint(
+            from_name.into_iden(),
+            to_name.into_iden(),
+        ))
+    }
+
+    /// Validate a constraint previously added as `NOT VALID`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use pgorm_query::{tests_cfg::*, *};
+    ///
+    /// let table = Table::alter()
+    ///     .table(Character::Table)
+    ///     .validate_constraint(Alias::new("FK_character_font"))
+    ///     .to_owned();
+    ///
+    /// assert_eq!(
+    ///     table.to_string(QueryBuilder),
+    ///     r#"ALTER TABLE "character" VALIDATE CONSTRAINT "FK_character_font""#
+    /// );
+    /// ```
+    pub fn validate_constraint<T>(&mut self, name: T) -> &mut Self
+    where
+        T: IntoIden,
+    {
+        self.add_alter_option(TableAlterOption::ValidateConstraint(name.into_iden()))
+    }
+
     fn add_alter_option(&mut self, alter_option: TableAlterOption) -> &mut Self {
         self.options.push(alter_option);
         self
@@ -307,17 +465,19 @@ impl TableAlterStatement {
 
 #[inherent]
 impl SchemaStatementBuilder for TableAlterStatement {
-    pub fn build(&self, schema_builder: QueryBuilder) -> String {
+    pub fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        schema_builder.prepare_table_alter_statement(self, &mut sql);
+        schema_builder
+            .into()
+            .prepare_table_alter_statement(self, &mut sql);
         sql
     }
 
-    pub fn build_any(&self, schema_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String {
         let mut sql = String::with_capacity(256);
         schema_builder.prepare_table_alter_statement(self, &mut sql);
         sql
     }
 
-    pub fn to_string(&self, schema_builder: QueryBuilder) -> String;
+    pub fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
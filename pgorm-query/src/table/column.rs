@@ -36,7 +36,7 @@ pub trait IntoColumnDef {
 /// | TimestampWithTimeZone | timestamp         | timestamp with time zone    | timestamp_with_timezone_text |
 /// | Time                  | time              | time                        | time_text                    |
 /// | Date                  | date              | date                        | date_text                    |
-/// | Year                  | year              | N/A                         | N/A                          |
+/// | Year                  | year              | smallint                    | N/A                          |
 /// | Interval              | N/A               | interval                    | N/A                          |
 /// | Blob                  | blob              | bytea                       | blob                         |
 /// | Binary                | binary            | bytea                       | blob                         |
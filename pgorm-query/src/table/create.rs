@@ -1,8 +1,8 @@
 use inherent::inherent;
 
 use crate::{
-    ColumnDef, IntoColumnDef, QueryBuilder, SchemaStatementBuilder, SimpleExpr, foreign_key::*,
-    index::*, types::*,
+    ColumnDef, ConfiguredQueryBuilder, IntoColumnDef, SchemaStatementBuilder, SimpleExpr,
+    error::Result, foreign_key::*, index::*, types::*,
 };
 
 /// Create a table
@@ -289,6 +289,17 @@ impl TableCreateStatement {
         self.extra.as_ref()
     }
 
+    /// Build the statement like [`SchemaStatementBuilder::build`], but fail instead of
+    /// falling back if it uses a feature the targeted server version lacks, see
+    /// [`ConfiguredQueryBuilder::server_version`].
+    pub fn try_build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> Result<String> {
+        let schema_builder = schema_builder.into();
+        for index in &self.indexes {
+            index.check_server_version(&schema_builder)?;
+        }
+        Ok(self.build(schema_builder))
+    }
+
     pub fn take(&mut self) -> Self {
         Self {
             table: self.table.take(),
@@ -307,17 +318,19 @@ impl TableCreateStatement {
 
 #[inherent]
 impl SchemaStatementBuilder for TableCreateStatement {
-    pub fn build(&self, schema_builder: QueryBuilder) -> String {
+    pub fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        schema_builder.prepare_table_create_statement(self, &mut sql);
+        schema_builder
+            .into()
+            .prepare_table_create_statement(self, &mut sql);
         sql
     }
 
-    pub fn build_any(&self, schema_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String {
         let mut sql = String::with_capacity(256);
         schema_builder.prepare_table_create_statement(self, &mut sql);
         sql
     }
 
-    pub fn to_string(&self, schema_builder: QueryBuilder) -> String;
+    pub fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
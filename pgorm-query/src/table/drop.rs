@@ -1,6 +1,6 @@
 use inherent::inherent;
 
-use crate::{QueryBuilder, SchemaStatementBuilder, types::*};
+use crate::{ConfiguredQueryBuilder, SchemaStatementBuilder, types::*};
 
 /// Drop a table
 ///
@@ -77,17 +77,19 @@ impl TableDropStatement {
 
 #[inherent]
 impl SchemaStatementBuilder for TableDropStatement {
-    pub fn build(&self, schema_builder: QueryBuilder) -> String {
+    pub fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        schema_builder.prepare_table_drop_statement(self, &mut sql);
+        schema_builder
+            .into()
+            .prepare_table_drop_statement(self, &mut sql);
         sql
     }
 
-    pub fn build_any(&self, schema_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String {
         let mut sql = String::with_capacity(256);
         schema_builder.prepare_table_drop_statement(self, &mut sql);
         sql
     }
 
-    pub fn to_string(&self, schema_builder: QueryBuilder) -> String;
+    pub fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
@@ -8,7 +8,7 @@
 //! - Table Rename, see [`TableRenameStatement`]
 //! - Table Truncate, see [`TableTruncateStatement`]
 
-use crate::QueryBuilder;
+use crate::ConfiguredQueryBuilder;
 
 mod alter;
 mod column;
@@ -67,7 +67,7 @@ impl Table {
 
 impl TableStatement {
     /// Build corresponding SQL statement for certain database backend and return SQL string
-    pub fn build(&self, table_builder: QueryBuilder) -> String {
+    pub fn build(&self, table_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         match self {
             Self::Create(stat) => stat.build(table_builder),
             Self::Alter(stat) => stat.build(table_builder),
@@ -78,7 +78,7 @@ impl TableStatement {
     }
 
     /// Build corresponding SQL statement for certain database backend and return SQL string
-    pub fn build_any(&self, table_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, table_builder: &ConfiguredQueryBuilder) -> String {
         match self {
             Self::Create(stat) => stat.build_any(table_builder),
             Self::Alter(stat) => stat.build_any(table_builder),
@@ -89,7 +89,7 @@ impl TableStatement {
     }
 
     /// Build corresponding SQL statement for certain database backend and return SQL string
-    pub fn to_string(&self, table_builder: QueryBuilder) -> String {
+    pub fn to_string(&self, table_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         match self {
             Self::Create(stat) => stat.to_string(table_builder),
             Self::Alter(stat) => stat.to_string(table_builder),
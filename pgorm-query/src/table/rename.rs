@@ -1,6 +1,6 @@
 use inherent::inherent;
 
-use crate::{QueryBuilder, SchemaStatementBuilder, types::*};
+use crate::{ConfiguredQueryBuilder, SchemaStatementBuilder, types::*};
 
 /// Rename a table
 ///
@@ -51,17 +51,19 @@ impl TableRenameStatement {
 
 #[inherent]
 impl SchemaStatementBuilder for TableRenameStatement {
-    pub fn build(&self, schema_builder: QueryBuilder) -> String {
+    pub fn build(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String {
         let mut sql = String::with_capacity(256);
-        schema_builder.prepare_table_rename_statement(self, &mut sql);
+        schema_builder
+            .into()
+            .prepare_table_rename_statement(self, &mut sql);
         sql
     }
 
-    pub fn build_any(&self, schema_builder: &QueryBuilder) -> String {
+    pub fn build_any(&self, schema_builder: &ConfiguredQueryBuilder) -> String {
         let mut sql = String::with_capacity(256);
         schema_builder.prepare_table_rename_statement(self, &mut sql);
         sql
     }
 
-    pub fn to_string(&self, schema_builder: QueryBuilder) -> String;
+    pub fn to_string(&self, schema_builder: impl Into<ConfiguredQueryBuilder>) -> String;
 }
@@ -679,4 +679,21 @@ mod tests {
             tokens.iter().map(|x| x.to_string()).collect::<String>()
         );
     }
+    #[test]
+    fn test_23() {
+        let string = "a$1 = $12";
+        let tokenizer = Tokenizer::new(string);
+        let tokens: Vec<Token> = tokenizer.iter().collect();
+        assert_eq!(
+            tokens,
+            vec![
+                Token::Unquoted("a$1".to_string()),
+                Token::Space(" ".to_string()),
+                Token::Punctuation("=".to_string()),
+                Token::Space(" ".to_string()),
+                Token::Punctuation("$".to_string()),
+                Token::Unquoted("12".to_string()),
+            ]
+        );
+    }
 }
@@ -5,14 +5,23 @@ use std::{fmt, mem, ops};
 
 pub use std::sync::Arc as RcOrArc;
 
+/// The quote characters of identifiers, and whether a simple identifier is left unquoted, see
+/// [`ConfiguredQueryBuilder::unquoted_identifiers`](crate::ConfiguredQueryBuilder::unquoted_identifiers)
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
-pub struct Quote(pub(crate) u8, pub(crate) u8);
+pub struct Quote(pub(crate) u8, pub(crate) u8, pub(crate) bool);
 
 macro_rules! iden_trait {
     ($($bounds:ident),*) => {
         /// Identifier
         pub trait Iden where $(Self: $bounds),* {
             fn prepare(&self, s: &mut dyn fmt::Write, q: Quote) {
+                if q.2 {
+                    let name = self.to_string();
+                    if q.leaves_unquoted(&name) {
+                        write!(s, "{name}").unwrap();
+                        return;
+                    }
+                }
                 write!(s, "{}{}{}", q.left(), self.quoted(q), q.right()).unwrap();
             }
 
@@ -133,8 +142,12 @@ pub enum TableRef {
     SubQuery(SelectStatement, DynIden),
     /// Values list with alias
     ValuesList(Vec<ValueTuple>, DynIden),
+    /// Values list of expressions with alias, e.g. to cast the parameters of a row
+    ValuesExprList(Vec<Vec<SimpleExpr>>, DynIden),
     /// Function call with alias
     FunctionCall(FunctionCall, DynIden),
+    /// Table reference prefixed with `ONLY`, excluding inheriting tables and partitions
+    Only(Box<TableRef>),
 }
 
 pub trait IntoTableRef {
@@ -156,6 +169,8 @@ pub enum BinOper {
     NotLike,
     Is,
     IsNot,
+    IsDistinctFrom,
+    IsNotDistinctFrom,
     In,
     NotIn,
     Between,
@@ -192,6 +207,8 @@ pub enum BinOper {
     GetJsonField,
     /// `->>`. Retrieves JSON field and casts it to an appropriate SQL type.
     CastJsonField,
+    /// `#-`. Deletes the JSON field or array element at the given path.
+    DeleteJsonPath,
     /// `~` Regex operator.
     Regex,
     /// `~*`. Regex operator with case insensitive matching.
@@ -199,6 +216,14 @@ pub enum BinOper {
     EuclideanDistance,
     NegativeInnerProduct,
     CosineDistance,
+    /// `AT TIME ZONE`. Converts between `timestamp` and `timestamptz`.
+    AtTimeZone,
+    /// `<<`. The left network is strictly contained within the right one. Unlike
+    /// [`BinOper::LShift`], this is the `inet`/`cidr` operator.
+    SubnetOf,
+    /// `>>`. The left network strictly contains the right one. Unlike
+    /// [`BinOper::RShift`], this is the `inet`/`cidr` operator.
+    SupernetOf,
     Custom(&'static str),
 }
 
@@ -332,7 +357,7 @@ pub enum SubQueryOper {
 
 impl Quote {
     pub fn new(c: u8) -> Self {
-        Self(c, c)
+        Self(c, c, false)
     }
 
     pub fn left(&self) -> char {
@@ -342,6 +367,39 @@ impl Quote {
     pub fn right(&self) -> char {
         char::from(self.1)
     }
+
+    /// Whether the identifier `name` is written without quotes, see
+    /// [`ConfiguredQueryBuilder::unquoted_identifiers`](crate::ConfiguredQueryBuilder::unquoted_identifiers)
+    pub fn leaves_unquoted(&self, name: &str) -> bool {
+        self.2 && is_simple_identifier(name)
+    }
+}
+
+/// Keywords Postgres reserves, including those that may only name a function or type, which
+/// must be quoted to be used as an identifier
+#[rustfmt::skip]
+const RESERVED_WORDS: &[&str] = &[
+    "all", "analyse", "analyze", "and", "any", "array", "as", "asc", "asymmetric",
+    "authorization", "binary", "both", "case", "cast", "check", "collate", "collation", "column",
+    "concurrently", "constraint", "create", "cross", "current_catalog", "current_date",
+    "current_role", "current_schema", "current_time", "current_timestamp", "current_user",
+    "default", "deferrable", "desc", "distinct", "do", "else", "end", "except", "false", "fetch",
+    "for", "foreign", "freeze", "from", "full", "grant", "group", "having", "ilike", "in",
+    "initially", "inner", "intersect", "into", "is", "isnull", "join", "lateral", "leading",
+    "left", "like", "limit", "localtime", "localtimestamp", "natural", "not", "notnull", "null",
+    "offset", "on", "only", "or", "order", "outer", "overlaps", "placing", "primary",
+    "references", "returning", "right", "select", "session_user", "similar", "some",
+    "symmetric", "system_user", "table", "tablesample", "then", "to", "trailing", "true",
+    "union", "unique", "user", "using", "variadic", "verbose", "when", "where", "window", "with",
+];
+
+/// Whether `name` matches `[a-z_][a-z0-9_]*` and is not a reserved word, so that it reads the
+/// same unquoted, since Postgres folds unquoted identifiers to lower case
+pub(crate) fn is_simple_identifier(name: &str) -> bool {
+    let mut chars = name.chars();
+    matches!(chars.next(), Some('a'..='z' | '_'))
+        && chars.all(|c| matches!(c, 'a'..='z' | '0'..='9' | '_'))
+        && RESERVED_WORDS.binary_search(&name).is_err()
 }
 
 impl From<char> for Quote {
@@ -364,7 +422,7 @@ impl From<u8> for Quote {
 
 impl From<(u8, u8)> for Quote {
     fn from((l, r): (u8, u8)) -> Self {
-        Quote(l, r)
+        Quote(l, r, false)
     }
 }
 
@@ -529,7 +587,18 @@ impl TableRef {
             }
             Self::SubQuery(statement, _) => Self::SubQuery(statement, alias.into_iden()),
             Self::ValuesList(values, _) => Self::ValuesList(values, alias.into_iden()),
+            Self::ValuesExprList(rows, _) => Self::ValuesExprList(rows, alias.into_iden()),
             Self::FunctionCall(func, _) => Self::FunctionCall(func, alias.into_iden()),
+            Self::Only(table_ref) => Self::Only(Box::new(table_ref.alias(alias))),
+        }
+    }
+
+    /// Prefix the table with `ONLY`, so that rows of inheriting tables and
+    /// partitions are left out
+    pub fn only(self) -> Self {
+        match self {
+            Self::Only(_) => self,
+            _ => Self::Only(Box::new(self)),
         }
     }
 }
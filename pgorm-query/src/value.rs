@@ -2,8 +2,8 @@
 
 use std::{borrow::Cow, hash::Hash};
 
+use base64::{Engine, engine::general_purpose::STANDARD as BASE64};
 use serde_json::Value as Json;
-use std::str::from_utf8;
 
 use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 
@@ -17,7 +17,7 @@ use std::net::IpAddr;
 
 use mac_address::MacAddress;
 
-use crate::{ColumnType, QueryBuilder, StringLen};
+use crate::{ColumnType, QueryBuilder, RcOrArc, StringLen};
 
 /// [`Value`] types variant for Postgres array
 #[derive(Clone, Debug, Eq, PartialEq, Hash)]
@@ -60,6 +60,38 @@ pub enum ArrayType {
     MacAddress,
 }
 
+impl ArrayType {
+    /// The type of column an element of this array is bound as, see [`Value::column_type`]
+    pub fn column_type(&self) -> ColumnType {
+        match self {
+            Self::Bool => ColumnType::Boolean,
+            Self::TinyInt => ColumnType::TinyInteger,
+            Self::SmallInt => ColumnType::SmallInteger,
+            Self::Int => ColumnType::Integer,
+            Self::BigInt => ColumnType::BigInteger,
+            Self::TinyUnsigned => ColumnType::TinyUnsigned,
+            Self::SmallUnsigned => ColumnType::Integer,
+            Self::Unsigned => ColumnType::Unsigned,
+            Self::BigUnsigned => ColumnType::BigUnsigned,
+            Self::Float => ColumnType::Float,
+            Self::Double => ColumnType::Double,
+            Self::String | Self::Char => ColumnType::Text,
+            Self::Bytes => ColumnType::Blob,
+            Self::Json => ColumnType::JsonBinary,
+            Self::ChronoDate => ColumnType::Date,
+            Self::ChronoTime => ColumnType::Time,
+            Self::ChronoDateTime => ColumnType::Timestamp,
+            Self::ChronoDateTimeUtc
+            | Self::ChronoDateTimeLocal
+            | Self::ChronoDateTimeWithTimeZone => ColumnType::TimestampWithTimeZone,
+            Self::Uuid => ColumnType::Uuid,
+            Self::Decimal => ColumnType::Decimal(None),
+            Self::IpNetwork => ColumnType::Inet,
+            Self::MacAddress => ColumnType::MacAddr,
+        }
+    }
+}
+
 /// Value variants
 ///
 /// We want the inner Value to be exactly 1 pointer sized, so anything larger should be boxed.
@@ -246,6 +278,116 @@ impl Value {
     {
         T::expect(self, msg)
     }
+
+    /// The inner `bool`; `None` if the value is NULL or not a `Value::Bool`
+    pub fn as_bool(&self) -> Option<bool> {
+        match self {
+            Self::Bool(v) => *v,
+            _ => None,
+        }
+    }
+
+    /// The inner integer widened to `i64`; `None` if the value is NULL, not an integer,
+    /// or a `Value::BigUnsigned` beyond `i64::MAX`
+    pub fn as_i64(&self) -> Option<i64> {
+        match self {
+            Self::TinyInt(v) => v.map(i64::from),
+            Self::SmallInt(v) => v.map(i64::from),
+            Self::Int(v) => v.map(i64::from),
+            Self::BigInt(v) => *v,
+            Self::TinyUnsigned(v) => v.map(i64::from),
+            Self::SmallUnsigned(v) => v.map(i64::from),
+            Self::Unsigned(v) => v.map(i64::from),
+            Self::BigUnsigned(v) => v.and_then(|v| TryFrom::try_from(v).ok()),
+            _ => None,
+        }
+    }
+
+    /// The inner string; `None` if the value is NULL or not a `Value::String`
+    pub fn as_str(&self) -> Option<&str> {
+        match self {
+            Self::String(v) => v.as_deref().map(String::as_str),
+            _ => None,
+        }
+    }
+
+    /// The type of column this value is bound as by the driver. A `Value::Char` binds as
+    /// text, like a `Value::String`.
+    pub fn column_type(&self) -> ColumnType {
+        match self {
+            Self::Bool(_) => ColumnType::Boolean,
+            Self::TinyInt(_) => ColumnType::TinyInteger,
+            Self::SmallInt(_) => ColumnType::SmallInteger,
+            Self::Int(_) => ColumnType::Integer,
+            Self::BigInt(_) => ColumnType::BigInteger,
+            Self::TinyUnsigned(_) => ColumnType::TinyUnsigned,
+            // A `u16` does not fit a `smallint`
+            Self::SmallUnsigned(_) => ColumnType::Integer,
+            Self::Unsigned(_) => ColumnType::Unsigned,
+            Self::BigUnsigned(_) => ColumnType::BigUnsigned,
+            Self::Float(_) => ColumnType::Float,
+            Self::Double(_) => ColumnType::Double,
+            Self::String(_) | Self::Char(_) => ColumnType::Text,
+            Self::Bytes(_) => ColumnType::Blob,
+            Self::Json(_) => ColumnType::JsonBinary,
+            Self::ChronoDate(_) => ColumnType::Date,
+            Self::ChronoTime(_) => ColumnType::Time,
+            Self::ChronoDateTime(_) => ColumnType::Timestamp,
+            Self::ChronoDateTimeUtc(_)
+            | Self::ChronoDateTimeLocal(_)
+            | Self::ChronoDateTimeWithTimeZone(_) => ColumnType::TimestampWithTimeZone,
+            Self::Uuid(_) => ColumnType::Uuid,
+            Self::Decimal(_) => ColumnType::Decimal(None),
+            Self::IpNetwork(_) => ColumnType::Inet,
+            Self::MacAddress(_) => ColumnType::MacAddr,
+            Self::Vector(_) => ColumnType::Vector(None),
+            Self::Array(array_type, _) => ColumnType::Array(RcOrArc::new(array_type.column_type())),
+        }
+    }
+
+    /// Construct a `Value::Array`, checking that every element is a value of `array_type`.
+    /// NULL elements are accepted.
+    pub fn array(array_type: ArrayType, values: Vec<Value>) -> Result<Value, ValueTypeErr> {
+        if values.iter().all(|v| v.is_element_of(&array_type)) {
+            Ok(Self::Array(array_type, Some(Box::new(values))))
+        } else {
+            Err(ValueTypeErr)
+        }
+    }
+
+    fn is_element_of(&self, array_type: &ArrayType) -> bool {
+        matches!(
+            (array_type, self),
+            (ArrayType::Bool, Self::Bool(_))
+                | (ArrayType::TinyInt, Self::TinyInt(_))
+                | (ArrayType::SmallInt, Self::SmallInt(_))
+                | (ArrayType::Int, Self::Int(_))
+                | (ArrayType::BigInt, Self::BigInt(_))
+                | (ArrayType::TinyUnsigned, Self::TinyUnsigned(_))
+                | (ArrayType::SmallUnsigned, Self::SmallUnsigned(_))
+                | (ArrayType::Unsigned, Self::Unsigned(_))
+                | (ArrayType::BigUnsigned, Self::BigUnsigned(_))
+                | (ArrayType::Float, Self::Float(_))
+                | (ArrayType::Double, Self::Double(_))
+                | (ArrayType::String, Self::String(_))
+                | (ArrayType::Char, Self::Char(_))
+                | (ArrayType::Bytes, Self::Bytes(_))
+                | (ArrayType::Json, Self::Json(_))
+                | (ArrayType::ChronoDate, Self::ChronoDate(_))
+                | (ArrayType::ChronoTime, Self::ChronoTime(_))
+                | (ArrayType::ChronoDateTime, Self::ChronoDateTime(_))
+                | (ArrayType::ChronoDateTimeUtc, Self::ChronoDateTimeUtc(_))
+                | (ArrayType::ChronoDateTimeLocal, Self::ChronoDateTimeLocal(_))
+                | (
+                    ArrayType::ChronoDateTimeWithTimeZone,
+                    Self::ChronoDateTimeWithTimeZone(_)
+                )
+                | (ArrayType::Uuid, Self::Uuid(_))
+                | (ArrayType::Decimal, Self::Decimal(_))
+                | (ArrayType::IpNetwork, Self::IpNetwork(_))
+                | (ArrayType::MacAddress, Self::MacAddress(_))
+        ) || matches!(self, Self::Array(ty, _) if ty == array_type)
+    }
 }
 
 macro_rules! type_to_value {
@@ -634,6 +776,7 @@ pub mod with_array {
     impl NotU8 for char {}
     impl NotU8 for String {}
     impl NotU8 for Vec<u8> {}
+    impl<T> NotU8 for Vec<T> where T: NotU8 {}
 
     // TODO impl<T: NotU8> NotU8 for Option<T> {}
 
@@ -759,6 +902,8 @@ macro_rules! box_to_opt_ref {
     };
 }
 
+// The accessors below return `None` both for a NULL and for a value of another variant,
+// check with the matching `is_*` first to tell them apart.
 impl Value {
     pub fn is_json(&self) -> bool {
         matches!(self, Self::Json(_))
@@ -767,7 +912,7 @@ impl Value {
     pub fn as_ref_json(&self) -> Option<&Json> {
         match self {
             Self::Json(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::Json"),
+            _ => None,
         }
     }
 }
@@ -780,7 +925,7 @@ impl Value {
     pub fn as_ref_chrono_date(&self) -> Option<&NaiveDate> {
         match self {
             Self::ChronoDate(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::ChronoDate"),
+            _ => None,
         }
     }
 }
@@ -793,7 +938,7 @@ impl Value {
     pub fn as_ref_chrono_time(&self) -> Option<&NaiveTime> {
         match self {
             Self::ChronoTime(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::ChronoTime"),
+            _ => None,
         }
     }
 }
@@ -806,7 +951,7 @@ impl Value {
     pub fn as_ref_chrono_date_time(&self) -> Option<&NaiveDateTime> {
         match self {
             Self::ChronoDateTime(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::ChronoDateTime"),
+            _ => None,
         }
     }
 }
@@ -819,7 +964,7 @@ impl Value {
     pub fn as_ref_chrono_date_time_utc(&self) -> Option<&DateTime<Utc>> {
         match self {
             Self::ChronoDateTimeUtc(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::ChronoDateTimeUtc"),
+            _ => None,
         }
     }
 }
@@ -832,7 +977,7 @@ impl Value {
     pub fn as_ref_chrono_date_time_local(&self) -> Option<&DateTime<Local>> {
         match self {
             Self::ChronoDateTimeLocal(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::ChronoDateTimeLocal"),
+            _ => None,
         }
     }
 }
@@ -845,7 +990,7 @@ impl Value {
     pub fn as_ref_chrono_date_time_with_time_zone(&self) -> Option<&DateTime<FixedOffset>> {
         match self {
             Self::ChronoDateTimeWithTimeZone(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::ChronoDateTimeWithTimeZone"),
+            _ => None,
         }
     }
 }
@@ -859,7 +1004,7 @@ impl Value {
             Self::ChronoDateTimeUtc(v) => v.as_ref().map(|v| v.naive_utc().to_string()),
             Self::ChronoDateTimeLocal(v) => v.as_ref().map(|v| v.naive_utc().to_string()),
             Self::ChronoDateTimeWithTimeZone(v) => v.as_ref().map(|v| v.naive_utc().to_string()),
-            _ => panic!("not chrono Value"),
+            _ => None,
         }
     }
 }
@@ -872,14 +1017,14 @@ impl Value {
     pub fn as_ref_decimal(&self) -> Option<&Decimal> {
         match self {
             Self::Decimal(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::Decimal"),
+            _ => None,
         }
     }
 
     pub fn decimal_to_f64(&self) -> Option<f64> {
         use rust_decimal::prelude::ToPrimitive;
 
-        self.as_ref_decimal().map(|d| d.to_f64().unwrap())
+        self.as_ref_decimal().and_then(|d| d.to_f64())
     }
 }
 
@@ -890,7 +1035,7 @@ impl Value {
     pub fn as_ref_uuid(&self) -> Option<&Uuid> {
         match self {
             Self::Uuid(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::Uuid"),
+            _ => None,
         }
     }
 }
@@ -903,7 +1048,7 @@ impl Value {
     pub fn as_ref_array(&self) -> Option<&Vec<Value>> {
         match self {
             Self::Array(_, v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::Array"),
+            _ => None,
         }
     }
 }
@@ -916,14 +1061,14 @@ impl Value {
     pub fn as_ref_ipnetwork(&self) -> Option<&IpNetwork> {
         match self {
             Self::IpNetwork(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::IpNetwork"),
+            _ => None,
         }
     }
 
     pub fn as_ipaddr(&self) -> Option<IpAddr> {
         match self {
             Self::IpNetwork(v) => v.clone().map(|v| v.network()),
-            _ => panic!("not Value::IpNetwork"),
+            _ => None,
         }
     }
 }
@@ -936,7 +1081,7 @@ impl Value {
     pub fn as_ref_mac_address(&self) -> Option<&MacAddress> {
         match self {
             Self::MacAddress(v) => box_to_opt_ref!(v),
-            _ => panic!("not Value::MacAddress"),
+            _ => None,
         }
     }
 }
@@ -1127,7 +1272,13 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         | Value::String(None)
         | Value::Char(None)
         | Value::Bytes(None)
-        | Value::Json(None) => Json::Null,
+        | Value::Json(None)
+        | Value::ChronoDate(None)
+        | Value::ChronoTime(None)
+        | Value::ChronoDateTime(None)
+        | Value::ChronoDateTimeWithTimeZone(None)
+        | Value::ChronoDateTimeUtc(None)
+        | Value::ChronoDateTimeLocal(None) => Json::Null,
 
         Value::Decimal(None) => Json::Null,
 
@@ -1151,25 +1302,23 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         Value::Double(Some(v)) => (*v).into(),
         Value::String(Some(s)) => Json::String(s.as_ref().clone()),
         Value::Char(Some(v)) => Json::String(v.to_string()),
-        Value::Bytes(Some(s)) => Json::String(from_utf8(s).unwrap().to_string()),
+        // JSON has no binary type, and bytes need not be valid UTF-8
+        Value::Bytes(Some(s)) => Json::String(BASE64.encode(s.as_ref())),
         Value::Json(Some(v)) => v.as_ref().clone(),
 
-        Value::ChronoDate(_) => QueryBuilder.value_to_string(value).into(),
+        Value::ChronoDate(Some(v)) => v.to_string().into(),
 
-        Value::ChronoTime(_) => QueryBuilder.value_to_string(value).into(),
+        Value::ChronoTime(Some(v)) => v.to_string().into(),
 
-        Value::ChronoDateTime(_) => QueryBuilder.value_to_string(value).into(),
+        Value::ChronoDateTime(Some(v)) => v.to_string().into(),
 
-        Value::ChronoDateTimeWithTimeZone(_) => QueryBuilder.value_to_string(value).into(),
+        Value::ChronoDateTimeWithTimeZone(Some(v)) => v.to_string().into(),
 
-        Value::ChronoDateTimeUtc(_) => QueryBuilder.value_to_string(value).into(),
+        Value::ChronoDateTimeUtc(Some(v)) => v.to_string().into(),
 
-        Value::ChronoDateTimeLocal(_) => QueryBuilder.value_to_string(value).into(),
+        Value::ChronoDateTimeLocal(Some(v)) => v.to_string().into(),
 
-        Value::Decimal(Some(v)) => {
-            use rust_decimal::prelude::ToPrimitive;
-            v.as_ref().to_f64().unwrap().into()
-        }
+        Value::Decimal(Some(v)) => decimal_to_json_value(v),
 
         Value::Uuid(Some(v)) => Json::String(v.to_string()),
         Value::Array(_, Some(v)) => {
@@ -1177,9 +1326,186 @@ pub fn sea_value_to_json_value(value: &Value) -> Json {
         }
         Value::Vector(Some(v)) => Json::Array(v.as_slice().iter().map(|&v| v.into()).collect()),
 
-        Value::IpNetwork(Some(_)) => QueryBuilder.value_to_string(value).into(),
+        Value::IpNetwork(Some(v)) => v.to_string().into(),
 
-        Value::MacAddress(Some(_)) => QueryBuilder.value_to_string(value).into(),
+        Value::MacAddress(Some(v)) => v.to_string().into(),
+    }
+}
+
+/// A decimal becomes a JSON number when an `f64` holds it exactly, and otherwise a string
+/// of its digits, which [`json_to_value`] reads back without loss.
+fn decimal_to_json_value(v: &Decimal) -> Json {
+    use rust_decimal::prelude::ToPrimitive;
+
+    v.to_f64()
+        .filter(|f| Decimal::from_str_exact(&f.to_string()).is_ok_and(|d| d == *v))
+        .and_then(serde_json::Number::from_f64)
+        .map_or_else(|| Json::String(v.to_string()), Json::Number)
+}
+
+/// Convert json value to value, picking the variant from the column type.
+/// JSON `null` becomes a NULL of that variant.
+///
+/// This undoes [`sea_value_to_json_value`], so bytes are read from base64.
+pub fn json_to_value(json: &Json, col_type: &ColumnType) -> Result<Value, ValueTypeErr> {
+    Ok(match col_type {
+        ColumnType::Char(_)
+        | ColumnType::String(_)
+        | ColumnType::Text
+        | ColumnType::Custom(_)
+        | ColumnType::Enum { .. }
+        | ColumnType::LTree => Value::String(json_str(json)?.map(|v| Box::new(v.to_owned()))),
+        ColumnType::TinyInteger => Value::TinyInt(json_int(json)?),
+        ColumnType::SmallInteger => Value::SmallInt(json_int(json)?),
+        ColumnType::Integer => Value::Int(json_int(json)?),
+        ColumnType::BigInteger => Value::BigInt(json_int(json)?),
+        ColumnType::TinyUnsigned => Value::TinyUnsigned(json_int(json)?),
+        ColumnType::SmallUnsigned => Value::SmallUnsigned(json_int(json)?),
+        ColumnType::Unsigned => Value::Unsigned(json_int(json)?),
+        ColumnType::BigUnsigned => Value::BigUnsigned(json_int(json)?),
+        ColumnType::Float => Value::Float(json_f64(json)?.map(|v| v as f32)),
+        ColumnType::Double => Value::Double(json_f64(json)?),
+        ColumnType::Decimal(_) | ColumnType::Money(_) => Value::Decimal(
+            match json {
+                Json::Null => None,
+                // a string keeps digits that a JSON number would lose
+                Json::String(v) => Some(v.parse().map_err(|_| ValueTypeErr)?),
+                Json::Number(v) => Some(
+                    Decimal::from_str_exact(&v.to_string())
+                        .or_else(|_| Decimal::from_scientific(&v.to_string()))
+                        .map_err(|_| ValueTypeErr)?,
+                ),
+                _ => return Err(ValueTypeErr),
+            }
+            .map(Box::new),
+        ),
+        ColumnType::Boolean => Value::Bool(match json {
+            Json::Null => None,
+            Json::Bool(v) => Some(*v),
+            _ => return Err(ValueTypeErr),
+        }),
+        ColumnType::Date => Value::ChronoDate(json_parse(json)?.map(Box::new)),
+        ColumnType::Time => Value::ChronoTime(json_parse(json)?.map(Box::new)),
+        ColumnType::DateTime | ColumnType::Timestamp => {
+            Value::ChronoDateTime(json_parse(json)?.map(Box::new))
+        }
+        ColumnType::TimestampWithTimeZone => {
+            Value::ChronoDateTimeWithTimeZone(json_parse(json)?.map(Box::new))
+        }
+        ColumnType::Blob | ColumnType::Binary(_) | ColumnType::VarBinary(_) => Value::Bytes(
+            json_str(json)?
+                .map(|v| BASE64.decode(v).map(Box::new).map_err(|_| ValueTypeErr))
+                .transpose()?,
+        ),
+        ColumnType::Json | ColumnType::JsonBinary => match json {
+            Json::Null => Value::Json(None),
+            _ => Value::Json(Some(Box::new(json.clone()))),
+        },
+        ColumnType::Uuid => Value::Uuid(json_parse(json)?.map(Box::new)),
+        ColumnType::Inet | ColumnType::Cidr => Value::IpNetwork(json_parse(json)?.map(Box::new)),
+        ColumnType::MacAddr => Value::MacAddress(json_parse(json)?.map(Box::new)),
+        ColumnType::Vector(_) => Value::Vector(match json {
+            Json::Null => None,
+            Json::Array(items) => Some(Box::new(pgvector::Vector::from(
+                items
+                    .iter()
+                    .map(|v| v.as_f64().map(|v| v as f32).ok_or(ValueTypeErr))
+                    .collect::<Result<Vec<_>, _>>()?,
+            ))),
+            _ => return Err(ValueTypeErr),
+        }),
+        ColumnType::Array(element_type) => {
+            let array_type = array_type_of(element_type).ok_or(ValueTypeErr)?;
+            match json {
+                Json::Null => Value::Array(array_type, None),
+                Json::Array(items) => Value::array(
+                    array_type,
+                    items
+                        .iter()
+                        .map(|v| json_to_value(v, element_type))
+                        .collect::<Result<_, _>>()?,
+                )?,
+                _ => return Err(ValueTypeErr),
+            }
+        }
+        ColumnType::Year
+        | ColumnType::Interval(_, _)
+        | ColumnType::Bit(_)
+        | ColumnType::VarBit(_) => return Err(ValueTypeErr),
+    })
+}
+
+fn array_type_of(col_type: &ColumnType) -> Option<ArrayType> {
+    Some(match col_type {
+        ColumnType::Char(_)
+        | ColumnType::String(_)
+        | ColumnType::Text
+        | ColumnType::Custom(_)
+        | ColumnType::Enum { .. }
+        | ColumnType::LTree => ArrayType::String,
+        ColumnType::TinyInteger => ArrayType::TinyInt,
+        ColumnType::SmallInteger => ArrayType::SmallInt,
+        ColumnType::Integer => ArrayType::Int,
+        ColumnType::BigInteger => ArrayType::BigInt,
+        ColumnType::TinyUnsigned => ArrayType::TinyUnsigned,
+        ColumnType::SmallUnsigned => ArrayType::SmallUnsigned,
+        ColumnType::Unsigned => ArrayType::Unsigned,
+        ColumnType::BigUnsigned => ArrayType::BigUnsigned,
+        ColumnType::Float => ArrayType::Float,
+        ColumnType::Double => ArrayType::Double,
+        ColumnType::Decimal(_) | ColumnType::Money(_) => ArrayType::Decimal,
+        ColumnType::Boolean => ArrayType::Bool,
+        ColumnType::Date => ArrayType::ChronoDate,
+        ColumnType::Time => ArrayType::ChronoTime,
+        ColumnType::DateTime | ColumnType::Timestamp => ArrayType::ChronoDateTime,
+        ColumnType::TimestampWithTimeZone => ArrayType::ChronoDateTimeWithTimeZone,
+        ColumnType::Blob | ColumnType::Binary(_) | ColumnType::VarBinary(_) => ArrayType::Bytes,
+        ColumnType::Json | ColumnType::JsonBinary => ArrayType::Json,
+        ColumnType::Uuid => ArrayType::Uuid,
+        ColumnType::Inet | ColumnType::Cidr => ArrayType::IpNetwork,
+        ColumnType::MacAddr => ArrayType::MacAddress,
+        _ => return None,
+    })
+}
+
+fn json_str(json: &Json) -> Result<Option<&str>, ValueTypeErr> {
+    match json {
+        Json::Null => Ok(None),
+        Json::String(v) => Ok(Some(v)),
+        _ => Err(ValueTypeErr),
+    }
+}
+
+fn json_parse<T: std::str::FromStr>(json: &Json) -> Result<Option<T>, ValueTypeErr> {
+    json_str(json)?
+        .map(|v| v.parse().map_err(|_| ValueTypeErr))
+        .transpose()
+}
+
+fn json_int<T>(json: &Json) -> Result<Option<T>, ValueTypeErr>
+where
+    T: TryFrom<i64> + TryFrom<u64>,
+{
+    match json {
+        Json::Null => Ok(None),
+        Json::Number(v) => v
+            .as_i64()
+            .and_then(|v| <T as TryFrom<i64>>::try_from(v).ok())
+            .or_else(|| {
+                v.as_u64()
+                    .and_then(|v| <T as TryFrom<u64>>::try_from(v).ok())
+            })
+            .map(Some)
+            .ok_or(ValueTypeErr),
+        _ => Err(ValueTypeErr),
+    }
+}
+
+fn json_f64(json: &Json) -> Result<Option<f64>, ValueTypeErr> {
+    match json {
+        Json::Null => Ok(None),
+        Json::Number(v) => v.as_f64().map(Some).ok_or(ValueTypeErr),
+        _ => Err(ValueTypeErr),
     }
 }
 
@@ -1256,6 +1582,252 @@ mod tests {
         test_none!(i64, BigInt);
     }
 
+    #[test]
+    fn test_safe_accessors() {
+        assert_eq!(Value::from(true).as_bool(), Some(true));
+        assert_eq!(Value::Bool(None).as_bool(), None);
+        assert_eq!(Value::from(1i32).as_bool(), None);
+
+        assert_eq!(Value::from(-8i8).as_i64(), Some(-8));
+        assert_eq!(Value::from(-16i16).as_i64(), Some(-16));
+        assert_eq!(Value::from(-32i32).as_i64(), Some(-32));
+        assert_eq!(Value::from(i64::MIN).as_i64(), Some(i64::MIN));
+        assert_eq!(Value::from(u8::MAX).as_i64(), Some(255));
+        assert_eq!(Value::from(u16::MAX).as_i64(), Some(65535));
+        assert_eq!(Value::from(u32::MAX).as_i64(), Some(4294967295));
+        assert_eq!(Value::from(i64::MAX as u64).as_i64(), Some(i64::MAX));
+        assert_eq!(Value::from(u64::MAX).as_i64(), None);
+        assert_eq!(Value::Int(None).as_i64(), None);
+        assert_eq!(Value::from(1.5f64).as_i64(), None);
+        assert_eq!(Value::from("1").as_i64(), None);
+
+        assert_eq!(Value::from("hello").as_str(), Some("hello"));
+        assert_eq!(Value::String(None).as_str(), None);
+        assert_eq!(Value::from('c').as_str(), None);
+        assert_eq!(Value::from(true).as_str(), None);
+    }
+
+    #[test]
+    fn test_checked_array() {
+        assert_eq!(
+            Value::array(ArrayType::Int, vec![1.into(), Value::Int(None), 3.into()]).unwrap(),
+            Value::Array(
+                ArrayType::Int,
+                Some(Box::new(vec![1.into(), Value::Int(None), 3.into()]))
+            )
+        );
+        assert_eq!(
+            Value::array(ArrayType::String, vec![]).unwrap(),
+            Value::Array(ArrayType::String, Some(Box::new(vec![])))
+        );
+        assert!(Value::array(ArrayType::Int, vec![1.into(), "2".into()]).is_err());
+        assert!(Value::array(ArrayType::BigInt, vec![1i32.into()]).is_err());
+    }
+
+    #[test]
+    fn test_accessors_none_and_wrong_variant() {
+        let decimal = Value::Decimal(Some(Box::new(Decimal::new(125, 2))));
+        assert_eq!(decimal.decimal_to_f64(), Some(1.25));
+        assert_eq!(Value::Decimal(None).decimal_to_f64(), None);
+        assert_eq!(Value::Int(Some(1)).decimal_to_f64(), None);
+        assert_eq!(Value::Int(Some(1)).as_ref_decimal(), None);
+
+        let network: IpNetwork = "10.0.0.0/8".parse().unwrap();
+        assert_eq!(
+            Value::IpNetwork(Some(Box::new(network))).as_ipaddr(),
+            Some("10.0.0.0".parse().unwrap())
+        );
+        assert_eq!(Value::IpNetwork(None).as_ipaddr(), None);
+        assert_eq!(Value::String(None).as_ipaddr(), None);
+        assert_eq!(Value::String(None).as_ref_ipnetwork(), None);
+
+        assert_eq!(Value::Bool(Some(true)).as_ref_json(), None);
+        assert_eq!(Value::Bool(Some(true)).as_ref_uuid(), None);
+        assert_eq!(Value::Bool(Some(true)).as_ref_array(), None);
+        assert_eq!(Value::Bool(Some(true)).as_ref_mac_address(), None);
+        assert_eq!(Value::Bool(Some(true)).as_ref_chrono_date(), None);
+        assert_eq!(
+            Value::Bool(Some(true)).chrono_as_naive_utc_in_string(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_sea_value_to_json_value_decimal() {
+        use serde_json::json;
+
+        let decimal = |s: &str| Value::Decimal(Some(Box::new(s.parse().unwrap())));
+
+        assert_eq!(sea_value_to_json_value(&decimal("12.30")), json!(12.3));
+        // Too many digits for an f64, so kept as a string
+        let precise = decimal("12345678901234567890.123456789");
+        assert_eq!(
+            sea_value_to_json_value(&precise),
+            json!("12345678901234567890.123456789")
+        );
+        assert_eq!(
+            json_to_value(
+                &sea_value_to_json_value(&precise),
+                &ColumnType::Decimal(None)
+            )
+            .unwrap(),
+            precise
+        );
+        assert_eq!(
+            sea_value_to_json_value(&Value::Decimal(Some(Box::new(Decimal::MAX)))),
+            json!(Decimal::MAX.to_string())
+        );
+    }
+
+    #[test]
+    fn test_sea_value_to_json_value_bytes() {
+        use serde_json::json;
+
+        let bytes = Value::Bytes(Some(Box::new(vec![0x00, 0x9f, 0x92, 0x96, 0xff])));
+        assert_eq!(sea_value_to_json_value(&bytes), json!("AJ+Slv8="));
+        assert_eq!(
+            json_to_value(&sea_value_to_json_value(&bytes), &ColumnType::Blob).unwrap(),
+            bytes
+        );
+        assert!(
+            json_to_value(
+                &json!("not base64"),
+                &ColumnType::VarBinary(StringLen::None)
+            )
+            .is_err()
+        );
+    }
+
+    #[test]
+    fn test_sea_value_to_json_value_chrono() {
+        use serde_json::json;
+
+        let date = Value::ChronoDate(Some(Box::new(
+            NaiveDate::from_ymd_opt(2024, 2, 29).unwrap(),
+        )));
+        assert_eq!(sea_value_to_json_value(&date), json!("2024-02-29"));
+        assert_eq!(
+            json_to_value(&sea_value_to_json_value(&date), &ColumnType::Date).unwrap(),
+            date
+        );
+        assert_eq!(
+            sea_value_to_json_value(&Value::ChronoDate(None)),
+            Json::Null
+        );
+    }
+
+    #[test]
+    fn test_json_to_value() {
+        use crate::RcOrArc;
+        use serde_json::json;
+
+        assert_eq!(
+            json_to_value(&json!(42), &ColumnType::Integer).unwrap(),
+            Value::Int(Some(42))
+        );
+        assert_eq!(
+            json_to_value(&json!(42), &ColumnType::BigUnsigned).unwrap(),
+            Value::BigUnsigned(Some(42))
+        );
+        assert_eq!(
+            json_to_value(&json!(1.5), &ColumnType::Double).unwrap(),
+            Value::Double(Some(1.5))
+        );
+        assert_eq!(
+            json_to_value(&json!(2), &ColumnType::Double).unwrap(),
+            Value::Double(Some(2.0))
+        );
+        assert_eq!(
+            json_to_value(&json!("12.30"), &ColumnType::Decimal(None)).unwrap(),
+            Value::Decimal(Some(Box::new(Decimal::new(1230, 2))))
+        );
+        assert_eq!(
+            json_to_value(&json!(12.3), &ColumnType::Decimal(None)).unwrap(),
+            Value::Decimal(Some(Box::new(Decimal::new(123, 1))))
+        );
+        assert_eq!(
+            json_to_value(&json!("hello"), &ColumnType::Text).unwrap(),
+            Value::String(Some(Box::new("hello".to_owned())))
+        );
+        assert_eq!(
+            json_to_value(&json!(true), &ColumnType::Boolean).unwrap(),
+            Value::Bool(Some(true))
+        );
+        assert_eq!(
+            json_to_value(&json!("2024-02-29"), &ColumnType::Date).unwrap(),
+            Value::ChronoDate(Some(Box::new(
+                NaiveDate::from_ymd_opt(2024, 2, 29).unwrap()
+            )))
+        );
+        assert_eq!(
+            json_to_value(
+                &json!("67e55044-10b1-426f-9247-bb680e5fe0c8"),
+                &ColumnType::Uuid
+            )
+            .unwrap(),
+            Value::Uuid(Some(Box::new(
+                Uuid::parse_str("67e55044-10b1-426f-9247-bb680e5fe0c8").unwrap()
+            )))
+        );
+        assert_eq!(
+            json_to_value(&json!({"a": [1]}), &ColumnType::JsonBinary).unwrap(),
+            Value::Json(Some(Box::new(json!({"a": [1]}))))
+        );
+        assert_eq!(
+            json_to_value(&json!(null), &ColumnType::Integer).unwrap(),
+            Value::Int(None)
+        );
+        assert_eq!(
+            json_to_value(
+                &json!([1, null, 3]),
+                &ColumnType::Array(RcOrArc::new(ColumnType::SmallInteger))
+            )
+            .unwrap(),
+            Value::Array(
+                ArrayType::SmallInt,
+                Some(Box::new(vec![
+                    Value::SmallInt(Some(1)),
+                    Value::SmallInt(None),
+                    Value::SmallInt(Some(3))
+                ]))
+            )
+        );
+        assert_eq!(
+            json_to_value(
+                &json!(["a", "b"]),
+                &ColumnType::Array(RcOrArc::new(ColumnType::Text))
+            )
+            .unwrap(),
+            Value::Array(
+                ArrayType::String,
+                Some(Box::new(vec!["a".into(), "b".into()]))
+            )
+        );
+
+        // mismatches
+        assert!(json_to_value(&json!("42"), &ColumnType::Integer).is_err());
+        assert!(json_to_value(&json!(1.5), &ColumnType::Integer).is_err());
+        assert!(json_to_value(&json!(300), &ColumnType::TinyInteger).is_err());
+        assert!(json_to_value(&json!(-1), &ColumnType::Unsigned).is_err());
+        assert!(json_to_value(&json!(1), &ColumnType::Text).is_err());
+        assert!(json_to_value(&json!("yes"), &ColumnType::Boolean).is_err());
+        assert!(json_to_value(&json!("not a uuid"), &ColumnType::Uuid).is_err());
+        assert!(
+            json_to_value(
+                &json!([1, "2"]),
+                &ColumnType::Array(RcOrArc::new(ColumnType::Integer))
+            )
+            .is_err()
+        );
+        assert!(
+            json_to_value(
+                &json!(1),
+                &ColumnType::Array(RcOrArc::new(ColumnType::Integer))
+            )
+            .is_err()
+        );
+    }
+
     #[test]
     fn test_cow_value() {
         let val: Cow<str> = "hello".into();
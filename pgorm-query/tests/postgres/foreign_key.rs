@@ -60,3 +60,21 @@ fn drop_2() {
         r#"ALTER TABLE "schema"."character" DROP CONSTRAINT "FK_2e303c3a712662f1fc2a4d0aad6""#
     );
 }
+
+#[test]
+fn create_3() {
+    assert_eq!(
+        ForeignKey::create()
+            .name("FK_2e303c3a712662f1fc2a4d0aad6")
+            .from(Char::Table, Char::FontId)
+            .to(Font::Table, Font::Id)
+            .not_valid()
+            .to_string(QueryBuilder),
+        [
+            r#"ALTER TABLE "character" ADD CONSTRAINT "FK_2e303c3a712662f1fc2a4d0aad6""#,
+            r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+            r#"NOT VALID"#,
+        ]
+        .join(" ")
+    );
+}
@@ -1,4 +1,5 @@
 use super::*;
+use pgorm_query::error::Error;
 use pretty_assertions::assert_eq;
 
 #[test]
@@ -83,6 +84,95 @@ fn create_6() {
     );
 }
 
+#[test]
+fn create_7() {
+    assert_eq!(
+        Index::create()
+            .unique()
+            .nulls_not_distinct()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .to_string(QueryBuilder::new().server_version(ServerVersion::new(15, 0))),
+        r#"CREATE UNIQUE INDEX "idx-glyph-aspect" ON "glyph" ("aspect") NULLS NOT DISTINCT"#
+    );
+    assert_eq!(
+        Index::create()
+            .unique()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .to_string(QueryBuilder::new().server_version(ServerVersion::new(14, 9))),
+        r#"CREATE UNIQUE INDEX "idx-glyph-aspect" ON "glyph" ("aspect")"#
+    );
+}
+
+#[test]
+fn create_8() {
+    assert_eq!(
+        Index::create()
+            .unique()
+            .nulls_not_distinct()
+            .name("idx-glyph-aspect")
+            .table(Glyph::Table)
+            .col(Glyph::Aspect)
+            .to_string(QueryBuilder::new().server_version(ServerVersion::new(14, 9))),
+        r#"CREATE UNIQUE INDEX "idx-glyph-aspect" ON "glyph" ("aspect")"#
+    );
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer())
+            .index(
+                Index::create()
+                    .unique()
+                    .nulls_not_distinct()
+                    .name("idx-glyph-id")
+                    .col(Glyph::Id)
+            )
+            .to_string(QueryBuilder::new().server_version(ServerVersion::new(14, 9))),
+        r#"CREATE TABLE "glyph" ( "id" integer, CONSTRAINT "idx-glyph-id" UNIQUE ("id") )"#
+    );
+}
+
+#[test]
+fn create_9() {
+    let index = Index::create()
+        .unique()
+        .nulls_not_distinct()
+        .name("idx-glyph-aspect")
+        .table(Glyph::Table)
+        .col(Glyph::Aspect)
+        .to_owned();
+
+    assert_eq!(
+        index.try_build(QueryBuilder::new().server_version(ServerVersion::from_version_num(90624))),
+        Err(Error::UnsupportedByServerVersion {
+            feature: "NULLS NOT DISTINCT",
+            required: ServerVersion::NULLS_NOT_DISTINCT,
+            target: ServerVersion::with_patch(9, 6, 24),
+        })
+    );
+    assert_eq!(
+        index
+            .try_build(QueryBuilder::new().server_version(ServerVersion::from_version_num(150004))),
+        Ok(
+            r#"CREATE UNIQUE INDEX "idx-glyph-aspect" ON "glyph" ("aspect") NULLS NOT DISTINCT"#
+                .to_owned()
+        )
+    );
+    assert_eq!(
+        Table::create()
+            .table(Glyph::Table)
+            .col(ColumnDef::new(Glyph::Id).integer())
+            .index(&mut index.clone())
+            .try_build(QueryBuilder::new().server_version(ServerVersion::new(14, 9)))
+            .unwrap_err()
+            .to_string(),
+        "NULLS NOT DISTINCT requires Postgres 15.0, targeting 14.9"
+    );
+}
+
 #[test]
 fn drop_1() {
     assert_eq!(
@@ -728,6 +728,75 @@ fn select_47() {
     );
 }
 
+#[test]
+fn cond_add_option() {
+    let cond = Cond::all().add(Expr::col(Glyph::Aspect).is_not_null());
+
+    assert_eq!(cond.clone().add_option::<SimpleExpr>(None), cond);
+    assert_eq!(
+        cond.clone()
+            .add_option(Some(Expr::col(Glyph::Aspect).lt(8))),
+        cond.add(Expr::col(Glyph::Aspect).lt(8))
+    );
+}
+
+#[test]
+fn select_schema_table_column() {
+    let statement = Query::select()
+        .column((
+            Alias::new("analytics"),
+            Alias::new("events"),
+            Alias::new("id"),
+        ))
+        .from((Alias::new("analytics"), Alias::new("events")))
+        .and_where(
+            Expr::col((
+                Alias::new("analytics"),
+                Alias::new("events"),
+                Alias::new("id"),
+            ))
+            .gt(10),
+        )
+        .to_string(QueryBuilder);
+
+    assert_eq!(
+        statement,
+        r#"SELECT "analytics"."events"."id" FROM "analytics"."events" WHERE "analytics"."events"."id" > 10"#
+    );
+}
+
+#[test]
+fn select_cust_typed() {
+    let color = ColumnType::Enum {
+        name: Alias::new("color").into_iden(),
+        variants: vec![
+            Alias::new("red").into_iden(),
+            Alias::new("blue").into_iden(),
+        ],
+    };
+    let query = Query::select()
+        .column(Glyph::Id)
+        .from(Glyph::Table)
+        .and_where(Expr::cust_typed(r#""color" = $1"#, [("red", color)]))
+        .to_owned();
+
+    assert_eq!(
+        query.build(QueryBuilder),
+        (
+            r#"SELECT "id" FROM "glyph" WHERE "color" = $1::"color""#.to_owned(),
+            Values(vec!["red".into()])
+        )
+    );
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        r#"SELECT "id" FROM "glyph" WHERE "color" = 'red'::"color""#
+    );
+    assert_eq!(
+        query.to_string(QueryBuilder::new().unquoted_identifiers(true)),
+        r#"SELECT id FROM glyph WHERE "color" = 'red'::color"#
+    );
+}
+
 #[test]
 fn select_48() {
     let statement = Query::select()
@@ -900,6 +969,76 @@ fn select_54() {
     );
 }
 
+#[test]
+fn select_distinct() {
+    assert_eq!(
+        Query::select()
+            .distinct()
+            .columns([Glyph::Aspect])
+            .from(Glyph::Table)
+            .to_string(QueryBuilder),
+        r#"SELECT DISTINCT "aspect" FROM "glyph""#
+    );
+}
+
+#[test]
+fn select_distinct_last_call_wins() {
+    assert_eq!(
+        Query::select()
+            .distinct_on([Glyph::Aspect])
+            .distinct()
+            .columns([Glyph::Aspect])
+            .from(Glyph::Table)
+            .to_string(QueryBuilder),
+        r#"SELECT DISTINCT "aspect" FROM "glyph""#
+    );
+    assert_eq!(
+        Query::select()
+            .distinct()
+            .distinct_on([Glyph::Aspect])
+            .columns([Glyph::Aspect])
+            .from(Glyph::Table)
+            .to_string(QueryBuilder),
+        r#"SELECT DISTINCT ON ("aspect") "aspect" FROM "glyph""#
+    );
+    assert_eq!(
+        Query::select()
+            .distinct()
+            .distinct_on(Vec::<Glyph>::new())
+            .columns([Glyph::Aspect])
+            .from(Glyph::Table)
+            .to_string(QueryBuilder),
+        r#"SELECT "aspect" FROM "glyph""#
+    );
+}
+
+#[test]
+fn select_from_only() {
+    assert_eq!(
+        Query::select()
+            .columns([Char::Character, Char::SizeW])
+            .from_only(Char::Table)
+            .and_where(Expr::col(Char::SizeW).gt(10))
+            .to_string(QueryBuilder),
+        r#"SELECT "character", "size_w" FROM ONLY "character" WHERE "size_w" > 10"#
+    );
+    assert_eq!(
+        Query::select()
+            .column((Alias::new("c"), Char::Character))
+            .from_as(Char::Table.into_table_ref().only(), Alias::new("c"))
+            .inner_join(
+                Font::Table,
+                Expr::col((Alias::new("c"), Char::FontId)).equals((Font::Table, Font::Id))
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"SELECT "c"."character" FROM ONLY "character" AS "c""#,
+            r#"INNER JOIN "font" ON "c"."font_id" = "font"."id""#,
+        ]
+        .join(" ")
+    );
+}
+
 #[test]
 fn select_55() {
     let statement = Query::select()
@@ -1273,6 +1412,20 @@ fn insert_10() {
     );
 }
 
+#[test]
+fn insert_char_value() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Char::Table)
+            .columns([Char::Character])
+            .values_panic(['é'.into()])
+            .values_panic(['\''.into()])
+            .values_panic(['\\'.into()])
+            .to_string(QueryBuilder),
+        r#"INSERT INTO "character" ("character") VALUES ('é'), (''''), (E'\\')"#
+    );
+}
+
 #[test]
 #[allow(clippy::approx_constant)]
 fn insert_on_conflict_1() {
@@ -1561,6 +1714,101 @@ fn insert_on_conflict_do_nothing_on() {
     );
 }
 
+#[test]
+fn insert_on_conflict_target_and_action_where() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Aspect, Glyph::Image])
+            .values_panic(["abcd".into(), 3.into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Aspect)
+                    .target_and_where(Expr::col(Glyph::Image).is_null())
+                    .update_column(Glyph::Image)
+                    .action_and_where(Expr::col((Glyph::Table, Glyph::Id)).gt(10))
+                    .to_owned(),
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"INSERT INTO "glyph" ("aspect", "image")"#,
+            r#"VALUES ('abcd', 3)"#,
+            r#"ON CONFLICT ("aspect") WHERE "image" IS NULL"#,
+            r#"DO UPDATE SET "image" = "excluded"."image""#,
+            r#"WHERE "glyph"."id" > 10"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn insert_on_conflict_update_columns_if_changed() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Glyph::Table)
+            .columns([Glyph::Id, Glyph::Aspect, Glyph::Image])
+            .values_panic([1.into(), 2.into(), "abcd".into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_columns_if_changed([Glyph::Aspect])
+                    .update_column(Glyph::Image)
+                    .action_and_where(Expr::col((Glyph::Table, Glyph::Id)).gt(10))
+                    .to_owned(),
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"INSERT INTO "glyph" ("id", "aspect", "image")"#,
+            r#"VALUES (1, 2, 'abcd')"#,
+            r#"ON CONFLICT ("id")"#,
+            r#"DO UPDATE SET "aspect" = "excluded"."aspect", "image" = "excluded"."image""#,
+            r#"WHERE "glyph"."id" > 10 AND "glyph"."aspect" IS DISTINCT FROM "excluded"."aspect""#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        Query::insert()
+            .into_table((Alias::new("public"), Glyph::Table))
+            .columns([Glyph::Id, Glyph::Aspect])
+            .values_panic([1.into(), 2.into()])
+            .on_conflict(
+                OnConflict::column(Glyph::Id)
+                    .update_columns_if_changed([Glyph::Aspect])
+                    .to_owned(),
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"INSERT INTO "public"."glyph" ("id", "aspect") VALUES (1, 2)"#,
+            r#"ON CONFLICT ("id") DO UPDATE SET "aspect" = "excluded"."aspect""#,
+            r#"WHERE "public"."glyph"."aspect" IS DISTINCT FROM "excluded"."aspect""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn insert_on_conflict_excluded_expr() {
+    assert_eq!(
+        Query::insert()
+            .into_table(Alias::new("t"))
+            .columns([Alias::new("key"), Alias::new("count")])
+            .values_panic(["a".into(), 1.into()])
+            .on_conflict(
+                OnConflict::column(Alias::new("key"))
+                    .value(
+                        Alias::new("count"),
+                        Expr::col((Alias::new("t"), Alias::new("count")))
+                            .add(Expr::excluded(Alias::new("count"))),
+                    )
+                    .to_owned(),
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"INSERT INTO "t" ("key", "count") VALUES ('a', 1)"#,
+            r#"ON CONFLICT ("key") DO UPDATE SET "count" = "t"."count" + "excluded"."count""#,
+        ]
+        .join(" ")
+    );
+}
+
 #[test]
 #[allow(clippy::approx_constant)]
 fn insert_returning_all_columns() {
@@ -1681,6 +1929,75 @@ fn update_returning_specified_columns() {
     );
 }
 
+#[test]
+fn update_only() {
+    assert_eq!(
+        Query::update()
+            .table_only(Glyph::Table)
+            .value(Glyph::Aspect, 1.23)
+            .and_where(Expr::col(Glyph::Id).eq(1))
+            .to_string(QueryBuilder),
+        r#"UPDATE ONLY "glyph" SET "aspect" = 1.23 WHERE "id" = 1"#
+    );
+}
+
+#[test]
+fn update_from_values() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(
+                Glyph::Aspect,
+                Expr::col((Alias::new("v"), Alias::new("column2")))
+            )
+            .from_values([(1, 1.5), (2, 2.5)], Alias::new("v"))
+            .and_where(
+                Expr::col((Glyph::Table, Glyph::Id))
+                    .equals((Alias::new("v"), Alias::new("column1")))
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"UPDATE "glyph" SET "aspect" = "v"."column2""#,
+            r#"FROM (VALUES (1, 1.5), (2, 2.5)) AS "v""#,
+            r#"WHERE "glyph"."id" = "v"."column1""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn update_from_values_expr_list() {
+    assert_eq!(
+        Query::update()
+            .table(Glyph::Table)
+            .value(
+                Glyph::Aspect,
+                Expr::col((Alias::new("v"), Alias::new("column2")))
+            )
+            .from(TableRef::ValuesExprList(
+                vec![
+                    vec![
+                        Expr::val(1).cast_as(Alias::new("int4")),
+                        Expr::val(1.5).cast_as(Alias::new("float8")),
+                    ],
+                    vec![Expr::val(2).into(), Expr::val(2.5).into()],
+                ],
+                Alias::new("v").into_iden(),
+            ))
+            .and_where(
+                Expr::col((Glyph::Table, Glyph::Id))
+                    .equals((Alias::new("v"), Alias::new("column1")))
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"UPDATE "glyph" SET "aspect" = "v"."column2""#,
+            r#"FROM (VALUES (CAST(1 AS int4), CAST(1.5 AS float8)), (2, 2.5)) AS "v""#,
+            r#"WHERE "glyph"."id" = "v"."column1""#,
+        ]
+        .join(" ")
+    );
+}
+
 #[test]
 fn delete_1() {
     assert_eq!(
@@ -1732,6 +2049,29 @@ fn escape_4() {
     )
 }
 
+#[test]
+fn escape_5() {
+    let test = r#"it's "a\b""#;
+    assert_eq!(
+        QueryBuilder.escape_standard_string(test),
+        r#"it''s "a\b""#.to_owned()
+    );
+}
+
+#[test]
+fn select_string_literals() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::val("it's"))
+            .expr(Expr::val(r#"say "hi""#))
+            .expr(Expr::val(r#"C:\dir"#))
+            .expr(Expr::val(r#"it's C:\"#))
+            .expr(Expr::val("a\nb"))
+            .to_string(QueryBuilder),
+        r#"SELECT 'it''s', 'say "hi"', E'C:\\dir', E'it\'s C:\\', E'a\nb'"#
+    );
+}
+
 #[test]
 fn delete_returning_all_columns() {
     assert_eq!(
@@ -2102,3 +2442,698 @@ fn test_pgvector_select() {
         r#"SELECT "character" FROM "character" WHERE "character" = '[1,2]'"#
     );
 }
+
+#[test]
+fn select_current_timestamp_filter() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(
+                Expr::col(Alias::new("created_at"))
+                    .gt(Expr::current_timestamp().sub(Expr::cust("INTERVAL '1 day'")))
+            )
+            .and_where(Expr::col(Alias::new("created_on")).lte(Expr::current_date()))
+            .to_string(QueryBuilder),
+        [
+            r#"SELECT "id" FROM "character""#,
+            r#"WHERE "created_at" > CURRENT_TIMESTAMP - (INTERVAL '1 day')"#,
+            r#"AND "created_on" <= CURRENT_DATE"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_now_filter() {
+    assert_eq!(
+        Query::select()
+            .column(Char::Id)
+            .from(Char::Table)
+            .and_where(Expr::col(Alias::new("expires_at")).lt(Expr::now()))
+            .to_string(QueryBuilder),
+        r#"SELECT "id" FROM "character" WHERE "expires_at" < NOW()"#
+    );
+}
+
+#[test]
+fn select_extract() {
+    assert_eq!(
+        Query::select()
+            .expr_as(
+                Func::extract(DatePart::Year, Expr::col(Char::CreatedAt)),
+                Alias::new("year")
+            )
+            .expr(Func::count(Expr::col(Char::Id)))
+            .from(Char::Table)
+            .and_where(Expr::expr(Func::extract(DatePart::Dow, Expr::col(Char::CreatedAt))).eq(0))
+            .group_by_col(Alias::new("year"))
+            .to_string(QueryBuilder),
+        [
+            r#"SELECT EXTRACT(YEAR FROM "created_at") AS "year", COUNT("id") FROM "character""#,
+            r#"WHERE EXTRACT(DOW FROM "created_at") = 0"#,
+            r#"GROUP BY "year""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_extract_epoch() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::extract(
+                DatePart::Epoch,
+                Expr::current_timestamp().sub(Expr::col(Char::CreatedAt))
+            ))
+            .expr(Func::extract(
+                DatePart::TimezoneHour,
+                Expr::current_timestamp()
+            ))
+            .from(Char::Table)
+            .to_string(QueryBuilder),
+        [
+            r#"SELECT EXTRACT(EPOCH FROM CURRENT_TIMESTAMP - "created_at"),"#,
+            r#"EXTRACT(TIMEZONE_HOUR FROM CURRENT_TIMESTAMP) FROM "character""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_date_trunc_group_by() {
+    let bucket = || Func::date_trunc("day", Expr::col(Char::CreatedAt));
+    let query = Query::select()
+        .expr_as(bucket(), Alias::new("day"))
+        .expr_as(Func::count(Expr::col(Char::Id)), Alias::new("count"))
+        .from(Char::Table)
+        .and_where(Expr::col(Char::FontSize).gt(10))
+        .add_group_by([bucket().into()])
+        .order_by_expr(bucket().into(), Order::Asc)
+        .to_owned();
+
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        [
+            r#"SELECT DATE_TRUNC('day', "created_at") AS "day", COUNT("id") AS "count""#,
+            r#"FROM "character" WHERE "font_size" > 10"#,
+            r#"GROUP BY DATE_TRUNC('day', "created_at")"#,
+            r#"ORDER BY DATE_TRUNC('day', "created_at") ASC"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        query.build(QueryBuilder),
+        (
+            [
+                r#"SELECT DATE_TRUNC('day', "created_at") AS "day", COUNT("id") AS "count""#,
+                r#"FROM "character" WHERE "font_size" > $1"#,
+                r#"GROUP BY DATE_TRUNC('day', "created_at")"#,
+                r#"ORDER BY DATE_TRUNC('day', "created_at") ASC"#,
+            ]
+            .join(" "),
+            Values(vec![10.into()])
+        )
+    );
+}
+
+#[test]
+fn select_at_time_zone_timestamp() {
+    let ts = chrono::NaiveDate::from_ymd_opt(2024, 1, 1)
+        .unwrap()
+        .and_hms_opt(9, 0, 0)
+        .unwrap();
+    assert_eq!(
+        Query::select()
+            .expr(Expr::val(ts).at_time_zone("UTC"))
+            .expr(Expr::col(Char::CreatedAt).at_time_zone("UTC"))
+            .from(Char::Table)
+            .and_where(Expr::expr(Expr::col(Char::CreatedAt).at_time_zone("UTC")).gte(ts))
+            .to_string(QueryBuilder),
+        [
+            r#"SELECT '2024-01-01 09:00:00' AT TIME ZONE 'UTC', "created_at" AT TIME ZONE 'UTC'"#,
+            r#"FROM "character" WHERE ("created_at" AT TIME ZONE 'UTC') >= '2024-01-01 09:00:00'"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_at_time_zone_timestamptz() {
+    let ts = chrono::DateTime::<chrono::Utc>::from_timestamp(1_704_099_600, 0).unwrap();
+    let query = Query::select()
+        .expr(Expr::val(ts).at_time_zone("Asia/Tokyo").at_time_zone("UTC"))
+        .to_owned();
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        r#"SELECT ('2024-01-01 09:00:00 +00:00' AT TIME ZONE 'Asia/Tokyo') AT TIME ZONE 'UTC'"#
+    );
+    assert_eq!(
+        query.build(QueryBuilder),
+        (
+            r#"SELECT ($1 AT TIME ZONE $2) AT TIME ZONE $3"#.to_owned(),
+            Values(vec![ts.into(), "Asia/Tokyo".into(), "UTC".into()])
+        )
+    );
+}
+
+#[test]
+fn select_with_recursive_search_cycle() {
+    let cte = CommonTableExpression::new()
+        .table_name(Alias::new("tree"))
+        .columns([Alias::new("id"), Alias::new("parent_id")])
+        .query(
+            Query::select()
+                .columns([Alias::new("id"), Alias::new("parent_id")])
+                .from(Alias::new("category"))
+                .and_where(Expr::col(Alias::new("parent_id")).is_null())
+                .union(
+                    UnionType::All,
+                    Query::select()
+                        .columns([
+                            (Alias::new("category"), Alias::new("id")),
+                            (Alias::new("category"), Alias::new("parent_id")),
+                        ])
+                        .from(Alias::new("category"))
+                        .inner_join(
+                            Alias::new("tree"),
+                            Expr::col((Alias::new("category"), Alias::new("parent_id")))
+                                .equals((Alias::new("tree"), Alias::new("id"))),
+                        )
+                        .to_owned(),
+                )
+                .to_owned(),
+        )
+        .search_breadth_first(Expr::col(Alias::new("id")), Alias::new("ordercol"))
+        .cycle(
+            Expr::col(Alias::new("id")),
+            Alias::new("is_cycle"),
+            Alias::new("path"),
+        )
+        .to_owned();
+    let query = Query::select()
+        .column(Alias::new("id"))
+        .from(Alias::new("tree"))
+        .and_where(Expr::col(Alias::new("is_cycle")).not())
+        .order_by(Alias::new("ordercol"), Order::Asc)
+        .to_owned()
+        .with(WithClause::new().recursive(true).cte(cte).to_owned());
+
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        [
+            r#"WITH RECURSIVE "tree" ("id", "parent_id") AS"#,
+            r#"(SELECT "id", "parent_id" FROM "category" WHERE "parent_id" IS NULL UNION ALL"#,
+            r#"(SELECT "category"."id", "category"."parent_id" FROM "category""#,
+            r#"INNER JOIN "tree" ON "category"."parent_id" = "tree"."id"))"#,
+            r#"SEARCH BREADTH FIRST BY "id" SET "ordercol""#,
+            r#"CYCLE "id" SET "is_cycle" USING "path""#,
+            r#"SELECT "id" FROM "tree" WHERE NOT "is_cycle" ORDER BY "ordercol" ASC"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn with_clause_try_build() {
+    let select = Query::select()
+        .column(Asterisk)
+        .from(Alias::new("cte"))
+        .to_owned();
+    let cte = CommonTableExpression::new()
+        .table_name(Alias::new("cte"))
+        .query(Query::select().expr(Expr::val(1)).to_owned())
+        .to_owned();
+
+    assert_eq!(
+        select
+            .clone()
+            .with(WithClause::new())
+            .try_build(QueryBuilder),
+        Err(error::Error::EmptyWithClause)
+    );
+    assert_eq!(
+        select
+            .clone()
+            .with(
+                WithClause::new()
+                    .recursive(true)
+                    .cte(cte.clone())
+                    .cte(cte.clone())
+                    .to_owned()
+            )
+            .try_build(QueryBuilder),
+        Err(error::Error::RecursiveWithClauseCteCount(2))
+    );
+    assert_eq!(
+        select
+            .with(WithClause::new().cte(cte).to_owned())
+            .try_build(QueryBuilder),
+        Ok((
+            r#"WITH "cte" AS (SELECT $1) SELECT * FROM "cte""#.to_owned(),
+            Values(vec![1.into()])
+        ))
+    );
+}
+
+#[test]
+fn select_func_named_arg() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::cust(Alias::new("make_interval")).named_arg(Alias::new("days"), 5))
+            .to_string(QueryBuilder),
+        r#"SELECT make_interval(days => 5)"#
+    );
+}
+
+#[test]
+fn select_func_variadic_arg() {
+    assert_eq!(
+        Query::select()
+            .expr(
+                Func::cust(Alias::new("concat_ws"))
+                    .arg(",")
+                    .variadic_arg(Expr::cust("ARRAY['a', 'b']"))
+            )
+            .to_string(QueryBuilder),
+        r#"SELECT concat_ws(',', VARIADIC ARRAY['a', 'b'])"#
+    );
+}
+
+#[test]
+fn select_empty_int_array() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::val(Value::Array(
+                ArrayType::Int,
+                Some(Box::default())
+            )))
+            .to_string(QueryBuilder),
+        r#"SELECT ARRAY[]::integer[]"#
+    );
+    assert_eq!(
+        Query::select()
+            .expr(Expr::val(Vec::<i32>::new()))
+            .to_string(QueryBuilder),
+        r#"SELECT ARRAY[]::integer[]"#
+    );
+}
+
+#[test]
+fn select_bool_array() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::val(vec![true, false]))
+            .to_string(QueryBuilder),
+        r#"SELECT ARRAY [true,false]"#
+    );
+}
+
+#[test]
+fn select_2d_int_array() {
+    let matrix = vec![vec![1, 2], vec![3, 4]];
+    assert_eq!(
+        Value::from(matrix.clone()),
+        Value::array(
+            ArrayType::Int,
+            vec![
+                Value::array(ArrayType::Int, vec![1.into(), 2.into()]).unwrap(),
+                Value::array(ArrayType::Int, vec![3.into(), 4.into()]).unwrap(),
+            ]
+        )
+        .unwrap()
+    );
+    assert_eq!(
+        Query::select()
+            .expr(Expr::val(matrix))
+            .to_string(QueryBuilder),
+        r#"SELECT ARRAY [[1,2],[3,4]]"#
+    );
+}
+
+#[test]
+fn select_array_of_exprs() {
+    let query = Query::select()
+        .expr(Expr::array([
+            Expr::col(Alias::new("a")).add(1),
+            Expr::col(Alias::new("b")).mul(2),
+        ]))
+        .from(Alias::new("t"))
+        .to_owned();
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        r#"SELECT ARRAY["a" + 1, "b" * 2] FROM "t""#
+    );
+    assert_eq!(
+        query.build(QueryBuilder),
+        (
+            r#"SELECT ARRAY["a" + $1, "b" * $2] FROM "t""#.to_owned(),
+            Values(vec![1.into(), 2.into()])
+        )
+    );
+    assert_eq!(
+        Query::select()
+            .expr(Func::cast_as_array(
+                Expr::val("{Bold}"),
+                Alias::new("FontStyle")
+            ))
+            .expr(Func::cast_as_type(
+                Expr::val("{1,2}"),
+                ColumnType::Array(RcOrArc::new(ColumnType::Integer))
+            ))
+            .expr(Func::cast_as(Expr::val("{3}"), Alias::new("int[]")))
+            .to_string(QueryBuilder),
+        r#"SELECT CAST('{Bold}' AS "FontStyle"[]), CAST('{1,2}' AS integer[]), CAST('{3}' AS int[])"#
+    );
+}
+
+#[test]
+fn select_unquoted_identifiers() {
+    let query = Query::select()
+        .column((Char::Table, Char::Character))
+        .column(Alias::new("order"))
+        .column(Alias::new("User"))
+        .column(Alias::new("size w"))
+        .from(Char::Table)
+        .and_where(Expr::col(Char::FontId).eq(5))
+        .order_by(Alias::new("user"), Order::Asc)
+        .to_owned();
+
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        [
+            r#"SELECT "character"."character", "order", "User", "size w""#,
+            r#"FROM "character" WHERE "font_id" = 5 ORDER BY "user" ASC"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        query.to_string(QueryBuilder::new().unquoted_identifiers(true)),
+        [
+            r#"SELECT character.character, "order", "User", "size w""#,
+            r#"FROM character WHERE font_id = 5 ORDER BY "user" ASC"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_group_by_rollup() {
+    assert_eq!(
+        Query::select()
+            .columns([Glyph::Aspect, Glyph::Image])
+            .expr(Expr::col(Glyph::Id).count())
+            .from(Glyph::Table)
+            .group_by_rollup([Glyph::Aspect, Glyph::Image])
+            .to_string(QueryBuilder),
+        r#"SELECT "aspect", "image", COUNT("id") FROM "glyph" GROUP BY ROLLUP ("aspect", "image")"#
+    );
+}
+
+#[test]
+fn select_group_by_cube() {
+    assert_eq!(
+        Query::select()
+            .columns([Glyph::Aspect, Glyph::Image])
+            .expr(Expr::col(Glyph::Id).count())
+            .from(Glyph::Table)
+            .group_by_cube([Glyph::Aspect, Glyph::Image])
+            .to_string(QueryBuilder),
+        r#"SELECT "aspect", "image", COUNT("id") FROM "glyph" GROUP BY CUBE ("aspect", "image")"#
+    );
+}
+
+#[test]
+fn select_group_by_grouping_sets() {
+    assert_eq!(
+        Query::select()
+            .columns([Glyph::Aspect, Glyph::Image])
+            .expr(Expr::col(Glyph::Id).count())
+            .from(Glyph::Table)
+            .group_by_grouping_sets([vec![Glyph::Aspect], vec![Glyph::Image], vec![]])
+            .to_string(QueryBuilder),
+        r#"SELECT "aspect", "image", COUNT("id") FROM "glyph" GROUP BY GROUPING SETS (("aspect"), ("image"), ())"#
+    );
+}
+
+#[test]
+fn select_group_by_columns_and_rollup() {
+    assert_eq!(
+        Query::select()
+            .columns([Glyph::Id, Glyph::Aspect, Glyph::Image])
+            .expr(Expr::col(Glyph::Id).count())
+            .from(Glyph::Table)
+            .group_by_col(Glyph::Id)
+            .group_by_rollup([Glyph::Aspect, Glyph::Image])
+            .and_having(Expr::col(Glyph::Id).gt(2))
+            .to_string(QueryBuilder),
+        r#"SELECT "id", "aspect", "image", COUNT("id") FROM "glyph" GROUP BY "id", ROLLUP ("aspect", "image") HAVING "id" > 2"#
+    );
+}
+
+#[test]
+fn select_having_without_group_by() {
+    assert_eq!(
+        Query::select()
+            .expr(Expr::col(Asterisk).count())
+            .from(Glyph::Table)
+            .cond_having(Cond::all().add(Expr::expr(Expr::col(Asterisk).count()).gt(5)))
+            .to_string(QueryBuilder),
+        r#"SELECT COUNT(*) FROM "glyph" HAVING COUNT(*) > 5"#
+    );
+}
+
+#[test]
+fn select_network_operators() {
+    assert_eq!(
+        Query::select()
+            .column(Glyph::Id)
+            .from(Glyph::Table)
+            .and_where(Expr::col(Alias::new("address")).subnet_of(Expr::val("10.0.0.0/8")))
+            .and_where(Expr::col(Alias::new("address")).supernet_of(Expr::val("10.1.2.3")))
+            .and_where(Expr::col(Glyph::Aspect).binary(BinOper::LShift, 2).eq(4))
+            .to_string(QueryBuilder),
+        [
+            r#"SELECT "id" FROM "glyph""#,
+            r#"WHERE "address" << '10.0.0.0/8'"#,
+            r#"AND "address" >> '10.1.2.3'"#,
+            r#"AND "aspect" << 2 = 4"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_macaddr() {
+    use mac_address::MacAddress;
+
+    let low = MacAddress::new([0x08, 0x00, 0x2b, 0x00, 0x00, 0x00]);
+    let high = MacAddress::new([0x08, 0x00, 0x2b, 0xff, 0xff, 0xff]);
+    let query = Query::select()
+        .expr(Func::macaddr_trunc(Expr::col(Alias::new("mac"))))
+        .from(Glyph::Table)
+        .and_where(Expr::col(Alias::new("mac")).between(low, high))
+        .and_where(Expr::col(Alias::new("mac")).ne(high))
+        .to_owned();
+
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        [
+            r#"SELECT TRUNC("mac") FROM "glyph""#,
+            r#"WHERE ("mac" BETWEEN '08:00:2B:00:00:00' AND '08:00:2B:FF:FF:FF')"#,
+            r#"AND "mac" <> '08:00:2B:FF:FF:FF'"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(
+        query.build(QueryBuilder),
+        (
+            [
+                r#"SELECT TRUNC("mac") FROM "glyph""#,
+                r#"WHERE ("mac" BETWEEN $1 AND $2) AND "mac" <> $3"#,
+            ]
+            .join(" "),
+            Values(vec![low.into(), high.into(), high.into()])
+        )
+    );
+}
+
+#[test]
+fn with_clause_data_modifying_cte() {
+    let update = Query::update()
+        .table(Glyph::Table)
+        .value(Glyph::Aspect, Expr::col(Glyph::Aspect).add(1))
+        .and_where(Expr::col(Glyph::Image).like("A%"))
+        .returning(Query::returning().columns([Glyph::Id, Glyph::Aspect]))
+        .to_owned();
+    let cte = CommonTableExpression::new()
+        .query(update)
+        .table_name(Alias::new("updated"))
+        .to_owned();
+    let select = Query::select()
+        .expr(Expr::col(Asterisk).count())
+        .from(Alias::new("updated"))
+        .to_owned();
+
+    assert_eq!(
+        select
+            .with(WithClause::new().cte(cte).to_owned())
+            .build(QueryBuilder),
+        (
+            [
+                r#"WITH "updated" AS (UPDATE "glyph" SET "aspect" = "aspect" + $1 WHERE "image" LIKE $2 RETURNING "id", "aspect")"#,
+                r#"SELECT COUNT(*) FROM "updated""#,
+            ]
+            .join(" "),
+            Values(vec![1.into(), "A%".into()])
+        )
+    );
+}
+
+#[test]
+fn values_statement() {
+    assert_eq!(
+        Query::values([(1, "a"), (2, "b")]).build(QueryBuilder),
+        (
+            r#"VALUES (CAST($1 AS integer), $2), ($3, $4)"#.to_owned(),
+            Values(vec![1.into(), "a".into(), 2.into(), "b".into()])
+        )
+    );
+}
+
+#[test]
+fn with_clause_values_cte() {
+    let cte = CommonTableExpression::new()
+        .query(Query::values([(1, "a"), (2, "b")]))
+        .columns([Glyph::Id, Glyph::Image])
+        .table_name(Alias::new("lookup"))
+        .to_owned();
+    let select = Query::select()
+        .column((Glyph::Table, Glyph::Aspect))
+        .column((Alias::new("lookup"), Glyph::Image))
+        .from(Glyph::Table)
+        .inner_join(
+            Alias::new("lookup"),
+            Expr::col((Glyph::Table, Glyph::Id)).equals((Alias::new("lookup"), Glyph::Id)),
+        )
+        .to_owned();
+
+    assert_eq!(
+        select
+            .with(WithClause::new().cte(cte).to_owned())
+            .to_string(QueryBuilder),
+        [
+            r#"WITH "lookup" ("id", "image") AS (VALUES (CAST(1 AS integer), 'a'), (2, 'b'))"#,
+            r#"SELECT "glyph"."aspect", "lookup"."image" FROM "glyph""#,
+            r#"INNER JOIN "lookup" ON "glyph"."id" = "lookup"."id""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn select_clear_parts() {
+    let base = Query::select()
+        .column(Glyph::Id)
+        .column(Glyph::Aspect)
+        .from(Glyph::Table)
+        .and_where(Expr::col(Glyph::Aspect).gt(2))
+        .group_by_col(Glyph::Aspect)
+        .and_having(Expr::expr(Expr::col(Glyph::Id).count()).gt(1))
+        .order_by(Glyph::Id, Order::Desc)
+        .limit(10)
+        .offset(20)
+        .to_owned();
+
+    let tail = r#"GROUP BY "aspect" HAVING COUNT("id") > 1"#;
+    assert_eq!(
+        base.to_string(QueryBuilder),
+        format!(
+            r#"SELECT "id", "aspect" FROM "glyph" WHERE "aspect" > 2 {tail} ORDER BY "id" DESC LIMIT 10 OFFSET 20"#
+        )
+    );
+    assert_eq!(
+        base.clone()
+            .clear_selects()
+            .expr(Expr::col(Glyph::Id).count())
+            .to_string(QueryBuilder),
+        format!(
+            r#"SELECT COUNT("id") FROM "glyph" WHERE "aspect" > 2 {tail} ORDER BY "id" DESC LIMIT 10 OFFSET 20"#
+        )
+    );
+    assert_eq!(
+        base.clone().clear_where().to_string(QueryBuilder),
+        format!(
+            r#"SELECT "id", "aspect" FROM "glyph" {tail} ORDER BY "id" DESC LIMIT 10 OFFSET 20"#
+        )
+    );
+    assert_eq!(
+        base.clone().clear_order_by().to_string(QueryBuilder),
+        format!(
+            r#"SELECT "id", "aspect" FROM "glyph" WHERE "aspect" > 2 {tail} LIMIT 10 OFFSET 20"#
+        )
+    );
+    assert_eq!(
+        base.clone().clear_limit().to_string(QueryBuilder),
+        format!(
+            r#"SELECT "id", "aspect" FROM "glyph" WHERE "aspect" > 2 {tail} ORDER BY "id" DESC OFFSET 20"#
+        )
+    );
+}
+
+#[test]
+fn select_table_sample() {
+    let query = Query::select()
+        .column(Char::Character)
+        .from(Char::Table)
+        .table_sample_repeatable(SampleMethod::Bernoulli, 2.5, 42.0)
+        .to_owned();
+
+    assert_eq!(
+        query.build(QueryBuilder),
+        (
+            r#"SELECT "character" FROM "character" TABLESAMPLE BERNOULLI ($1) REPEATABLE ($2)"#
+                .to_owned(),
+            Values(vec![2.5f32.into(), 42f64.into()])
+        )
+    );
+}
+
+#[test]
+fn select_cast_as_type() {
+    assert_eq!(
+        Query::select()
+            .expr(Func::cast_as_type(Expr::val("42"), ColumnType::Integer))
+            .expr(Func::cast_as_type(Expr::col(Glyph::Id), ColumnType::Text))
+            .expr(Func::cast_as_type(
+                Expr::val("[1,2,3]"),
+                ColumnType::Vector(Some(3))
+            ))
+            .from(Glyph::Table)
+            .to_string(QueryBuilder),
+        [
+            r#"SELECT CAST('42' AS integer), CAST("id" AS text),"#,
+            r#"CAST('[1,2,3]' AS vector(3)) FROM "glyph""#,
+        ]
+        .join(" ")
+    );
+
+    let query = Query::select()
+        .expr(Func::cast_as_type(
+            Expr::val("red"),
+            ColumnType::Enum {
+                name: Alias::new("color").into_iden(),
+                variants: vec![],
+            },
+        ))
+        .expr(Func::cast_as_type(Expr::val(2024), ColumnType::Year))
+        .to_owned();
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        r#"SELECT CAST('red' AS "color"), CAST(2024 AS smallint)"#
+    );
+    assert_eq!(
+        query.to_string(QueryBuilder::new().unquoted_identifiers(true)),
+        r#"SELECT CAST('red' AS color), CAST(2024 AS smallint)"#
+    );
+}
@@ -609,3 +609,150 @@ fn create_16() {
         .join(" ")
     );
 }
+
+#[test]
+fn alter_11() {
+    assert_eq!(
+        Table::alter()
+            .table(Character::Table)
+            .add_foreign_key(
+                TableForeignKey::new()
+                    .name("FK_character_font")
+                    .from_tbl(Character::Table)
+                    .from_col(Character::FontId)
+                    .to_tbl(Font::Table)
+                    .to_col(Font::Id)
+                    .on_delete(ForeignKeyAction::Cascade)
+                    .not_valid()
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"ALTER TABLE "character""#,
+            r#"ADD CONSTRAINT "FK_character_font""#,
+            r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+            r#"ON DELETE CASCADE NOT VALID"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_12() {
+    assert_eq!(
+        Table::alter()
+            .table(Character::Table)
+            .validate_constraint(Alias::new("FK_character_font"))
+            .to_string(QueryBuilder),
+        r#"ALTER TABLE "character" VALIDATE CONSTRAINT "FK_character_font""#
+    );
+}
+
+#[test]
+fn alter_13() {
+    assert_eq!(
+        Table::alter()
+            .table((Alias::new("schema"), Character::Table))
+            .rename_constraint(Alias::new("FK_character_font"), Alias::new("FK_char_font"))
+            .to_string(QueryBuilder),
+        [
+            r#"ALTER TABLE "schema"."character""#,
+            r#"RENAME CONSTRAINT "FK_character_font" TO "FK_char_font""#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn create_with_not_valid_foreign_key() {
+    assert_eq!(
+        Table::create()
+            .table(Character::Table)
+            .col(ColumnDef::new(Character::FontId).integer())
+            .foreign_key(
+                ForeignKey::create()
+                    .from(Character::Table, Character::FontId)
+                    .to(Font::Table, Font::Id)
+                    .not_valid()
+            )
+            .to_string(QueryBuilder),
+        [
+            r#"CREATE TABLE "character" ("#,
+            r#""font_id" integer,"#,
+            r#"FOREIGN KEY ("font_id") REFERENCES "font" ("id")"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+}
+
+#[test]
+fn alter_set_default() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .set_default(Font::Variant, Expr::val("regular"))
+            .to_string(QueryBuilder),
+        r#"ALTER TABLE "font" ALTER COLUMN "variant" SET DEFAULT 'regular'"#
+    );
+}
+
+#[test]
+fn alter_set_default_keyword() {
+    assert_eq!(
+        Table::alter()
+            .table(Glyph::Table)
+            .set_default(Glyph::Aspect, Keyword::Null)
+            .to_string(QueryBuilder),
+        r#"ALTER TABLE "glyph" ALTER COLUMN "aspect" SET DEFAULT NULL"#
+    );
+}
+
+#[test]
+fn alter_drop_default() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .drop_default(Font::Variant)
+            .to_string(QueryBuilder),
+        r#"ALTER TABLE "font" ALTER COLUMN "variant" DROP DEFAULT"#
+    );
+}
+
+#[test]
+fn alter_set_not_null() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .set_not_null(Font::Name)
+            .to_string(QueryBuilder),
+        r#"ALTER TABLE "font" ALTER COLUMN "name" SET NOT NULL"#
+    );
+}
+
+#[test]
+fn alter_drop_not_null() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .drop_not_null(Font::Name)
+            .to_string(QueryBuilder),
+        r#"ALTER TABLE "font" ALTER COLUMN "name" DROP NOT NULL"#
+    );
+}
+
+#[test]
+fn alter_column_options_combined() {
+    assert_eq!(
+        Table::alter()
+            .table(Font::Table)
+            .drop_default(Font::Variant)
+            .set_not_null(Font::Variant)
+            .to_string(QueryBuilder),
+        [
+            r#"ALTER TABLE "font""#,
+            r#"ALTER COLUMN "variant" DROP DEFAULT,"#,
+            r#"ALTER COLUMN "variant" SET NOT NULL"#,
+        ]
+        .join(" ")
+    );
+}
@@ -0,0 +1,171 @@
+use crate::{ConnectionTrait, DatabaseConnection, DatabasePool, DatabaseTransaction, DbErr};
+use std::{ops::Deref, sync::Arc};
+use tokio_postgres::NoTls;
+
+/// A session-level advisory lock, held on a connection checked out of the pool.
+///
+/// The lock is released by [`AdvisoryLockGuard::unlock`], or when the guard is dropped,
+/// after which the connection goes back to the pool. If the lock can't be released within
+/// a runtime, the connection is closed instead, which ends the session and with it the
+/// lock. The connection is available through [`Deref`] to run queries while holding the
+/// lock.
+#[derive(Debug)]
+pub struct AdvisoryLockGuard {
+    // Shared with the task releasing the lock once the guard is dropped
+    conn: Arc<DatabaseConnection>,
+    key: i64,
+    held: bool,
+}
+
+impl AdvisoryLockGuard {
+    /// The key the lock was taken on
+    pub fn key(&self) -> i64 {
+        self.key
+    }
+
+    /// Release the lock with `pg_advisory_unlock`, returning the connection to the pool
+    pub async fn unlock(mut self) -> Result<(), DbErr> {
+        self.held = false;
+        let (conn, key) = (Arc::clone(&self.conn), self.key);
+        drop(self);
+        advisory_unlock(conn, key).await
+    }
+}
+
+impl Deref for AdvisoryLockGuard {
+    type Target = DatabaseConnection;
+
+    fn deref(&self) -> &Self::Target {
+        &self.conn
+    }
+}
+
+impl Drop for AdvisoryLockGuard {
+    fn drop(&mut self) {
+        if !self.held {
+            return;
+        }
+        let (conn, key) = (Arc::clone(&self.conn), self.key);
+        match tokio::runtime::Handle::try_current() {
+            Ok(handle) => {
+                handle.spawn(async move {
+                    if let Err(err) = advisory_unlock(conn, key).await {
+                        tracing::warn!("Failed to release advisory lock {key}: {err}");
+                    }
+                });
+            }
+            Err(_) => {
+                // The connection task runs on the pool's runtime, so a runtime of our own
+                // is enough to drive the query
+                let res = tokio::runtime::Builder::new_current_thread()
+                    .build()
+                    .map_err(|err| DbErr::Custom(err.to_string()))
+                    .and_then(|rt| rt.block_on(unlock_query(&conn, key)));
+                if let Err(err) = res {
+                    tracing::warn!("Failed to release advisory lock {key}: {err}");
+                }
+            }
+        }
+    }
+}
+
+async fn unlock_query(conn: &DatabaseConnection, key: i64) -> Result<(), DbErr> {
+    let row = conn
+        .query_one("SELECT pg_advisory_unlock($1)", &[&key])
+        .await?;
+    if row.get::<_, bool>(0) {
+        Ok(())
+    } else {
+        Err(DbErr::Custom(format!("Advisory lock {key} was not held")))
+    }
+}
+
+/// Release the lock `key`, closing `conn` rather than returning it to the pool with the
+/// lock possibly still held if that fails. The guard is gone by then, leaving `conn` as
+/// the only reference to the connection.
+async fn advisory_unlock(conn: Arc<DatabaseConnection>, key: i64) -> Result<(), DbErr> {
+    let res = unlock_query(&conn, key).await;
+    if res.is_err() {
+        if let Some(conn) = Arc::into_inner(conn) {
+            conn.detach();
+        }
+    }
+    res
+}
+
+/// The connection `pg_advisory_lock` waits on. If the wait is dropped, say by a timeout,
+/// the connection is closed and the statement cancelled, rather than going back to the pool
+/// and taking the lock there once it is free.
+struct PendingLock(Option<DatabaseConnection>);
+
+impl PendingLock {
+    fn conn(&self) -> &DatabaseConnection {
+        self.0
+            .as_ref()
+            .expect("connection is only taken on completion")
+    }
+
+    fn complete(mut self) -> DatabaseConnection {
+        self.0
+            .take()
+            .expect("connection is only taken on completion")
+    }
+}
+
+impl Drop for PendingLock {
+    fn drop(&mut self) {
+        let Some(conn) = self.0.take() else {
+            return;
+        };
+        let cancel_token = conn.0.cancel_token();
+        conn.detach();
+        if let Ok(handle) = tokio::runtime::Handle::try_current() {
+            handle.spawn(async move {
+                if let Err(err) = cancel_token.cancel_query(NoTls).await {
+                    tracing::warn!("Failed to cancel advisory lock wait: {err}");
+                }
+            });
+        }
+    }
+}
+
+impl DatabasePool {
+    /// Take the session-level advisory lock `key` with `pg_advisory_lock`, waiting until it
+    /// is available. The lock is held on a connection checked out of the pool until the
+    /// returned guard is unlocked or dropped. If the returned future is dropped while waiting,
+    /// the connection is closed instead of being returned to the pool.
+    ///
+    /// ```no_run
+    /// # use pgorm::{ConnectionTrait, DatabasePool, DbErr};
+    /// # async fn f(db: &DatabasePool) -> Result<(), DbErr> {
+    /// let lock = db.advisory_lock(42).await?;
+    /// lock.execute("UPDATE \"job\" SET \"claimed\" = true", &[]).await?;
+    /// lock.unlock().await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn advisory_lock(&self, key: i64) -> Result<AdvisoryLockGuard, DbErr> {
+        let pending = PendingLock(Some(self.get().await?));
+        let res = pending
+            .conn()
+            .execute("SELECT pg_advisory_lock($1)", &[&key])
+            .await;
+        let conn = pending.complete();
+        res?;
+        Ok(AdvisoryLockGuard {
+            conn: Arc::new(conn),
+            key,
+            held: true,
+        })
+    }
+}
+
+impl DatabaseTransaction<'_> {
+    /// Take the transaction-level advisory lock `key` with `pg_advisory_xact_lock`, waiting
+    /// until it is available. The lock is released when the transaction commits or rolls back.
+    pub async fn advisory_xact_lock(&self, key: i64) -> Result<(), DbErr> {
+        self.execute("SELECT pg_advisory_xact_lock($1)", &[&key])
+            .await?;
+        Ok(())
+    }
+}
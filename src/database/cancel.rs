@@ -0,0 +1,83 @@
+use crate::{DatabaseConnection, DatabasePool, DbErr};
+use futures::future::BoxFuture;
+use std::{future::Future, pin::pin};
+use tokio_postgres::NoTls;
+pub use tokio_util::sync::CancellationToken;
+
+impl DatabaseConnection {
+    /// Drive `fut`, queries running on this connection, until it finishes or `token` is
+    /// cancelled. On cancellation `fut` is dropped, so none of its later statements run, the
+    /// statement in flight is cancelled server-side through a separate connection, and
+    /// [`DbErr::Cancelled`] is returned. Also tells whether `token` stopped `fut`, whatever
+    /// the cancel request returned.
+    async fn drive_cancellable<F, T>(
+        &self,
+        token: &CancellationToken,
+        fut: F,
+    ) -> (bool, Result<T, DbErr>)
+    where
+        F: Future<Output = Result<T, DbErr>>,
+    {
+        {
+            let fut = pin!(fut);
+            tokio::select! {
+                res = fut => return (false, res),
+                _ = token.cancelled() => {}
+            }
+        }
+
+        let res = match self.0.cancel_token().cancel_query(NoTls).await {
+            Ok(()) => Err(DbErr::Cancelled),
+            Err(err) => Err(DbErr::Postgres(err)),
+        };
+        (true, res)
+    }
+}
+
+impl DatabasePool {
+    /// Run the queries of `callback` on a connection checked out of the pool, stopping it and
+    /// cancelling its statement server-side once `token` is cancelled. The callback is dropped
+    /// on cancellation, so none of its later statements run, and [`DbErr::Cancelled`] is
+    /// returned.
+    ///
+    /// The cancel request races the connection: it may reach the server after the dropped
+    /// statement has finished and cancel the next one instead. The connection of a cancelled
+    /// callback is therefore closed instead of being returned to the pool.
+    ///
+    /// Cancel the token when the request that issued the query is aborted, or from a timer to
+    /// bound how long the query may run.
+    ///
+    /// ```no_run
+    /// # use pgorm::{CancellationToken, ConnectionTrait, DatabasePool, DbErr};
+    /// # use std::time::Duration;
+    /// # async fn f(db: &DatabasePool) -> Result<(), DbErr> {
+    /// let token = CancellationToken::new();
+    /// let timeout = token.clone();
+    /// tokio::spawn(async move {
+    ///     tokio::time::sleep(Duration::from_secs(5)).await;
+    ///     timeout.cancel();
+    /// });
+    ///
+    /// db.run_cancellable(&token, |conn| {
+    ///     Box::pin(async move { conn.execute("SELECT pg_sleep(10)", &[]).await })
+    /// })
+    /// .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    pub async fn run_cancellable<F, T>(
+        &self,
+        token: &CancellationToken,
+        callback: F,
+    ) -> Result<T, DbErr>
+    where
+        F: for<'c> FnOnce(&'c DatabaseConnection) -> BoxFuture<'c, Result<T, DbErr>>,
+    {
+        let conn = self.get().await?;
+        let (cancelled, res) = conn.drive_cancellable(token, callback(&conn)).await;
+        if cancelled {
+            conn.detach();
+        }
+        res
+    }
+}
@@ -1,13 +1,16 @@
-use crate::DbErr;
+use crate::{DbErr, ValueHolder, prepare_statement};
+use futures::future::BoxFuture;
+use pgorm_query::Values;
 use tokio_postgres::{
-    Row, ToStatement,
-    types::{BorrowToSql, ToSql},
+    Client, Row, Statement, ToStatement,
+    types::{BorrowToSql, ToSql, Type},
 };
 
 use super::DatabaseTransaction;
 
 /// The generic API for a database connection that can perform query or execute statements.
-/// It abstracts database connection and transaction
+/// It abstracts database pool, connection and transaction, so code that takes a
+/// `&impl ConnectionTrait` can run against any of them.
 #[async_trait::async_trait]
 pub trait ConnectionTrait: Sync {
     /// Execute a [Statement]
@@ -47,6 +50,81 @@ pub trait ConnectionTrait: Sync {
     where
         T: ?Sized + ToStatement + Send + Sync;
 
+    /// Run a statement with the type of each parameter given up front, instead of
+    /// letting the server infer them. See [`typed_values`](crate::typed_values).
+    async fn query_typed(
+        &self,
+        statement: &str,
+        params: &[(&(dyn ToSql + Sync), Type)],
+    ) -> Result<Vec<Row>, DbErr>;
+
+    /// Prepare a statement, giving the type of some of its parameters up front.
+    /// Parameters typed [`Type::UNKNOWN`], or past the end of `types`, are left for
+    /// the server to infer.
+    async fn prepare_typed(&self, statement: &str, types: &[Type]) -> Result<Statement, DbErr>;
+
+    /// Run `callback` with the underlying [`Client`], for features the ORM does not wrap,
+    /// such as `COPY` or `simple_query`. A pool checks out a connection for the duration of
+    /// the callback and returns it afterwards; in a transaction, the client runs inside it.
+    ///
+    /// ```no_run
+    /// # use pgorm::{ConnectionTrait, DatabasePool, DbErr};
+    /// # async fn f(db: &DatabasePool) -> Result<(), DbErr> {
+    /// let messages = db
+    ///     .with_client(|client| {
+    ///         Box::pin(async move { Ok(client.simple_query("SELECT 1").await?) })
+    ///     })
+    ///     .await?;
+    /// # Ok(())
+    /// # }
+    /// ```
+    async fn with_client<F, T>(&self, callback: F) -> Result<T, DbErr>
+    where
+        F: for<'c> FnOnce(&'c Client) -> BoxFuture<'c, Result<T, DbErr>> + Send,
+        T: Send;
+
+    /// Prepare a statement with the parameter types of `values`, then execute it
+    async fn execute_values(&self, statement: &str, values: Values) -> Result<u64, DbErr>
+    where
+        Self: Sized,
+    {
+        let stmt = prepare_statement(self, statement, &values).await?;
+        let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
+        let values = values
+            .iter()
+            .map(|x| x as _)
+            .collect::<Vec<&(dyn ToSql + Sync)>>();
+        self.execute(&stmt, &values).await
+    }
+
+    /// Prepare a statement with the parameter types of `values`, then query at most one row
+    async fn query_opt_values(&self, statement: &str, values: Values) -> Result<Option<Row>, DbErr>
+    where
+        Self: Sized,
+    {
+        let stmt = prepare_statement(self, statement, &values).await?;
+        let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
+        let values = values
+            .iter()
+            .map(|x| x as _)
+            .collect::<Vec<&(dyn ToSql + Sync)>>();
+        self.query_opt(&stmt, &values).await
+    }
+
+    /// Prepare a statement with the parameter types of `values`, then query all rows
+    async fn query_all_values(&self, statement: &str, values: Values) -> Result<Vec<Row>, DbErr>
+    where
+        Self: Sized,
+    {
+        let stmt = prepare_statement(self, statement, &values).await?;
+        let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
+        let values = values
+            .iter()
+            .map(|x| x as _)
+            .collect::<Vec<&(dyn ToSql + Sync)>>();
+        self.query_all(&stmt, &values).await
+    }
+
     // async fn query_raw<T, P, I>(&self, statement: &T, params: I) -> Result<RowStream, DbErr>
     // where
     //     T: ?Sized + ToStatement,
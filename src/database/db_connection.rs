@@ -1,18 +1,20 @@
-use std::{collections::BTreeMap, sync::Arc};
+use std::{collections::BTreeMap, sync::Arc, time::Instant};
 
+use super::hooks::{ConnectionHooks, ReleaseGuard};
 use crate::{ConnectionTrait, TransactionTrait, error::*};
 use deadpool::Status;
-use pgorm_pool::{Object, Pool, Transaction};
+use futures::future::BoxFuture;
+use pgorm_pool::{Pool, PoolError, Transaction};
+use pgorm_query::Values;
 use tokio_postgres::{
-    ToStatement,
-    types::{BorrowToSql, ToSql},
+    Client, ToStatement,
+    types::{BorrowToSql, ToSql, Type},
 };
 
 /// Handle a database connection depending on the backend enabled by the feature
 /// flags. This creates a database pool.
 #[derive(Debug, Clone)]
-#[repr(transparent)]
-pub struct DatabasePool(pub(crate) Pool);
+pub struct DatabasePool(pub(crate) Pool, pub(crate) ConnectionHooks);
 
 #[derive(Debug, Clone)]
 #[repr(transparent)]
@@ -40,9 +42,18 @@ impl DatabaseMultiPool {
 // }
 
 impl DatabasePool {
+    pub(crate) fn new(pool: Pool) -> Self {
+        DatabasePool(pool, ConnectionHooks::default())
+    }
+
     pub async fn get(&self) -> Result<DatabaseConnection, DbErr> {
-        let conn = Pool::get(&self.0).await?;
-        Ok(DatabaseConnection(conn))
+        let started = Instant::now();
+        let conn = Pool::get(&self.0).await.map_err(|e| match e {
+            PoolError::Timeout(_) => DbErr::ConnectionAcquire(ConnAcquireErr::Timeout),
+            e => e.into(),
+        })?;
+        let release = self.1.acquired(self.tag(), started.elapsed());
+        Ok(DatabaseConnection(conn, release))
     }
 
     pub fn tag(&self) -> Arc<String> {
@@ -55,7 +66,11 @@ impl DatabasePool {
 }
 
 #[derive(Debug)]
-pub struct DatabaseConnection(pub(crate) Object);
+pub struct DatabaseConnection(
+    pub(crate) pgorm_pool::Client,
+    // Only held to run the release hooks on drop
+    #[allow(dead_code)] Option<ReleaseGuard>,
+);
 
 impl DatabaseConnection {
     async fn begin_with_config(
@@ -75,6 +90,11 @@ impl DatabaseConnection {
 
         Ok(DatabaseTransaction(Some(t.start().await?)))
     }
+
+    /// Take the connection out of its pool and close it, for one left in an unknown state
+    pub(crate) fn detach(self) {
+        drop(pgorm_pool::Client::take(self.0));
+    }
 }
 
 #[derive(Debug)]
@@ -88,6 +108,24 @@ impl DatabaseTransaction<'_> {
             unreachable!()
         }
     }
+
+    /// Roll the transaction back explicitly instead of relying on it being dropped
+    pub async fn rollback(mut self) -> Result<(), DbErr> {
+        if let Some(tx) = self.0.take() {
+            tx.rollback().await.map_err(DbErr::Postgres)
+        } else {
+            unreachable!()
+        }
+    }
+}
+
+impl<'a> DatabaseTransaction<'a> {
+    /// The open transaction, only taken by `commit` and `rollback` as they consume `self`
+    fn transaction(&self) -> Result<&Transaction<'a>, DbErr> {
+        self.0
+            .as_ref()
+            .ok_or_else(|| conn_err("Transaction was already committed or rolled back"))
+    }
 }
 
 impl Drop for DatabaseTransaction<'_> {
@@ -98,6 +136,110 @@ impl Drop for DatabaseTransaction<'_> {
     }
 }
 
+/// Each call checks out a connection from the pool for its own duration. A [`Statement`]
+/// from [`prepare_typed`](ConnectionTrait::prepare_typed) belongs to the connection that
+/// prepared it, so to reuse one, check out a connection with [`DatabasePool::get`].
+///
+/// [`Statement`]: tokio_postgres::Statement
+#[async_trait::async_trait]
+impl ConnectionTrait for DatabasePool {
+    async fn execute<T>(&self, statement: &T, params: &[&(dyn ToSql + Sync)]) -> Result<u64, DbErr>
+    where
+        T: ?Sized + ToStatement + Send + Sync,
+    {
+        self.get().await?.execute(statement, params).await
+    }
+
+    async fn execute_raw<T, P, I>(&self, statement: &T, params: I) -> Result<u64, DbErr>
+    where
+        T: ?Sized + ToStatement + Send + Sync,
+        P: BorrowToSql,
+        I: IntoIterator<Item = P> + Send,
+        I::IntoIter: ExactSizeIterator,
+    {
+        self.get().await?.execute_raw(statement, params).await
+    }
+
+    async fn query_one<T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<tokio_postgres::Row, DbErr>
+    where
+        T: ?Sized + ToStatement + Send + Sync,
+    {
+        self.get().await?.query_one(statement, params).await
+    }
+
+    async fn query_opt<T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Option<tokio_postgres::Row>, DbErr>
+    where
+        T: ?Sized + ToStatement + Send + Sync,
+    {
+        self.get().await?.query_opt(statement, params).await
+    }
+
+    async fn query_all<T>(
+        &self,
+        statement: &T,
+        params: &[&(dyn ToSql + Sync)],
+    ) -> Result<Vec<tokio_postgres::Row>, DbErr>
+    where
+        T: ?Sized + ToStatement + Send + Sync,
+    {
+        self.get().await?.query_all(statement, params).await
+    }
+
+    async fn query_typed(
+        &self,
+        statement: &str,
+        params: &[(&(dyn ToSql + Sync), Type)],
+    ) -> Result<Vec<tokio_postgres::Row>, DbErr> {
+        self.get().await?.query_typed(statement, params).await
+    }
+
+    async fn prepare_typed(
+        &self,
+        statement: &str,
+        types: &[Type],
+    ) -> Result<tokio_postgres::Statement, DbErr> {
+        self.get().await?.prepare_typed(statement, types).await
+    }
+
+    async fn with_client<F, T>(&self, callback: F) -> Result<T, DbErr>
+    where
+        F: for<'c> FnOnce(&'c Client) -> BoxFuture<'c, Result<T, DbErr>> + Send,
+        T: Send,
+    {
+        let conn = self.get().await?;
+        conn.with_client(callback).await
+    }
+
+    // Prepare and run on the same connection
+    async fn execute_values(&self, statement: &str, values: Values) -> Result<u64, DbErr> {
+        self.get().await?.execute_values(statement, values).await
+    }
+
+    async fn query_opt_values(
+        &self,
+        statement: &str,
+        values: Values,
+    ) -> Result<Option<tokio_postgres::Row>, DbErr> {
+        self.get().await?.query_opt_values(statement, values).await
+    }
+
+    async fn query_all_values(
+        &self,
+        statement: &str,
+        values: Values,
+    ) -> Result<Vec<tokio_postgres::Row>, DbErr> {
+        self.get().await?.query_all_values(statement, values).await
+    }
+}
+
 #[async_trait::async_trait]
 impl ConnectionTrait for &DatabaseConnection {
     // #[instrument(level = "trace")]
@@ -151,6 +293,30 @@ impl ConnectionTrait for &DatabaseConnection {
     {
         Ok(self.0.query(statement, params).await?)
     }
+
+    async fn query_typed(
+        &self,
+        statement: &str,
+        params: &[(&(dyn ToSql + Sync), Type)],
+    ) -> Result<Vec<tokio_postgres::Row>, DbErr> {
+        Ok(self.0.query_typed(statement, params).await?)
+    }
+
+    async fn prepare_typed(
+        &self,
+        statement: &str,
+        types: &[Type],
+    ) -> Result<tokio_postgres::Statement, DbErr> {
+        Ok(self.0.prepare_typed(statement, types).await?)
+    }
+
+    async fn with_client<F, T>(&self, callback: F) -> Result<T, DbErr>
+    where
+        F: for<'c> FnOnce(&'c Client) -> BoxFuture<'c, Result<T, DbErr>> + Send,
+        T: Send,
+    {
+        callback(&self.0).await
+    }
 }
 
 #[async_trait::async_trait]
@@ -207,6 +373,30 @@ impl ConnectionTrait for DatabaseConnection {
         Ok(self.0.query(statement, params).await?)
     }
 
+    async fn query_typed(
+        &self,
+        statement: &str,
+        params: &[(&(dyn ToSql + Sync), Type)],
+    ) -> Result<Vec<tokio_postgres::Row>, DbErr> {
+        Ok(self.0.query_typed(statement, params).await?)
+    }
+
+    async fn prepare_typed(
+        &self,
+        statement: &str,
+        types: &[Type],
+    ) -> Result<tokio_postgres::Statement, DbErr> {
+        Ok(self.0.prepare_typed(statement, types).await?)
+    }
+
+    async fn with_client<F, T>(&self, callback: F) -> Result<T, DbErr>
+    where
+        F: for<'c> FnOnce(&'c Client) -> BoxFuture<'c, Result<T, DbErr>> + Send,
+        T: Send,
+    {
+        callback(&self.0).await
+    }
+
     // async fn query_raw<T, P, I>(&self, statement: &T, params: I) -> Result<RowStream, DbErr>
     // where
     //     T: ?Sized + ToStatement,
@@ -287,6 +477,30 @@ impl ConnectionTrait for DatabaseTransaction<'_> {
         Ok(self.0.as_ref().unwrap().query(statement, params).await?)
     }
 
+    async fn query_typed(
+        &self,
+        statement: &str,
+        params: &[(&(dyn ToSql + Sync), Type)],
+    ) -> Result<Vec<tokio_postgres::Row>, DbErr> {
+        Ok(self.transaction()?.query_typed(statement, params).await?)
+    }
+
+    async fn prepare_typed(
+        &self,
+        statement: &str,
+        types: &[Type],
+    ) -> Result<tokio_postgres::Statement, DbErr> {
+        Ok(self.transaction()?.prepare_typed(statement, types).await?)
+    }
+
+    async fn with_client<F, T>(&self, callback: F) -> Result<T, DbErr>
+    where
+        F: for<'c> FnOnce(&'c Client) -> BoxFuture<'c, Result<T, DbErr>> + Send,
+        T: Send,
+    {
+        callback(self.transaction()?.client()).await
+    }
+
     // async fn query_raw<T, P, I>(&self, statement: &T, params: I) -> Result<RowStream, DbErr>
     // where
     //     T: ?Sized + ToStatement,
@@ -0,0 +1,166 @@
+use crate::{ConnectionTrait, DatabasePool, error::*};
+use std::collections::BTreeMap;
+
+/// A table found by [`DatabasePool::discover_tables`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct TableInfo {
+    /// Name of the table
+    pub name: String,
+    /// Columns, in their declared order
+    pub columns: Vec<ColumnInfo>,
+    /// Constraints on the table, ordered by name
+    pub constraints: Vec<ConstraintInfo>,
+}
+
+/// A column of a [`TableInfo`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ColumnInfo {
+    /// Name of the column
+    pub name: String,
+    /// SQL type of the column as Postgres prints it, e.g. `character varying(255)`
+    pub data_type: String,
+    /// Whether the column accepts `NULL`
+    pub nullable: bool,
+    /// The default expression, if any
+    pub default: Option<String>,
+}
+
+/// A constraint of a [`TableInfo`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConstraintInfo {
+    /// Name of the constraint
+    pub name: String,
+    /// Kind of the constraint
+    pub kind: ConstraintKind,
+    /// Constrained columns, in key order
+    pub columns: Vec<String>,
+}
+
+/// Kind of a [`ConstraintInfo`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConstraintKind {
+    /// `PRIMARY KEY`
+    PrimaryKey,
+    /// `UNIQUE`
+    Unique,
+    /// `FOREIGN KEY`
+    ForeignKey,
+    /// `CHECK`
+    Check,
+    /// `EXCLUDE`
+    Exclusion,
+}
+
+impl TableInfo {
+    /// Look up a column by name
+    pub fn column(&self, name: &str) -> Option<&ColumnInfo> {
+        self.columns.iter().find(|column| column.name == name)
+    }
+
+    /// Columns of the primary key, empty if the table has none
+    pub fn primary_key(&self) -> &[String] {
+        self.constraints
+            .iter()
+            .find(|constraint| constraint.kind == ConstraintKind::PrimaryKey)
+            .map(|constraint| constraint.columns.as_slice())
+            .unwrap_or_default()
+    }
+}
+
+impl ConstraintKind {
+    fn from_contype(contype: &str) -> Option<Self> {
+        match contype {
+            "p" => Some(Self::PrimaryKey),
+            "u" => Some(Self::Unique),
+            "f" => Some(Self::ForeignKey),
+            "c" => Some(Self::Check),
+            "x" => Some(Self::Exclusion),
+            _ => None,
+        }
+    }
+}
+
+const TABLES_SQL: &str = r#"
+SELECT c.relname::text
+FROM pg_catalog.pg_class c
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE n.nspname = current_schema() AND c.relkind IN ('r', 'p')
+"#;
+
+const COLUMNS_SQL: &str = r#"
+SELECT c.relname::text, a.attname::text, pg_catalog.format_type(a.atttypid, a.atttypmod),
+    NOT a.attnotnull, pg_catalog.pg_get_expr(d.adbin, d.adrelid)
+FROM pg_catalog.pg_class c
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+JOIN pg_catalog.pg_attribute a ON a.attrelid = c.oid AND a.attnum > 0 AND NOT a.attisdropped
+LEFT JOIN pg_catalog.pg_attrdef d ON d.adrelid = c.oid AND d.adnum = a.attnum
+WHERE n.nspname = current_schema() AND c.relkind IN ('r', 'p')
+ORDER BY c.relname, a.attnum
+"#;
+
+const CONSTRAINTS_SQL: &str = r#"
+SELECT c.relname::text, con.conname::text, con.contype::text,
+    ARRAY(
+        SELECT a.attname::text
+        FROM unnest(con.conkey) WITH ORDINALITY AS k(attnum, ord)
+        JOIN pg_catalog.pg_attribute a ON a.attrelid = con.conrelid AND a.attnum = k.attnum
+        ORDER BY k.ord
+    )
+FROM pg_catalog.pg_constraint con
+JOIN pg_catalog.pg_class c ON c.oid = con.conrelid
+JOIN pg_catalog.pg_namespace n ON n.oid = c.relnamespace
+WHERE n.nspname = current_schema() AND c.relkind IN ('r', 'p')
+ORDER BY c.relname, con.conname
+"#;
+
+impl DatabasePool {
+    /// Read the tables of the current schema from the system catalogs, along with
+    /// their columns and constraints. Tables are ordered by name.
+    pub async fn discover_tables(&self) -> Result<Vec<TableInfo>, DbErr> {
+        let conn = self.get().await?;
+
+        let mut tables: BTreeMap<String, TableInfo> = conn
+            .query_all(TABLES_SQL, &[])
+            .await?
+            .into_iter()
+            .map(|row| {
+                let name: String = row.get(0);
+                let table = TableInfo {
+                    name: name.clone(),
+                    columns: Vec::new(),
+                    constraints: Vec::new(),
+                };
+                (name, table)
+            })
+            .collect();
+
+        for row in conn.query_all(COLUMNS_SQL, &[]).await? {
+            let table: String = row.get(0);
+            if let Some(table) = tables.get_mut(&table) {
+                table.columns.push(ColumnInfo {
+                    name: row.get(1),
+                    data_type: row.get(2),
+                    nullable: row.get(3),
+                    default: row.get(4),
+                });
+            }
+        }
+
+        for row in conn.query_all(CONSTRAINTS_SQL, &[]).await? {
+            let table: String = row.get(0);
+            let contype: String = row.get(2);
+            let Some(kind) = ConstraintKind::from_contype(&contype) else {
+                continue;
+            };
+            if let Some(table) = tables.get_mut(&table) {
+                table.constraints.push(ConstraintInfo {
+                    name: row.get(1),
+                    kind,
+                    columns: row.get(3),
+                });
+            }
+        }
+
+        Ok(tables.into_values().collect())
+    }
+}
@@ -0,0 +1,119 @@
+use crate::DatabasePool;
+use std::{
+    fmt,
+    sync::Arc,
+    time::{Duration, Instant},
+};
+
+/// A connection checked out of, or returned to, a [`DatabasePool`]
+#[derive(Debug, Clone)]
+pub struct ConnectionEvent {
+    /// The tag of the pool, see [`DatabasePool::tag`]
+    pub tag: Arc<String>,
+    /// On acquire, how long [`DatabasePool::get`] waited for the connection. On release, how
+    /// long the connection was checked out.
+    pub elapsed: Duration,
+}
+
+type Hook = Arc<dyn Fn(&ConnectionEvent) + Send + Sync>;
+
+#[derive(Clone, Default)]
+pub(crate) struct ConnectionHooks {
+    on_acquire: Vec<Hook>,
+    on_release: Vec<Hook>,
+}
+
+impl fmt::Debug for ConnectionHooks {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ConnectionHooks")
+            .field("on_acquire", &self.on_acquire.len())
+            .field("on_release", &self.on_release.len())
+            .finish()
+    }
+}
+
+impl ConnectionHooks {
+    pub(crate) fn acquired(&self, tag: Arc<String>, waited: Duration) -> Option<ReleaseGuard> {
+        let event = ConnectionEvent {
+            tag,
+            elapsed: waited,
+        };
+        for hook in &self.on_acquire {
+            hook(&event);
+        }
+
+        (!self.on_release.is_empty()).then(|| ReleaseGuard {
+            tag: event.tag,
+            acquired_at: Instant::now(),
+            hooks: self.on_release.clone(),
+        })
+    }
+}
+
+/// Runs the release hooks once the connection holding it is returned to the pool
+pub(crate) struct ReleaseGuard {
+    tag: Arc<String>,
+    acquired_at: Instant,
+    hooks: Vec<Hook>,
+}
+
+impl fmt::Debug for ReleaseGuard {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("ReleaseGuard")
+            .field("tag", &self.tag)
+            .field("acquired_at", &self.acquired_at)
+            .finish()
+    }
+}
+
+impl Drop for ReleaseGuard {
+    fn drop(&mut self) {
+        let event = ConnectionEvent {
+            tag: self.tag.clone(),
+            elapsed: self.acquired_at.elapsed(),
+        };
+        for hook in &self.hooks {
+            hook(&event);
+        }
+    }
+}
+
+impl DatabasePool {
+    /// Register `hook` to be called each time a connection is checked out of the pool, with
+    /// how long the checkout waited for a free connection. Queries run directly on the pool
+    /// check out a connection each.
+    ///
+    /// Hooks belong to this handle: clones made before registering do not call them.
+    ///
+    /// ```no_run
+    /// use std::sync::{
+    ///     Arc,
+    ///     atomic::{AtomicUsize, Ordering},
+    /// };
+    ///
+    /// let checkouts = Arc::new(AtomicUsize::new(0));
+    /// let counter = checkouts.clone();
+    /// let db = pgorm::connect("host=localhost user=postgres".parse().unwrap())
+    ///     .on_acquire(move |event| {
+    ///         counter.fetch_add(1, Ordering::Relaxed);
+    ///         tracing::debug!(tag = %event.tag, waited = ?event.elapsed, "connection acquired");
+    ///     });
+    /// ```
+    pub fn on_acquire<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&ConnectionEvent) + Send + Sync + 'static,
+    {
+        self.1.on_acquire.push(Arc::new(hook));
+        self
+    }
+
+    /// Register `hook` to be called each time a connection is returned to the pool, with how
+    /// long it was checked out, see [`DatabasePool::on_acquire`].
+    pub fn on_release<F>(mut self, hook: F) -> Self
+    where
+        F: Fn(&ConnectionEvent) + Send + Sync + 'static,
+    {
+        self.1.on_release.push(Arc::new(hook));
+        self
+    }
+}
@@ -1,15 +1,24 @@
+mod advisory_lock;
+mod cancel;
 mod connection;
 mod db_connection;
+mod discovery;
+mod hooks;
 // mod statement;
 // mod stream;
-// mod transaction;
+mod transaction;
 
+pub use advisory_lock::*;
+pub use cancel::*;
 pub use connection::*;
 pub use db_connection::*;
+pub use discovery::*;
 use futures::FutureExt as _;
+pub use hooks::*;
 pub use tokio_postgres::Config;
 
-use pgorm_pool::{ClientWrapper, Manager, ManagerConfig, Pool, PoolBuilder, RecyclingMethod};
+use pgorm_pool::{ClientWrapper, Manager, Pool, PoolBuilder, Runtime};
+pub use pgorm_pool::{ManagerConfig, RecyclingMethod};
 use std::{
     collections::BTreeMap,
     ops::{Deref, DerefMut},
@@ -18,31 +27,74 @@ use std::{
 // pub use statement::*;
 // pub use stream::*;
 use tokio_postgres::NoTls;
-// pub use transaction::*;
+pub use transaction::*;
 
 /// Method to create a [DatabasePool] on a database
 pub fn connect(config: Config) -> DatabasePool {
     let mgr_config = ManagerConfig {
         recycling_method: RecyclingMethod::Fast,
-        tag: None,
+        ..Default::default()
     };
     let mgr = Manager::from_config(config, NoTls, mgr_config);
-    let pool = Pool::builder(mgr).build().unwrap();
+    let pool = Pool::builder(mgr).runtime(Runtime::Tokio1).build().unwrap();
 
-    DatabasePool(pool)
+    DatabasePool::new(pool)
 }
 
+/// Method to create a [DatabasePool] on a database, customising the pool with `build`
+///
+/// Set [`PoolBuilder::wait_timeout`] to bound how long [`DatabasePool::get`] waits for a
+/// free connection once the pool is exhausted; acquisition then fails with
+/// [`ConnAcquireErr::Timeout`](crate::ConnAcquireErr::Timeout) instead of blocking.
+///
+/// ```no_run
+/// use std::time::Duration;
+///
+/// let db = pgorm::connect_with_builder(
+///     "host=localhost user=postgres".parse().unwrap(),
+///     |builder| builder.max_size(4).wait_timeout(Some(Duration::from_secs(2))),
+/// );
+/// ```
 pub fn connect_with_builder(
     config: Config,
     build: impl Fn(PoolBuilder) -> PoolBuilder,
 ) -> DatabasePool {
     let mgr_config = ManagerConfig {
         recycling_method: RecyclingMethod::Fast,
-        tag: None,
+        ..Default::default()
     };
+    connect_with_config(config, mgr_config, build)
+}
+
+/// Method to create a [DatabasePool] on a database with a custom [ManagerConfig]
+///
+/// Use [`ManagerConfig::max_lifetime`] to have connections replaced instead of
+/// being reused past the given age, and [`ManagerConfig::idle_timeout`] to have those
+/// left unused for too long replaced.
+///
+/// ```no_run
+/// use pgorm::{ManagerConfig, RecyclingMethod};
+/// use std::time::Duration;
+///
+/// let db = pgorm::connect_with_config(
+///     "host=localhost user=postgres".parse().unwrap(),
+///     ManagerConfig {
+///         recycling_method: RecyclingMethod::Fast,
+///         max_lifetime: Some(Duration::from_secs(30 * 60)),
+///         idle_timeout: Some(Duration::from_secs(10 * 60)),
+///         ..Default::default()
+///     },
+///     |builder| builder,
+/// );
+/// ```
+pub fn connect_with_config(
+    config: Config,
+    mgr_config: ManagerConfig,
+    build: impl Fn(PoolBuilder) -> PoolBuilder,
+) -> DatabasePool {
     let mgr = Manager::from_config(config, NoTls, mgr_config);
-    let builder = build(Pool::builder(mgr));
-    builder.build().map(DatabasePool).unwrap()
+    let builder = build(Pool::builder(mgr).runtime(Runtime::Tokio1));
+    builder.build().map(DatabasePool::new).unwrap()
 }
 
 pub fn connect_multi_with_builder(
@@ -55,11 +107,12 @@ pub fn connect_multi_with_builder(
             let mgr_config = ManagerConfig {
                 recycling_method: RecyclingMethod::Fast,
                 tag: Some(key),
+                ..Default::default()
             };
 
             let mgr = Manager::from_config(config.clone(), NoTls, mgr_config);
-            let builder = build(Pool::builder(mgr));
-            let pool = builder.build().map(DatabasePool).unwrap();
+            let builder = build(Pool::builder(mgr).runtime(Runtime::Tokio1));
+            let pool = builder.build().map(DatabasePool::new).unwrap();
 
             (pool.tag(), pool)
         })
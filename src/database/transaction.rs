@@ -0,0 +1,56 @@
+use crate::{DatabasePool, DatabaseTransaction, TransactionError, TransactionTrait};
+use futures::future::BoxFuture;
+
+/// A boxed transaction callback, as accepted by [`DatabasePool::transaction_boxed`]
+pub type TransactionCallback<'f, T, E> = Box<
+    dyn for<'a, 'c> FnOnce(&'a DatabaseTransaction<'c>) -> BoxFuture<'a, Result<T, E>> + Send + 'f,
+>;
+
+impl DatabasePool {
+    /// Execute the function inside a transaction on a connection checked out of the pool.
+    /// If the function returns an error, the transaction will be rolled back. If it does not
+    /// return an error, the transaction will be committed.
+    ///
+    /// ```no_run
+    /// # use pgorm::{ConnectionTrait, DatabasePool, DbErr, TransactionError};
+    /// # async fn f(db: &DatabasePool) -> Result<(), TransactionError<DbErr>> {
+    /// db.transaction(|txn| {
+    ///     Box::pin(async move {
+    ///         txn.execute("UPDATE account SET balance = balance - 10 WHERE id = 1", &[])
+    ///             .await?;
+    ///         txn.execute("UPDATE account SET balance = balance + 10 WHERE id = 2", &[])
+    ///             .await?;
+    ///         Ok(())
+    ///     })
+    /// })
+    /// .await
+    /// # }
+    /// ```
+    pub async fn transaction<F, T, E>(&self, callback: F) -> Result<T, TransactionError<E>>
+    where
+        F: for<'a, 'c> FnOnce(&'a DatabaseTransaction<'c>) -> BoxFuture<'a, Result<T, E>> + Send,
+    {
+        self.transaction_boxed(Box::new(callback)).await
+    }
+
+    /// Same as [`DatabasePool::transaction`], taking the callback as a trait object so that
+    /// callbacks can be stored or chosen at runtime.
+    pub async fn transaction_boxed<T, E>(
+        &self,
+        callback: TransactionCallback<'_, T, E>,
+    ) -> Result<T, TransactionError<E>> {
+        let mut conn = self.get().await.map_err(TransactionError::Connection)?;
+        let txn = conn.begin().await.map_err(TransactionError::Connection)?;
+
+        match callback(&txn).await {
+            Ok(value) => {
+                txn.commit().await.map_err(TransactionError::Connection)?;
+                Ok(value)
+            }
+            Err(err) => {
+                txn.rollback().await.map_err(TransactionError::Connection)?;
+                Err(TransactionError::Transaction(err))
+            }
+        }
+    }
+}
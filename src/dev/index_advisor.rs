@@ -0,0 +1,246 @@
+use crate::{ConnectionTrait, DbErr, EntityTrait, QueryTrait, Select};
+use serde_json::Value as Json;
+
+/// Tables estimated to hold fewer rows than this are cheaper to scan sequentially than through
+/// an index, so their scans are not reported by [`Select::suggest_indexes`]
+pub const SUGGEST_INDEXES_MIN_ROWS: f64 = 1000.0;
+
+/// A column to index, found by [`Select::suggest_indexes`]
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct IndexSuggestion {
+    /// The table that is scanned sequentially
+    pub table: String,
+    /// The column the scan filters or joins on
+    pub column: String,
+}
+
+/// A sequential scan in the plan, with the conditions on its rows
+#[derive(Debug)]
+struct SeqScan {
+    schema: String,
+    table: String,
+    alias: String,
+    refs: Vec<(Option<String>, String)>,
+}
+
+impl<E> Select<E>
+where
+    E: EntityTrait,
+{
+    /// Development helper that `EXPLAIN`s the query and suggests the columns to index so that
+    /// it doesn't scan large tables sequentially.
+    ///
+    /// A column is suggested when a sequential scan of a table with at least
+    /// [`SUGGEST_INDEXES_MIN_ROWS`] rows filters on it, or joins on it, and no index of the
+    /// table starts with it. Table sizes are the planner's estimates from the last `ANALYZE`.
+    pub async fn suggest_indexes<C>(&self, db: &C) -> Result<Vec<IndexSuggestion>, DbErr>
+    where
+        C: ConnectionTrait,
+    {
+        let (sql, values) = self.build();
+        let rows = db
+            .query_all_values(&format!("EXPLAIN (VERBOSE, FORMAT JSON) {sql}"), values)
+            .await?;
+        let plan: Json = match rows.first() {
+            Some(row) => row.try_get(0)?,
+            None => return Ok(Vec::new()),
+        };
+
+        let mut scans = Vec::new();
+        let mut join_refs = Vec::new();
+        if let Some(plan) = plan.get(0).and_then(|plan| plan.get("Plan")) {
+            collect_plan(plan, &mut scans, &mut join_refs);
+        }
+
+        let mut suggestions = Vec::new();
+        for scan in scans {
+            let rows = db
+                .query_one(
+                    "SELECT reltuples::float8 FROM pg_class \
+                     WHERE oid = format('%I.%I', $1::text, $2::text)::regclass",
+                    &[&scan.schema, &scan.table],
+                )
+                .await?;
+            let estimated_rows: f64 = rows.try_get(0)?;
+            if estimated_rows < SUGGEST_INDEXES_MIN_ROWS {
+                continue;
+            }
+
+            let columns: Vec<String> = db
+                .query_all(
+                    "SELECT attname::text FROM pg_attribute \
+                     WHERE attrelid = format('%I.%I', $1::text, $2::text)::regclass \
+                     AND attnum > 0 AND NOT attisdropped",
+                    &[&scan.schema, &scan.table],
+                )
+                .await?
+                .iter()
+                .map(|row| row.try_get(0))
+                .collect::<Result<_, _>>()?;
+            let indexed: Vec<String> = db
+                .query_all(
+                    "SELECT a.attname::text FROM pg_index i \
+                     JOIN pg_attribute a ON a.attrelid = i.indrelid AND a.attnum = i.indkey[0] \
+                     WHERE i.indrelid = format('%I.%I', $1::text, $2::text)::regclass",
+                    &[&scan.schema, &scan.table],
+                )
+                .await?
+                .iter()
+                .map(|row| row.try_get(0))
+                .collect::<Result<_, _>>()?;
+
+            let refs = scan.refs.iter().chain(join_refs.iter());
+            for (qualifier, column) in refs {
+                // Unqualified columns in a scan's filter belong to the scanned table
+                if qualifier.as_ref().is_some_and(|q| *q != scan.alias)
+                    || !columns.contains(column)
+                    || indexed.contains(column)
+                {
+                    continue;
+                }
+                let suggestion = IndexSuggestion {
+                    table: scan.table.clone(),
+                    column: column.clone(),
+                };
+                if !suggestions.contains(&suggestion) {
+                    suggestions.push(suggestion);
+                }
+            }
+        }
+        Ok(suggestions)
+    }
+}
+
+fn collect_plan(
+    plan: &Json,
+    scans: &mut Vec<SeqScan>,
+    join_refs: &mut Vec<(Option<String>, String)>,
+) {
+    if plan["Node Type"] == "Seq Scan" {
+        if let (Some(schema), Some(table)) =
+            (plan["Schema"].as_str(), plan["Relation Name"].as_str())
+        {
+            scans.push(SeqScan {
+                schema: schema.to_owned(),
+                table: table.to_owned(),
+                alias: plan["Alias"].as_str().unwrap_or(table).to_owned(),
+                refs: plan["Filter"].as_str().map(column_refs).unwrap_or_default(),
+            });
+        }
+    }
+    for cond in ["Hash Cond", "Merge Cond", "Join Filter"] {
+        if let Some(cond) = plan[cond].as_str() {
+            // Join conditions qualify every column, so unqualified names are not columns
+            join_refs.extend(
+                column_refs(cond)
+                    .into_iter()
+                    .filter(|(qualifier, _)| qualifier.is_some()),
+            );
+        }
+    }
+    for plan in plan["Plans"].as_array().into_iter().flatten() {
+        collect_plan(plan, scans, join_refs);
+    }
+}
+
+#[derive(Debug, PartialEq)]
+enum Token {
+    Ident(String),
+    Punct(char),
+    Literal,
+}
+
+/// Split a condition as printed by `EXPLAIN` into identifiers and punctuation, skipping over
+/// string literals
+fn tokenize(expr: &str) -> Vec<Token> {
+    let mut tokens = Vec::new();
+    let mut chars = expr.chars().peekable();
+    while let Some(c) = chars.next() {
+        match c {
+            '\'' => {
+                while let Some(c) = chars.next() {
+                    if c == '\'' && chars.next_if_eq(&'\'').is_none() {
+                        break;
+                    }
+                }
+                tokens.push(Token::Literal);
+            }
+            '"' => {
+                let mut ident = String::new();
+                while let Some(c) = chars.next() {
+                    if c == '"' && chars.next_if_eq(&'"').is_none() {
+                        break;
+                    }
+                    ident.push(c);
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            c if c.is_alphabetic() || c == '_' => {
+                let mut ident = c.to_string();
+                while let Some(c) = chars.next_if(|c| c.is_alphanumeric() || *c == '_' || *c == '$')
+                {
+                    ident.push(c);
+                }
+                tokens.push(Token::Ident(ident));
+            }
+            c if c.is_whitespace() => {}
+            c => tokens.push(Token::Punct(c)),
+        }
+    }
+    tokens
+}
+
+/// The names in a condition that may be columns, as `(qualifier, name)`. Type names of casts
+/// are left out, keywords and function names are not but won't match a column of the table.
+fn column_refs(expr: &str) -> Vec<(Option<String>, String)> {
+    let tokens = tokenize(expr);
+    let mut refs = Vec::new();
+    let mut i = 0;
+    while i < tokens.len() {
+        if let Token::Ident(name) = &tokens[i] {
+            if let (Some(Token::Punct('.')), Some(Token::Ident(column))) =
+                (tokens.get(i + 1), tokens.get(i + 2))
+            {
+                refs.push((Some(name.clone()), column.clone()));
+                i += 3;
+                continue;
+            }
+            let is_cast =
+                i >= 2 && tokens[i - 1] == Token::Punct(':') && tokens[i - 2] == Token::Punct(':');
+            if !is_cast {
+                refs.push((None, name.clone()));
+            }
+        }
+        i += 1;
+    }
+    refs
+}
+
+#[cfg(test)]
+mod tests {
+    use super::column_refs;
+    use pretty_assertions::assert_eq;
+
+    #[test]
+    fn column_refs_of_conditions() {
+        assert_eq!(
+            column_refs(r#"((email = 'it''s x'::text) AND ("Name" ~~ 'a.b%'::text))"#),
+            [
+                (None, "email".to_owned()),
+                (None, "AND".to_owned()),
+                (None, "Name".to_owned()),
+            ]
+        );
+        assert_eq!(
+            column_refs("(book.author_id = author.id)"),
+            [
+                (Some("book".to_owned()), "author_id".to_owned()),
+                (Some("author".to_owned()), "id".to_owned()),
+            ]
+        );
+        assert_eq!(
+            column_refs("(lower((email)::text) = $1)"),
+            [(None, "lower".to_owned()), (None, "email".to_owned())]
+        );
+    }
+}
@@ -0,0 +1,3 @@
+mod index_advisor;
+
+pub use index_advisor::*;
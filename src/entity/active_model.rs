@@ -85,6 +85,10 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// leaving [ActiveValue::NotSet] untouched.
     fn reset(&mut self, c: <Self::Entity as EntityTrait>::Column);
 
+    /// Set the columns marked with `#[pgorm(created_timestamp)]` and `#[pgorm(updated_timestamp)]`
+    /// to the current time. Created timestamps are only touched when `insert` is true.
+    fn set_auto_timestamps(&mut self, _insert: bool) {}
+
     /// Reset all values from [ActiveValue::Unchanged] to [ActiveValue::Set],
     /// leaving [ActiveValue::NotSet] untouched.
     fn reset_all(mut self) -> Self {
@@ -248,13 +252,17 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// # Ok(())
     /// # }
     /// ```
-    async fn insert<'a, C>(self, db: &'a C) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
+    async fn insert<'a, C>(
+        mut self,
+        db: &'a C,
+    ) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
     where
         <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
     {
         // tracing::debug!("Insert ActiveModel: {:?}", self);
+        self.set_auto_timestamps(true);
         let am = ActiveModelBehavior::before_save(self, db, true).await?;
         // tracing::debug!("before save");
         let model = <Self::Entity as EntityTrait>::insert(am)
@@ -373,12 +381,16 @@ pub trait ActiveModelTrait: Clone + Debug {
     /// # Ok(())
     /// # }
     /// ```
-    async fn update<'a, C>(self, db: &'a C) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
+    async fn update<'a, C>(
+        mut self,
+        db: &'a C,
+    ) -> Result<<Self::Entity as EntityTrait>::Model, DbErr>
     where
         <Self::Entity as EntityTrait>::Model: IntoActiveModel<Self>,
         Self: ActiveModelBehavior + 'a,
         C: ConnectionTrait,
     {
+        self.set_auto_timestamps(false);
         let am = ActiveModelBehavior::before_save(self, db, false).await?;
         let model: <Self::Entity as EntityTrait>::Model = Self::Entity::update(am).exec(db).await?;
         Self::after_save(model, db, false).await
@@ -408,7 +420,8 @@ pub trait ActiveModelTrait: Clone + Debug {
         Ok(res.into_active_model())
     }
 
-    /// Delete an active model by its primary key
+    /// Delete an active model by its primary key.
+    /// Returns [`DbErr::RecordNotDeleted`] if the record does not exist.
     ///
     /// # Example
     ///
@@ -574,6 +587,9 @@ pub trait ActiveModelBehavior: ActiveModelTrait {
     }
 
     /// Will be called before `ActiveModel::insert`, `ActiveModel::update`, and `ActiveModel::save`
+    ///
+    /// Columns marked with `#[pgorm(created_timestamp)]` or `#[pgorm(updated_timestamp)]`
+    /// have already been set by the time this is called.
     async fn before_save<C>(self, db: &C, insert: bool) -> Result<Self, DbErr>
     where
         C: ConnectionTrait,
@@ -738,6 +754,54 @@ impl_into_active_value!(crate::prelude::TimeDateTime);
 #[cfg_attr(docsrs, doc(cfg(feature = "with-time")))]
 impl_into_active_value!(crate::prelude::TimeDateTimeWithTimeZone);
 
+/// A timestamp type that can be filled in by `#[pgorm(created_timestamp)]`
+/// and `#[pgorm(updated_timestamp)]` columns
+pub trait AutoTimestamp {
+    /// The current time
+    fn now() -> Self;
+}
+
+impl<T> AutoTimestamp for Option<T>
+where
+    T: AutoTimestamp,
+{
+    fn now() -> Self {
+        Some(T::now())
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
+impl AutoTimestamp for crate::prelude::DateTime {
+    fn now() -> Self {
+        chrono::Utc::now().naive_utc()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
+impl AutoTimestamp for crate::prelude::DateTimeWithTimeZone {
+    fn now() -> Self {
+        chrono::Utc::now().fixed_offset()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
+impl AutoTimestamp for crate::prelude::DateTimeUtc {
+    fn now() -> Self {
+        chrono::Utc::now()
+    }
+}
+
+#[cfg(feature = "with-chrono")]
+#[cfg_attr(docsrs, doc(cfg(feature = "with-chrono")))]
+impl AutoTimestamp for crate::prelude::DateTimeLocal {
+    fn now() -> Self {
+        chrono::Local::now()
+    }
+}
+
 impl<V> Default for ActiveValue<V>
 where
     V: Into<Value>,
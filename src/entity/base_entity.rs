@@ -1,9 +1,10 @@
 use crate::{
     ActiveModelBehavior, ActiveModelTrait, ColumnTrait, Delete, DeleteMany, DeleteOne,
-    FromQueryResult, Insert, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryFilter, Related,
-    RelationBuilder, RelationTrait, RelationType, Select, Update, UpdateMany, UpdateOne,
+    FromQueryResult, Insert, InsertManyLenient, ModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait,
+    QueryFilter, Related, RelationBuilder, RelationTrait, RelationType, Select, Update, UpdateMany,
+    UpdateOne,
 };
-use pgorm_query::{Alias, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef};
+use pgorm_query::{Alias, DynIden, Iden, IntoIden, IntoTableRef, IntoValueTuple, TableRef, Value};
 use std::fmt::Debug;
 pub use strum::IntoEnumIterator as Iterable;
 
@@ -94,6 +95,14 @@ pub trait EntityTrait: EntityName {
         RelationBuilder::from_rel(RelationType::HasMany, R::to().rev(), true)
     }
 
+    /// All columns of the Entity as `(table, column)` references, in declaration order,
+    /// e.g. to build a projection dynamically
+    fn column_refs() -> Vec<(DynIden, DynIden)> {
+        Self::Column::iter()
+            .map(|col| col.as_column_ref())
+            .collect()
+    }
+
     /// Construct select statement to find one / all models
     ///
     /// - To select columns, join tables and group by expressions, see [`QuerySelect`](crate::query::QuerySelect)
@@ -475,6 +484,21 @@ pub trait EntityTrait: EntityName {
         Insert::many(models)
     }
 
+    /// Insert many models, skipping the rows that fail instead of aborting the batch
+    ///
+    /// Each row is inserted by its own statement inside a savepoint, which is rolled back when
+    /// the row fails, e.g. on a unique constraint violation. The inserted rows and the errors of
+    /// the failed ones are collected in a [`LenientInsertResult`](crate::LenientInsertResult).
+    /// Savepoints need a transaction, so the batch is executed on a
+    /// [`DatabaseTransaction`](crate::DatabaseTransaction).
+    fn insert_many_lenient<A, I>(models: I) -> InsertManyLenient<A>
+    where
+        A: ActiveModelTrait<Entity = Self>,
+        I: IntoIterator<Item = A>,
+    {
+        InsertManyLenient::many(models)
+    }
+
     /// Update an model in database
     ///
     /// - To apply where conditions / filters, see [`QueryFilter`](crate::query::QueryFilter)
@@ -652,6 +676,84 @@ pub trait EntityTrait: EntityName {
         Update::many(Self::default())
     }
 
+    /// Update the columns set in `model` on the row with the given primary key,
+    /// without needing the key inside the ActiveModel
+    ///
+    /// # Example
+    ///
+    /// ```
+    /// use pgorm::{ActiveValue, QueryTrait, entity::prelude::*};
+    /// # mod fruit {
+    /// #     use pgorm::entity::prelude::*;
+    /// #
+    /// #     #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    /// #     #[pgorm(table_name = "fruit")]
+    /// #     pub struct Model {
+    /// #         #[pgorm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub name: String,
+    /// #     }
+    /// #
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::update_by_id(
+    ///         3,
+    ///         fruit::ActiveModel {
+    ///             name: ActiveValue::set("Orange".to_owned()),
+    ///             ..Default::default()
+    ///         }
+    ///     )
+    ///     .build()
+    ///     .0,
+    ///     r#"UPDATE "fruit" SET "name" = $1 WHERE "fruit"."id" = $2"#,
+    /// );
+    /// ```
+    ///
+    /// # Panics
+    ///
+    /// Panics if arity of input values don't match arity of primary key
+    fn update_by_id<T, A>(values: T, model: A) -> UpdateMany<Self>
+    where
+        T: Into<<Self::PrimaryKey as PrimaryKeyTrait>::ValueType>,
+        A: ActiveModelTrait<Entity = Self>,
+    {
+        let mut update = Self::update_many().set(model);
+        let mut keys = Self::PrimaryKey::iter();
+        for v in values.into().into_value_tuple() {
+            if let Some(key) = keys.next() {
+                let col = key.into_column();
+                update = update.filter(col.eq(v));
+            } else {
+                panic!("primary key arity mismatch");
+            }
+        }
+        if keys.next().is_some() {
+            panic!("primary key arity mismatch");
+        }
+        update
+    }
+
+    /// Set `col` to a different value on each row, matching rows by `key`, in one
+    /// statement. Each row is given as `(key, value)`; see [`Update::from_values`].
+    /// Without rows the update affects no rows.
+    fn update_from_values<K, V, I>(
+        key: Self::Column,
+        col: Self::Column,
+        rows: I,
+    ) -> UpdateMany<Self>
+    where
+        K: Into<Value>,
+        V: Into<Value>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        Update::from_values(Self::default(), key, col, rows)
+    }
+
     /// Delete an model from database
     ///
     /// - To apply where conditions / filters, see [`QueryFilter`](crate::query::QueryFilter)
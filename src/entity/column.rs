@@ -1,8 +1,12 @@
-use crate::{EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable};
+use crate::{
+    EntityName, Iden, IdenStatic, IntoSimpleExpr, Iterable, column_type_to_pg_type, is_null,
+    null_pg_type,
+};
 use pgorm_query::{
-    Alias, BinOper, DynIden, Expr, IntoIden, SeaRc, SelectStatement, SimpleExpr, Value,
+    Alias, BinOper, DynIden, Expr, Func, IntoIden, SeaRc, SelectStatement, SimpleExpr, Value,
 };
 use std::str::FromStr;
+use tokio_postgres::types::Type;
 
 // The original `pgorm::ColumnType` enum was dropped since 0.11.0
 // It was replaced by `pgorm_query::ColumnType`, we reexport it here to keep the `ColumnType` symbol
@@ -14,8 +18,10 @@ pub struct ColumnDef {
     pub(crate) col_type: ColumnType,
     pub(crate) null: bool,
     pub(crate) unique: bool,
+    pub(crate) unique_keys: Vec<String>,
     pub(crate) indexed: bool,
     pub(crate) default: Option<SimpleExpr>,
+    pub(crate) generated: Option<(SimpleExpr, bool)>,
     pub(crate) comment: Option<String>,
 }
 
@@ -66,6 +72,15 @@ macro_rules! bind_subquery_func {
     };
 }
 
+macro_rules! bind_subquery_oper {
+    ( $func: ident, $oper: ident, $quantifier: ident ) => {
+        #[allow(missing_docs)]
+        fn $func(&self, s: SelectStatement) -> SimpleExpr {
+            Expr::col((self.entity_name(), *self)).$oper(Expr::$quantifier(s))
+        }
+    };
+}
+
 // LINT: when the operand value does not match column type
 /// API for working with a `Column`. Mostly a wrapper of the identically named methods in [`pgorm_query::Expr`]
 pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
@@ -225,6 +240,7 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     bind_func_no_params!(min);
     bind_func_no_params!(sum);
     bind_func_no_params!(count);
+    bind_func_no_params!(count_distinct);
     bind_func_no_params!(is_null);
     bind_func_no_params!(is_not_null);
 
@@ -236,12 +252,128 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
         Expr::col((self.entity_name(), *self)).if_null(v)
     }
 
+    /// Concatenate the column with another expression using `||`
+    fn concat<T>(&self, right: T) -> SimpleExpr
+    where
+        T: IntoSimpleExpr,
+    {
+        Expr::col((self.entity_name(), *self)).concat(right.into_simple_expr())
+    }
+
+    /// Merge a JSON value into the `jsonb` column using `||`
+    fn json_merge<V>(&self, v: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).concat(Expr::val(v))
+    }
+
+    /// Remove a key from the `jsonb` column using `-`
+    fn json_delete_key<T>(&self, key: T) -> SimpleExpr
+    where
+        T: Into<String>,
+    {
+        Expr::col((self.entity_name(), *self)).sub(key.into())
+    }
+
+    /// Remove the field or array element at a path from the `jsonb` column using `#-`
+    fn json_delete_path<I, T>(&self, path: I) -> SimpleExpr
+    where
+        I: IntoIterator<Item = T>,
+        T: Into<String>,
+    {
+        let path: Vec<String> = path.into_iter().map(Into::into).collect();
+        Expr::col((self.entity_name(), *self)).delete_json_path(path)
+    }
+
+    /// Check the `inet`/`cidr` column is strictly contained within `network` using `<<`
+    fn subnet_of<V>(&self, network: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).subnet_of(Expr::val(network))
+    }
+
+    /// Check the `inet`/`cidr` column strictly contains `network` using `>>`
+    fn supernet_of<V>(&self, network: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).supernet_of(Expr::val(network))
+    }
+
+    /// Check the `inet`/`cidr` column and `network` overlap, one containing or equal to the
+    /// other, using `&&`
+    fn network_overlaps<V>(&self, network: V) -> SimpleExpr
+    where
+        V: Into<Value>,
+    {
+        Expr::col((self.entity_name(), *self)).binary(BinOper::Overlap, Expr::val(network))
+    }
+
     bind_vec_func!(is_in);
     bind_vec_func!(is_not_in);
 
     bind_subquery_func!(in_subquery);
     bind_subquery_func!(not_in_subquery);
 
+    bind_subquery_oper!(eq_any, eq, any);
+    bind_subquery_oper!(ne_all, ne, all);
+    bind_subquery_oper!(gt_any, gt, any);
+    bind_subquery_oper!(gt_all, gt, all);
+    bind_subquery_oper!(gte_any, gte, any);
+    bind_subquery_oper!(gte_all, gte, all);
+    bind_subquery_oper!(lt_any, lt, any);
+    bind_subquery_oper!(lt_all, lt, all);
+    bind_subquery_oper!(lte_any, lte, any);
+    bind_subquery_oper!(lte_all, lte, all);
+
+    /// Check the column equals any of `exprs` using `= ANY(ARRAY[..])`. Unlike [`Self::is_in`],
+    /// the elements may be expressions, such as other columns, as well as values.
+    ///
+    /// ```
+    /// use pgorm::{entity::*, query::*};
+    /// use pgorm::pgorm_query::Expr;
+    /// # mod fruit {
+    /// #     use pgorm::entity::prelude::*;
+    /// #
+    /// #     #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    /// #     #[pgorm(table_name = "fruit")]
+    /// #     pub struct Model {
+    /// #         #[pgorm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub name: String,
+    /// #         pub cake_id: Option<i32>,
+    /// #     }
+    /// #
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    ///
+    /// assert_eq!(
+    ///     fruit::Entity::find()
+    ///         .filter(fruit::Column::Id.eq_any_exprs([
+    ///             Expr::val(1).into(),
+    ///             Expr::col(fruit::Column::CakeId).into(),
+    ///         ]))
+    ///         .build()
+    ///         .0,
+    ///     [
+    ///         r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+    ///         r#"WHERE "fruit"."id" = ANY(ARRAY[$1, "cake_id"])"#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    fn eq_any_exprs<I>(&self, exprs: I) -> SimpleExpr
+    where
+        I: IntoIterator<Item = SimpleExpr>,
+    {
+        Expr::col((self.entity_name(), *self)).eq(Func::any(Expr::array(exprs)))
+    }
+
     /// Construct a [`SimpleExpr::Column`] wrapped in [`Expr`].
     fn into_expr(self) -> Expr {
         Expr::expr(self.into_simple_expr())
@@ -260,7 +392,11 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
     }
 
     /// Cast enum column as text; do nothing if `self` is not an enum.
+    /// A `money` column is read as `numeric`, whose output does not depend on `lc_monetary`.
     fn select_enum_as(&self, expr: Expr) -> SimpleExpr {
+        if let ColumnType::Money(_) = self.def().get_column_type() {
+            return expr.cast_as(Numeric);
+        }
         cast_enum_as(expr, self, |col, _, col_type| {
             let type_name = match col_type {
                 ColumnType::Array(_) => TextArray.into_iden(),
@@ -278,7 +414,13 @@ pub trait ColumnTrait: IdenStatic + Iterable + FromStr {
 
     /// Cast value of an enum column as enum type; do nothing if `self` is not an enum.
     /// Will also transform `Array(Vec<Json>)` into `Json(Vec<Json>)` if the column type is `Json`.
+    /// A value of a `money` column is passed as `numeric` and then cast, since the text
+    /// form of `money` is parsed according to `lc_monetary`.
     fn save_enum_as(&self, val: Expr) -> SimpleExpr {
+        if let ColumnType::Money(_) = self.def().get_column_type() {
+            return val.cast_as(Numeric).cast_as(Money);
+        }
+        let val = cast_null_as(val, self.def().get_column_type());
         cast_enum_as(val, self, |col, enum_name, col_type| {
             let type_name = match col_type {
                 ColumnType::Array(_) => {
@@ -306,8 +448,10 @@ impl ColumnTypeTrait for ColumnType {
             col_type: self,
             null: false,
             unique: false,
+            unique_keys: Vec::new(),
             indexed: false,
             default: None,
+            generated: None,
             comment: None,
         }
     }
@@ -341,6 +485,13 @@ impl ColumnDef {
         self.unique = true;
         self
     }
+
+    /// Adds the column to the composite unique constraint `name`, which spans every column of
+    /// the entity added to it, in declaration order
+    pub fn unique_key(mut self, name: &str) -> Self {
+        self.unique_keys.push(name.to_owned());
+        self
+    }
     /// Set column comment
     pub fn comment(mut self, v: &str) -> Self {
         self.comment = Some(v.into());
@@ -382,6 +533,17 @@ impl ColumnDef {
         self
     }
 
+    /// Make the column a generated column computed from `expr`, `GENERATED ALWAYS AS (expr)`,
+    /// followed by `STORED` if `stored`. A generated column can't be written, so its value is
+    /// left out when inserting or updating.
+    pub fn generated<T>(mut self, expr: T, stored: bool) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.generated = Some((expr.into(), stored));
+        self
+    }
+
     /// Get [ColumnType] as reference
     pub fn get_column_type(&self) -> &ColumnType {
         &self.col_type
@@ -391,10 +553,17 @@ impl ColumnDef {
     pub fn is_null(&self) -> bool {
         self.null
     }
+
+    /// Returns true if the column is a generated column
+    pub fn is_generated(&self) -> bool {
+        self.generated.is_some()
+    }
 }
 
 struct Text;
 struct TextArray;
+struct Numeric;
+struct Money;
 
 impl Iden for Text {
     fn unquoted(&self, s: &mut dyn std::fmt::Write) {
@@ -408,6 +577,36 @@ impl Iden for TextArray {
     }
 }
 
+impl Iden for Numeric {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        let _ = write!(s, "numeric");
+    }
+}
+
+impl Iden for Money {
+    fn unquoted(&self, s: &mut dyn std::fmt::Write) {
+        let _ = write!(s, "money");
+    }
+}
+
+/// Cast a `NULL` to the type of its column, when that type is known and differs from the type
+/// the `NULL` would be prepared with, which the column may not accept.
+fn cast_null_as(val: Expr, col_type: &ColumnType) -> Expr {
+    let val = SimpleExpr::from(val);
+    if let SimpleExpr::Value(value) = &val {
+        let null_type = null_pg_type(value);
+        let col_pg_type = column_type_to_pg_type(col_type);
+        if is_null(value)
+            && null_type != Type::UNKNOWN
+            && col_pg_type != Type::UNKNOWN
+            && null_type != col_pg_type
+        {
+            return Expr::expr(Func::cast_as_type(val, col_type.clone()));
+        }
+    }
+    Expr::expr(val)
+}
+
 fn cast_enum_as<C, F>(expr: Expr, col: &C, f: F) -> SimpleExpr
 where
     C: ColumnTrait,
@@ -4,7 +4,9 @@ pub use crate::{
     Iden, IdenStatic, Linked, LoaderTrait, ModelTrait, PrimaryKeyArity, PrimaryKeyToColumn,
     PrimaryKeyTrait, QueryFilter, QueryResult, Related, RelationDef, RelationTrait, Select, Value,
     error::*,
-    pgorm_query::{DynIden, Expr, ForeignKeyAction, RcOrArc, SeaRc, StringLen},
+    pgorm_query::{
+        DynIden, Expr, ForeignKeyAction, Func, Keyword, RcOrArc, SeaRc, SimpleExpr, StringLen,
+    },
 };
 
 #[cfg(feature = "macros")]
@@ -82,3 +84,7 @@ pub use bigdecimal::BigDecimal;
 
 #[cfg(feature = "with-uuid")]
 pub use uuid::Uuid;
+
+pub use ipnetwork::IpNetwork;
+
+pub use mac_address::MacAddress;
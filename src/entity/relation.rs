@@ -217,6 +217,18 @@ impl RelationDef {
         self
     }
 
+    /// Express the relation to a table alias.
+    ///
+    /// This is the counterpart of [`RelationDef::from_alias`]. `DeriveRelation` uses it
+    /// on self-referencing relations, so the joined table does not clash with the base table.
+    pub fn to_alias<A>(mut self, alias: A) -> Self
+    where
+        A: IntoIden,
+    {
+        self.to_tbl = self.to_tbl.alias(alias);
+        self
+    }
+
     /// Set custom join ON condition.
     ///
     /// This method takes a closure with two parameters
@@ -61,6 +61,16 @@ pub enum DbErr {
     /// May be the table is empty or the record does not exist
     #[error("None of the records are updated")]
     RecordNotUpdated,
+    /// No record was deleted by a single-row delete, that means the record does not exist,
+    /// possibly because it was already deleted
+    #[error("None of the records are deleted")]
+    RecordNotDeleted,
+    /// The statement was rejected by client-side validation before reaching the database
+    #[error("Validation Error: {0}")]
+    Validation(String),
+    /// The operation was stopped because its cancellation token was cancelled
+    #[error("The operation was cancelled")]
+    Cancelled,
     /// A custom error
     #[error("Custom Error: {0}")]
     Custom(String),
@@ -93,11 +103,34 @@ impl PartialEq for DbErr {
 
 impl Eq for DbErr {}
 
+impl From<pgorm_query::ValueTypeErr> for DbErr {
+    fn from(err: pgorm_query::ValueTypeErr) -> Self {
+        type_err(err)
+    }
+}
+
+impl From<pgorm_query::error::Error> for DbErr {
+    fn from(err: pgorm_query::error::Error) -> Self {
+        query_err(err)
+    }
+}
+
 /// Error during `impl FromStr for Entity::Column`
 #[derive(Error, Debug)]
 #[error("Failed to match \"{0}\" as Column")]
 pub struct ColumnFromStrErr(pub String);
 
+/// An error from a closure-driven transaction, see [`DatabasePool::transaction`](crate::DatabasePool::transaction)
+#[derive(Error, Debug)]
+pub enum TransactionError<E> {
+    /// Acquiring the connection, beginning, committing or rolling back failed
+    #[error("Connection Error: {0}")]
+    Connection(#[source] DbErr),
+    /// The callback returned an error and the transaction was rolled back
+    #[error("Transaction Error: {0}")]
+    Transaction(#[source] E),
+}
+
 #[allow(dead_code)]
 pub(crate) fn conn_err<T>(s: T) -> DbErr
 where
@@ -1,16 +1,19 @@
 use crate::{
     ConnectionTrait, DbErr, EntityTrait, FromQueryResult, Identity, IdentityOf, IntoIdentity,
     PartialModelTrait, PrimaryKeyToColumn, QueryOrder, QuerySelect, Select, SelectModel, SelectTwo,
-    SelectTwoModel, SelectorTrait,
+    SelectTwoModel, SelectorTrait, column_type_to_pg_type,
 };
 // use bigdecimal::BigDecimal;
 // use chrono::{DateTime, FixedOffset, Local, NaiveDate, NaiveDateTime, NaiveTime, Utc};
 // use rust_decimal::Decimal;
 use pgorm_query::{
-    ArrayType, Condition, DynIden, Expr, IntoValueTuple, Order, QueryBuilder, SeaRc,
-    SelectStatement, SimpleExpr, Value, ValueTuple,
+    Condition, DynIden, Expr, IntoValueTuple, Order, QueryBuilder, SeaRc, SelectStatement,
+    SimpleExpr, Value, ValueTuple, Values,
+};
+use tokio_postgres::{
+    Statement,
+    types::{IsNull, ToSql, Type, to_sql_checked},
 };
-use tokio_postgres::types::{IsNull, ToSql, Type, to_sql_checked};
 // use uuid::Uuid;
 use std::marker::PhantomData;
 use strum::IntoEnumIterator as Iterable;
@@ -281,13 +284,8 @@ where
         self.apply_filters();
 
         let (stmt, values) = self.query.build(QueryBuilder);
-        let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
-        let values = values
-            .iter()
-            .map(|x| &*x as _)
-            .collect::<Vec<&(dyn ToSql + Sync)>>();
 
-        let rows = db.query_all(&stmt, &values).await?;
+        let rows = db.query_all_values(&stmt, values).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
             buffer.push(S::from_raw_query_result(QueryResult { row })?);
@@ -2408,10 +2406,194 @@ impl std::fmt::Debug for ValueHolder {
     }
 }
 
+impl ValueHolder {
+    /// The Postgres type this value is bound as, going by the [`Value`] variant alone,
+    /// so a `NULL` has a type too. Values without a built-in type bind as
+    /// [`Type::UNKNOWN`], which leaves the type to the server.
+    pub fn pg_type(&self) -> Type {
+        pg_type(&self.0)
+    }
+}
+
+fn pg_type(value: &Value) -> Type {
+    column_type_to_pg_type(&value.column_type())
+}
+
+/// Pair each bound parameter with the Postgres type it binds as, for
+/// [`ConnectionTrait::query_typed`]
+///
+/// ```no_run
+/// # use pgorm::{ConnectionTrait, DatabaseConnection, DbErr, typed_values};
+/// # use pgorm::pgorm_query::{Value, Values};
+/// # use pgorm::types::ToSql;
+/// # async fn f(conn: &DatabaseConnection) -> Result<(), DbErr> {
+/// let params = typed_values(Values(vec![Value::Int(None)])).collect::<Vec<_>>();
+/// let params = params
+///     .iter()
+///     .map(|(value, ty)| (value as &(dyn ToSql + Sync), ty.clone()))
+///     .collect::<Vec<_>>();
+/// conn.query_typed("SELECT $1 IS NULL", &params).await?;
+/// # Ok(())
+/// # }
+/// ```
+pub fn typed_values(values: Values) -> impl Iterator<Item = (ValueHolder, Type)> {
+    values.into_iter().map(|value| {
+        let value = ValueHolder(value);
+        let ty = value.pg_type();
+        (value, ty)
+    })
+}
+
+/// Prepare a built statement for binding `values`. Each `NULL` is given the type of
+/// [`null_pg_type`], as the server cannot always infer one from the query alone; the
+/// other parameters are left for the server to infer.
+pub(crate) async fn prepare_statement<C>(
+    db: &C,
+    stmt: &str,
+    values: &Values,
+) -> Result<Statement, DbErr>
+where
+    C: ConnectionTrait,
+{
+    let types = values
+        .iter()
+        .map(|value| match is_null(value) {
+            true => null_pg_type(value),
+            false => Type::UNKNOWN,
+        })
+        .collect::<Vec<_>>();
+    db.prepare_typed(stmt, &types).await
+}
+
+/// The type a `NULL` is prepared with. A text or JSON `NULL` is left for the server to
+/// infer, since a column of any type, such as `interval`, may be mapped to a `String`;
+/// any other takes the type of its [`Value`] variant.
+pub(crate) fn null_pg_type(value: &Value) -> Type {
+    match value {
+        Value::String(_) | Value::Char(_) | Value::Json(_) => Type::UNKNOWN,
+        _ => pg_type(value),
+    }
+}
+
+pub(crate) fn is_null(value: &Value) -> bool {
+    matches!(
+        value,
+        Value::Bool(None)
+            | Value::TinyInt(None)
+            | Value::SmallInt(None)
+            | Value::Int(None)
+            | Value::BigInt(None)
+            | Value::TinyUnsigned(None)
+            | Value::SmallUnsigned(None)
+            | Value::Unsigned(None)
+            | Value::BigUnsigned(None)
+            | Value::Float(None)
+            | Value::Double(None)
+            | Value::String(None)
+            | Value::Char(None)
+            | Value::Bytes(None)
+            | Value::Json(None)
+            | Value::ChronoDate(None)
+            | Value::ChronoTime(None)
+            | Value::ChronoDateTime(None)
+            | Value::ChronoDateTimeUtc(None)
+            | Value::ChronoDateTimeLocal(None)
+            | Value::ChronoDateTimeWithTimeZone(None)
+            | Value::Uuid(None)
+            | Value::Decimal(None)
+            | Value::Array(_, None)
+            | Value::Vector(None)
+            | Value::IpNetwork(None)
+            | Value::MacAddress(None)
+    )
+}
+
 use bytes::BytesMut;
+use ipnetwork::IpNetwork;
+use std::net::IpAddr;
 
 use super::QueryResult;
 
+/// Write the binary `inet`/`cidr` format: address family, prefix length, whether it is a
+/// `cidr`, address length and the address bytes
+fn inet_to_sql(network: &IpNetwork, ty: &Type, out: &mut BytesMut) {
+    use bytes::BufMut;
+
+    let (family, addr) = match network.ip() {
+        IpAddr::V4(ip) => (2, ip.octets().to_vec()),
+        IpAddr::V6(ip) => (3, ip.octets().to_vec()),
+    };
+    out.put_u8(family);
+    out.put_u8(network.prefix());
+    out.put_u8(u8::from(*ty == Type::CIDR));
+    out.put_u8(addr.len() as u8);
+    out.put_slice(&addr);
+}
+
+type BoxError = Box<dyn std::error::Error + Sync + Send>;
+
+/// Write a multidimensional array in the binary array format: the number of dimensions,
+/// whether any element is `NULL`, the element type, the length and lower bound of each
+/// dimension, then every element in row-major order, each prefixed by its length
+fn nested_array_to_sql(array: &[Value], ty: &Type, out: &mut BytesMut) -> Result<IsNull, BoxError> {
+    use bytes::BufMut;
+    use tokio_postgres::types::Kind;
+
+    let Kind::Array(member) = ty.kind() else {
+        return Err(format!("expected array type, got {ty}").into());
+    };
+
+    let mut dims = Vec::new();
+    let mut level = array;
+    loop {
+        dims.push(level.len());
+        match level.first() {
+            Some(Value::Array(_, Some(inner))) => level = inner,
+            _ => break,
+        }
+    }
+    let mut elements = Vec::new();
+    flatten_array(array, &dims, &mut elements)?;
+
+    out.put_i32(i32::try_from(dims.len())?);
+    out.put_i32(i32::from(elements.iter().any(|value| is_null(value))));
+    out.put_u32(member.oid());
+    for len in dims {
+        out.put_i32(i32::try_from(len)?);
+        out.put_i32(1);
+    }
+    for value in elements {
+        let start = out.len();
+        out.put_i32(-1);
+        if let IsNull::No = ValueHolder(value.clone()).to_sql(member, out)? {
+            let len = i32::try_from(out.len() - start - 4)?;
+            out[start..start + 4].copy_from_slice(&len.to_be_bytes());
+        }
+    }
+    Ok(IsNull::No)
+}
+
+/// Collect the elements of a nested array, checking each level has the length in `dims`
+fn flatten_array<'a>(
+    array: &'a [Value],
+    dims: &[usize],
+    elements: &mut Vec<&'a Value>,
+) -> Result<(), BoxError> {
+    const MISMATCH: &str = "multidimensional arrays must have sub-arrays with matching dimensions";
+
+    if array.len() != dims[0] {
+        return Err(MISMATCH.into());
+    }
+    for value in array {
+        match (value, dims.len()) {
+            (Value::Array(_, Some(inner)), 2..) => flatten_array(inner, &dims[1..], elements)?,
+            (Value::Array(..), _) | (_, 2..) => return Err(MISMATCH.into()),
+            _ => elements.push(value),
+        }
+    }
+    Ok(())
+}
+
 #[inline(always)]
 fn accepts<T: ToSql>(input: T, ty: &Type) -> bool {
     T::accepts(ty)
@@ -2428,12 +2610,12 @@ impl ToSql for ValueHolder {
     {
         match &self.0 {
             Value::Bool(x) => x.to_sql(ty, out),
-            Value::TinyInt(x) => x.to_sql(ty, out),
+            Value::TinyInt(x) => x.map(i16::from).to_sql(ty, out),
             Value::SmallInt(x) => x.to_sql(ty, out),
             Value::Int(x) => x.to_sql(ty, out),
             Value::BigInt(x) => x.to_sql(ty, out),
-            Value::TinyUnsigned(_x) => unimplemented!("u8 not supported"), //x.as_ref().map(|x| (*x).to_sql(ty, out))
-            Value::SmallUnsigned(_x) => unimplemented!("u16 not supported"), // x.map(|x| x as _).to_sql(ty, out),
+            Value::TinyUnsigned(x) => x.map(i16::from).to_sql(ty, out),
+            Value::SmallUnsigned(x) => x.map(i32::from).to_sql(ty, out),
             Value::Unsigned(x) => x.to_sql(ty, out),
             Value::BigUnsigned(x) => {
                 //unimplemented!("ToSql: {x:?}, {ty:?}, u64 not supported"), // x.map(|x| x as _).to_sql(ty, out),
@@ -2488,6 +2670,9 @@ impl ToSql for ValueHolder {
                 .unwrap_or(Ok(IsNull::Yes)), // x.map(|x| &*x).to_sql(ty, out),
             Value::Decimal(x) => x.as_ref().map(|x| &**x).to_sql(ty, out),
             // Value::BigDecimal(x) => x.map(|x| &**x).to_sql(ty, out),
+            Value::Array(_, Some(x)) if matches!(x.first(), Some(Value::Array(..))) => {
+                nested_array_to_sql(x, ty, out)
+            }
             Value::Array(_, Some(x)) => x
                 .iter()
                 .map(|x| ValueHolder(x.clone()))
@@ -2495,8 +2680,20 @@ impl ToSql for ValueHolder {
                 .to_sql(ty, out),
             Value::Array(_, None) => Ok(IsNull::Yes),
             Value::Vector(vector) => todo!(),
-            Value::IpNetwork(ip_network) => todo!(),
-            Value::MacAddress(mac_address) => todo!(),
+            Value::IpNetwork(x) => match x.as_ref() {
+                Some(x) => {
+                    inet_to_sql(x, ty, out);
+                    Ok(IsNull::No)
+                }
+                None => Ok(IsNull::Yes),
+            },
+            Value::MacAddress(x) => match x.as_ref() {
+                Some(x) => {
+                    out.extend_from_slice(&x.bytes());
+                    Ok(IsNull::No)
+                }
+                None => Ok(IsNull::Yes),
+            },
         }
     }
 
@@ -1,14 +1,12 @@
 use crate::{ActiveModelTrait, ConnectionTrait, DeleteMany, DeleteOne, EntityTrait, error::*};
 use pgorm_query::{DeleteStatement, QueryBuilder};
 use std::future::Future;
-use tokio_postgres::types::ToSql;
-
-use super::ValueHolder;
 
 /// Handles DELETE operations in a ActiveModel using [DeleteStatement]
 #[derive(Clone, Debug)]
 pub struct Deleter {
     query: DeleteStatement,
+    check_record_exists: bool,
 }
 
 /// The result of a DELETE operation
@@ -22,13 +20,14 @@ impl<'a, A: 'a> DeleteOne<A>
 where
     A: ActiveModelTrait,
 {
-    /// Execute a DELETE operation on one ActiveModel
+    /// Execute a DELETE operation on one ActiveModel.
+    /// Returns [`DbErr::RecordNotDeleted`] if no row was deleted.
     pub fn exec<C>(self, db: &'a C) -> impl Future<Output = Result<DeleteResult, DbErr>> + '_
     where
         C: ConnectionTrait,
     {
         // so that self is dropped before entering await
-        exec_delete_only(self.query, db)
+        exec_delete_one(self.query, db)
     }
 }
 
@@ -49,7 +48,16 @@ where
 impl Deleter {
     /// Instantiate a new [Deleter] by passing it a [DeleteStatement]
     pub fn new(query: DeleteStatement) -> Self {
-        Self { query }
+        Self {
+            query,
+            check_record_exists: false,
+        }
+    }
+
+    /// Check that the DELETE operation removed at least one row
+    pub fn check_record_exists(mut self) -> Self {
+        self.check_record_exists = true;
+        self
     }
 
     /// Execute a DELETE operation
@@ -57,10 +65,17 @@ impl Deleter {
     where
         C: ConnectionTrait,
     {
-        exec_delete(self.query, db)
+        exec_delete(self.query, self.check_record_exists, db)
     }
 }
 
+async fn exec_delete_one<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
+where
+    C: ConnectionTrait,
+{
+    Deleter::new(query).check_record_exists().exec(db).await
+}
+
 async fn exec_delete_only<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
 where
     C: ConnectionTrait,
@@ -68,18 +83,20 @@ where
     Deleter::new(query).exec(db).await
 }
 
-async fn exec_delete<C>(query: DeleteStatement, db: &C) -> Result<DeleteResult, DbErr>
+async fn exec_delete<C>(
+    query: DeleteStatement,
+    check_record_exists: bool,
+    db: &C,
+) -> Result<DeleteResult, DbErr>
 where
     C: ConnectionTrait,
 {
     let (stmt, values) = query.build(QueryBuilder);
-    let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
-    let values = values
-        .iter()
-        .map(|x| &*x as _)
-        .collect::<Vec<&(dyn ToSql + Sync)>>();
 
-    let result = db.execute(&stmt, &values).await?;
+    let result = db.execute_values(&stmt, values).await?;
+    if check_record_exists && result == 0 {
+        return Err(DbErr::RecordNotDeleted);
+    }
     Ok(DeleteResult {
         rows_affected: result,
     })
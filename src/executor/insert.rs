@@ -1,13 +1,14 @@
 use crate::{
-    ActiveModelTrait, ColumnTrait, ConnectionTrait, EntityTrait, Insert, IntoActiveModel, Iterable,
-    PrimaryKeyToColumn, PrimaryKeyTrait, QueryResult, SelectModel, SelectorRaw, TryInsert,
-    error::*,
+    ActiveModelTrait, ColumnTrait, ConnectionTrait, DatabaseTransaction, EntityTrait,
+    FromQueryResult, Insert, InsertManyLenient, IntoActiveModel, IntoIdentity, IntoSimpleExpr,
+    Iterable, PartialModelTrait, PrimaryKeyToColumn, PrimaryKeyTrait, QueryResult, SelectColumns,
+    SelectModel, SelectorRaw, TryInsert, error::*,
+};
+use pgorm_query::{
+    BinOper, Expr, FromValueTuple, Iden, InsertStatement, Query, QueryBuilder, SimpleExpr,
+    ValueTuple,
 };
-use pgorm_query::{FromValueTuple, Iden, InsertStatement, Query, QueryBuilder, ValueTuple};
 use std::{future::Future, marker::PhantomData};
-use tokio_postgres::types::ToSql;
-
-use super::ValueHolder;
 
 /// Defines a structure to perform INSERT operations in an ActiveModel
 #[derive(Debug)]
@@ -26,7 +27,8 @@ pub struct InsertResult<A>
 where
     A: ActiveModelTrait,
 {
-    /// The id performed when AUTOINCREMENT was performed on the PrimaryKey
+    /// The primary key of the inserted row, typed as the Entity's primary key value: a tuple
+    /// for a composite key. Unless every key column was set, it is read back with `RETURNING`.
     pub last_insert_id: <<<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::ValueType,
 }
 
@@ -41,6 +43,60 @@ pub enum TryInsertResult<T> {
     Inserted(T),
 }
 
+/// The result of a lenient batch INSERT, see [`InsertManyLenient`]
+#[derive(Debug)]
+pub struct LenientInsertResult<A>
+where
+    A: ActiveModelTrait,
+{
+    /// The rows that were inserted, in input order
+    pub inserted: Vec<InsertResult<A>>,
+    /// The input position and error of each row that failed and was skipped
+    pub failed: Vec<(usize, DbErr)>,
+}
+
+const LENIENT_INSERT_SAVEPOINT: &str = "pgorm_insert_lenient";
+
+impl<A> InsertManyLenient<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Insert the rows one by one inside `txn`, each in a savepoint. A row that fails is
+    /// rolled back to its savepoint and reported in [`LenientInsertResult::failed`], and the
+    /// remaining rows are still inserted.
+    ///
+    /// An error from the savepoint commands themselves aborts the batch.
+    pub async fn exec(
+        self,
+        txn: &DatabaseTransaction<'_>,
+    ) -> Result<LenientInsertResult<A>, DbErr> {
+        let mut inserted = Vec::new();
+        let mut failed = Vec::new();
+        for (idx, insert) in self.inserts.into_iter().enumerate() {
+            txn.execute(&format!("SAVEPOINT {LENIENT_INSERT_SAVEPOINT}"), &[])
+                .await?;
+            match insert.exec(txn).await {
+                Ok(res) => inserted.push(res),
+                Err(err) => {
+                    txn.execute(
+                        &format!("ROLLBACK TO SAVEPOINT {LENIENT_INSERT_SAVEPOINT}"),
+                        &[],
+                    )
+                    .await?;
+                    failed.push((idx, err));
+                }
+            }
+            // ROLLBACK TO keeps the savepoint on the stack, so release it either way
+            txn.execute(
+                &format!("RELEASE SAVEPOINT {LENIENT_INSERT_SAVEPOINT}"),
+                &[],
+            )
+            .await?;
+        }
+        Ok(LenientInsertResult { inserted, failed })
+    }
+}
+
 impl<A> TryInsert<A>
 where
     A: ActiveModelTrait,
@@ -112,12 +168,16 @@ where
     A: ActiveModelTrait,
 {
     /// Execute an insert operation
+    ///
+    /// Fails with [`DbErr::Validation`] if [`Insert::returning_expr`] expressions were added,
+    /// which only [`Insert::exec_with_returning_as`] can read back.
     #[allow(unused_mut)]
     pub fn exec<'a, C>(self, db: &'a C) -> impl Future<Output = Result<InsertResult<A>, DbErr>> + '_
     where
         C: ConnectionTrait,
         A: 'a,
     {
+        let checked = self.check_returning_read("exec");
         // so that self is dropped before entering await
         let mut query = self.query;
         let returning =
@@ -126,11 +186,17 @@ where
                     .select_as(c.into_column().into_returning_expr())
             }));
         query.returning(returning);
-        Inserter::<A>::new(self.primary_key, query).exec(db)
+        let exec = Inserter::<A>::new(self.primary_key, query).exec(db);
+        async move {
+            checked?;
+            exec.await
+        }
     }
 
     /// Execute an insert operation without returning (don't use `RETURNING` syntax)
     /// Number of rows affected is returned
+    ///
+    /// Fails with [`DbErr::Validation`] if [`Insert::returning_expr`] expressions were added.
     pub fn exec_without_returning<'a, C>(
         self,
         db: &'a C,
@@ -140,10 +206,18 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_without_returning(db)
+        let checked = self.check_returning_read("exec_without_returning");
+        let exec = Inserter::<A>::new(self.primary_key, self.query).exec_without_returning(db);
+        async move {
+            checked?;
+            exec.await
+        }
     }
 
     /// Execute an insert operation and return the inserted model (use `RETURNING` syntax if supported)
+    ///
+    /// Fails with [`DbErr::Validation`] if [`Insert::returning_expr`] expressions were added,
+    /// which only [`Insert::exec_with_returning_as`] can read back.
     pub fn exec_with_returning<'a, C>(
         self,
         db: &'a C,
@@ -153,7 +227,75 @@ where
         C: ConnectionTrait,
         A: 'a,
     {
-        Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db)
+        let checked = self.check_returning_read("exec_with_returning");
+        let exec = Inserter::<A>::new(self.primary_key, self.query).exec_with_returning(db);
+        async move {
+            checked?;
+            exec.await
+        }
+    }
+
+    /// Reject [`Insert::returning_expr`] expressions in an `exec` method that would drop them
+    fn check_returning_read(&self, method: &str) -> Result<(), DbErr> {
+        if self.returning.is_empty() {
+            return Ok(());
+        }
+        Err(DbErr::Validation(format!(
+            "Insert::{method} can't read back returning_expr expressions, use exec_with_returning_as"
+        )))
+    }
+
+    /// Execute an insert operation and decode the returned row, including any
+    /// [`Insert::returning_expr`] expressions, into `M`
+    pub fn exec_with_returning_as<'a, M, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<M, DbErr>> + 'a
+    where
+        M: FromQueryResult + 'a,
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        exec_insert_with_returning_as::<A, M, _>(self.query, self.returning, db)
+    }
+
+    /// Execute an insert operation, returning only the columns selected by the partial model
+    /// `M`, followed by any [`Insert::returning_expr`] expressions, and decoding the returned
+    /// row into it
+    pub fn exec_returning_partial<'a, M, C>(
+        self,
+        db: &'a C,
+    ) -> impl Future<Output = Result<M, DbErr>> + 'a
+    where
+        M: PartialModelTrait + 'a,
+        C: ConnectionTrait,
+        A: 'a,
+    {
+        let ReturningColumns(mut exprs) = M::select_cols(ReturningColumns(Vec::new()));
+        exprs.extend(self.returning);
+        exec_insert_returning_exprs::<M, _>(self.query, exprs, db)
+    }
+}
+
+/// Collects the columns a [`PartialModelTrait`] selects into a `RETURNING` list
+struct ReturningColumns(Vec<SimpleExpr>);
+
+impl SelectColumns for ReturningColumns {
+    fn select_column<C: ColumnTrait>(mut self, col: C) -> Self {
+        self.0.push(col.select_as(col.into_returning_expr()));
+        self
+    }
+
+    fn select_column_as<C, I>(mut self, col: C, alias: I) -> Self
+    where
+        C: IntoSimpleExpr,
+        I: IntoIdentity,
+    {
+        self.0.push(
+            col.into_simple_expr()
+                .binary(BinOper::As, Expr::col(alias.into_identity())),
+        );
+        self
     }
 }
 
@@ -215,25 +357,20 @@ where
     A: ActiveModelTrait,
 {
     let (stmt, values) = statement.build(QueryBuilder);
-    let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
-    let values = values
-        .iter()
-        .map(|x| &*x as _)
-        .collect::<Vec<&(dyn ToSql + Sync)>>();
 
     type PrimaryKey<A> = <<A as ActiveModelTrait>::Entity as EntityTrait>::PrimaryKey;
     type ValueTypeOf<A> = <PrimaryKey<A> as PrimaryKeyTrait>::ValueType;
 
     let last_insert_id = match primary_key {
         Some(value_tuple) => {
-            let res = db.execute(&stmt, &values).await?;
+            let res = db.execute_values(&stmt, values).await?;
             if res == 0 {
                 return Err(DbErr::RecordNotInserted);
             }
             FromValueTuple::from_value_tuple(value_tuple)
         }
         None => {
-            let mut rows = db.query_all(&stmt, &values).await?;
+            let mut rows = db.query_all_values(&stmt, values).await?;
             let row = match rows.pop() {
                 Some(row) => QueryResult { row },
                 None => return Err(DbErr::RecordNotInserted),
@@ -257,13 +394,8 @@ where
     C: ConnectionTrait,
 {
     let (stmt, values) = insert_statement.build(QueryBuilder);
-    let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
-    let values = values
-        .iter()
-        .map(|x| &*x as _)
-        .collect::<Vec<&(dyn ToSql + Sync)>>();
 
-    let exec_result = db.execute(&stmt, &values).await?;
+    let exec_result = db.execute_values(&stmt, values).await?;
     Ok(exec_result)
 }
 
@@ -293,3 +425,42 @@ where
         None => Err(DbErr::RecordNotFound),
     }
 }
+
+async fn exec_insert_with_returning_as<A, M, C>(
+    insert_statement: InsertStatement,
+    exprs: Vec<SimpleExpr>,
+    db: &C,
+) -> Result<M, DbErr>
+where
+    A: ActiveModelTrait,
+    M: FromQueryResult,
+    C: ConnectionTrait,
+{
+    let returning = <A::Entity as EntityTrait>::Column::iter()
+        .map(|c| c.select_as(c.into_returning_expr()))
+        .chain(exprs)
+        .collect();
+    exec_insert_returning_exprs::<M, _>(insert_statement, returning, db).await
+}
+
+async fn exec_insert_returning_exprs<M, C>(
+    mut insert_statement: InsertStatement,
+    exprs: Vec<SimpleExpr>,
+    db: &C,
+) -> Result<M, DbErr>
+where
+    M: FromQueryResult,
+    C: ConnectionTrait,
+{
+    insert_statement.returning(Query::returning().exprs(exprs));
+    let (stmt, values) = insert_statement.build(QueryBuilder);
+
+    let found = SelectorRaw::<SelectModel<M>>::from_statement(stmt, values)
+        .one_opt(db)
+        .await?;
+
+    match found {
+        Some(model) => Ok(model),
+        None => Err(DbErr::RecordNotFound),
+    }
+}
@@ -3,6 +3,7 @@ mod delete;
 mod execute;
 mod insert;
 mod paginator;
+mod pg_type;
 mod query;
 mod select;
 mod update;
@@ -12,6 +13,7 @@ pub use delete::*;
 pub use execute::*;
 pub use insert::*;
 pub use paginator::*;
+pub use pg_type::*;
 pub use query::*;
 pub use select::*;
 pub use update::*;
@@ -6,9 +6,8 @@ use async_stream::stream;
 use futures::Stream;
 use pgorm_query::{Alias, Expr, QueryBuilder, SelectStatement};
 use std::{marker::PhantomData, pin::Pin};
-use tokio_postgres::types::ToSql;
 
-use super::{QueryResult, ValueHolder};
+use super::QueryResult;
 
 /// Pin a Model so that stream operations can be performed on the model
 pub type PinBoxStream<'db, Item> = Pin<Box<dyn Stream<Item = Item> + 'db>>;
@@ -52,12 +51,7 @@ where
             .offset(self.page_size * page)
             .to_owned();
         let (stmt, values) = query.build(QueryBuilder);
-        let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
-        let values = values
-            .iter()
-            .map(|x| &*x as _)
-            .collect::<Vec<&(dyn ToSql + Sync)>>();
-        let rows = self.db.query_all(&stmt, &values).await?;
+        let rows = self.db.query_all_values(&stmt, values).await?;
         let mut buffer = Vec::with_capacity(rows.len());
         for row in rows.into_iter() {
             // TODO: Error handling
@@ -86,12 +80,7 @@ where
             )
             .to_owned();
         let (stmt, values) = stmt.build(QueryBuilder);
-        let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
-        let values = values
-            .iter()
-            .map(|x| &*x as _)
-            .collect::<Vec<&(dyn ToSql + Sync)>>();
-        let result = match self.db.query_opt(&stmt, &values).await? {
+        let result = match self.db.query_opt_values(&stmt, values).await? {
             Some(res) => res,
             None => return Ok(0),
         };
@@ -0,0 +1,80 @@
+use pgorm_query::ColumnType;
+use tokio_postgres::types::Type;
+
+/// The Postgres type of a column declared with `column_type`, matching the type the schema
+/// builder creates it with. Extension types (`vector`, `ltree`), enums and custom types have no
+/// fixed OID and map to [`Type::UNKNOWN`], which leaves the type to the server, as does an
+/// array of them.
+pub fn column_type_to_pg_type(column_type: &ColumnType) -> Type {
+    match column_type {
+        ColumnType::Char(_) => Type::BPCHAR,
+        ColumnType::String(_) => Type::VARCHAR,
+        ColumnType::Text => Type::TEXT,
+        ColumnType::Blob | ColumnType::Binary(_) | ColumnType::VarBinary(_) => Type::BYTEA,
+        ColumnType::TinyInteger
+        | ColumnType::SmallInteger
+        | ColumnType::TinyUnsigned
+        | ColumnType::SmallUnsigned => Type::INT2,
+        ColumnType::Integer | ColumnType::Unsigned => Type::INT4,
+        ColumnType::BigInteger | ColumnType::BigUnsigned => Type::INT8,
+        ColumnType::Float => Type::FLOAT4,
+        ColumnType::Double => Type::FLOAT8,
+        ColumnType::Decimal(_) => Type::NUMERIC,
+        ColumnType::DateTime | ColumnType::Timestamp => Type::TIMESTAMP,
+        ColumnType::TimestampWithTimeZone => Type::TIMESTAMPTZ,
+        ColumnType::Time => Type::TIME,
+        ColumnType::Date => Type::DATE,
+        ColumnType::Interval(_, _) => Type::INTERVAL,
+        ColumnType::Bit(_) => Type::BIT,
+        ColumnType::VarBit(_) => Type::VARBIT,
+        ColumnType::Boolean => Type::BOOL,
+        ColumnType::Money(_) => Type::MONEY,
+        ColumnType::Json => Type::JSON,
+        ColumnType::JsonBinary => Type::JSONB,
+        ColumnType::Uuid => Type::UUID,
+        ColumnType::Cidr => Type::CIDR,
+        ColumnType::Inet => Type::INET,
+        ColumnType::MacAddr => Type::MACADDR,
+        ColumnType::Year => Type::INT2,
+        ColumnType::Array(elem_type) => array_pg_type(&column_type_to_pg_type(elem_type)),
+        ColumnType::Vector(_)
+        | ColumnType::LTree
+        | ColumnType::Custom(_)
+        | ColumnType::Enum { .. } => Type::UNKNOWN,
+        _ => Type::UNKNOWN,
+    }
+}
+
+fn array_pg_type(elem: &Type) -> Type {
+    [
+        (Type::BPCHAR, Type::BPCHAR_ARRAY),
+        (Type::VARCHAR, Type::VARCHAR_ARRAY),
+        (Type::TEXT, Type::TEXT_ARRAY),
+        (Type::BYTEA, Type::BYTEA_ARRAY),
+        (Type::INT2, Type::INT2_ARRAY),
+        (Type::INT4, Type::INT4_ARRAY),
+        (Type::INT8, Type::INT8_ARRAY),
+        (Type::FLOAT4, Type::FLOAT4_ARRAY),
+        (Type::FLOAT8, Type::FLOAT8_ARRAY),
+        (Type::NUMERIC, Type::NUMERIC_ARRAY),
+        (Type::TIMESTAMP, Type::TIMESTAMP_ARRAY),
+        (Type::TIMESTAMPTZ, Type::TIMESTAMPTZ_ARRAY),
+        (Type::TIME, Type::TIME_ARRAY),
+        (Type::DATE, Type::DATE_ARRAY),
+        (Type::INTERVAL, Type::INTERVAL_ARRAY),
+        (Type::BIT, Type::BIT_ARRAY),
+        (Type::VARBIT, Type::VARBIT_ARRAY),
+        (Type::BOOL, Type::BOOL_ARRAY),
+        (Type::MONEY, Type::MONEY_ARRAY),
+        (Type::JSON, Type::JSON_ARRAY),
+        (Type::JSONB, Type::JSONB_ARRAY),
+        (Type::UUID, Type::UUID_ARRAY),
+        (Type::CIDR, Type::CIDR_ARRAY),
+        (Type::INET, Type::INET_ARRAY),
+        (Type::MACADDR, Type::MACADDR_ARRAY),
+    ]
+    .into_iter()
+    .find_map(|(ty, array)| (ty == *elem).then_some(array))
+    .unwrap_or(Type::UNKNOWN)
+}
+
@@ -77,6 +77,12 @@ impl From<DbErr> for TryGetError {
 
 // QueryResult //
 
+impl From<Row> for QueryResult {
+    fn from(row: Row) -> Self {
+        Self { row }
+    }
+}
+
 impl QueryResult {
     /// Get a value from the query result with an RowIndex
     pub fn try_get_by<T, I>(&self, index: I) -> Result<T, DbErr>
@@ -103,6 +109,21 @@ impl QueryResult {
         Ok(T::try_get_by_index(self, idx)?)
     }
 
+    /// Decode the composite column with prefixed name into `T` through its
+    /// [`FromSql`] implementation, such as one derived with `postgres-types`
+    /// for a struct mirroring the composite type
+    pub fn try_get_composite<T>(&self, pre: &str, col: &str) -> Result<T, DbErr>
+    where
+        T: for<'a> FromSql<'a>,
+    {
+        let value = if pre.is_empty() {
+            self.row.try_get(col)
+        } else {
+            self.row.try_get(format!("{pre}{col}").as_str())
+        };
+        Ok(value.map_err(TryGetError::postgres)?)
+    }
+
     /// Get a tuple value from the query result with prefixed column name
     pub fn try_get_many<T>(&self, pre: &str, cols: &[String]) -> Result<T, DbErr>
     where
@@ -127,6 +148,210 @@ impl QueryResult {
             .map(|c| c.name().to_string())
             .collect()
     }
+
+    /// Retrieves the Postgres types of the columns in the result set, in the same order as
+    /// [`QueryResult::column_names`]
+    pub fn column_types(&self) -> Vec<Type> {
+        self.row
+            .columns()
+            .iter()
+            .map(|c| c.type_().clone())
+            .collect()
+    }
+
+    /// Whether the column with prefixed name holds `NULL`, whatever its type
+    pub fn is_null(&self, pre: &str, col: &str) -> Result<bool, DbErr> {
+        let value: Option<AnyValue> = if pre.is_empty() {
+            self.row.try_get(col)?
+        } else {
+            self.row.try_get(format!("{pre}{col}").as_str())?
+        };
+        Ok(value.is_none())
+    }
+
+    /// Read the `json`/`jsonb` column `col` and deserialize the value at `path` inside it into
+    /// `T`. Each segment of `path` is an object key or, within an array, an index. A missing
+    /// path or column `NULL` reads as JSON `null`, so decode into an `Option` to allow it.
+    #[cfg(feature = "with-json")]
+    pub fn try_get_json_path<T>(&self, col: &str, path: &[&str]) -> Result<T, DbErr>
+    where
+        T: serde::de::DeserializeOwned,
+    {
+        let value: Option<serde_json::Value> = self.try_get("", col)?;
+        let mut value = value.unwrap_or_default();
+        for segment in path {
+            value = match value {
+                serde_json::Value::Object(mut map) => map.remove(*segment),
+                serde_json::Value::Array(mut array) => segment
+                    .parse::<usize>()
+                    .ok()
+                    .filter(|i| *i < array.len())
+                    .map(|i| array.swap_remove(i)),
+                _ => None,
+            }
+            .unwrap_or_default();
+        }
+        serde_json::from_value(value)
+            .map_err(|e| json_err(format!("Failed to decode {col} at {path:?}: {e}")))
+    }
+
+    /// Read every column by name into a JSON object, picking how to decode each one
+    /// from the type reported by the driver. A later column overwrites an earlier one
+    /// of the same name.
+    ///
+    /// Enums are read as their label, `bytea` as base64, `timestamptz` as RFC 3339 and arrays
+    /// element by element. `inet`, `cidr` and `macaddr` are formatted as Postgres prints them.
+    /// Any other type, such as `interval`, `money` or `point`, fails with an error naming it;
+    /// cast such a column to `text` in the query to read it.
+    #[cfg(feature = "with-json")]
+    pub fn into_json(self) -> Result<serde_json::Value, DbErr> {
+        let mut map = serde_json::Map::new();
+        for (idx, column) in self.row.columns().iter().enumerate() {
+            let value = self
+                .row
+                .try_get::<_, Option<JsonCell>>(idx)?
+                .map(|cell| cell.0)
+                .unwrap_or_default();
+            map.insert(column.name().to_owned(), value);
+        }
+        Ok(serde_json::Value::Object(map))
+    }
+}
+
+/// A column of any type decoded into JSON, see [`QueryResult::into_json`]
+#[cfg(feature = "with-json")]
+struct JsonCell(serde_json::Value);
+
+#[cfg(feature = "with-json")]
+impl<'a> FromSql<'a> for JsonCell {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        let value: Value = match *ty {
+            Type::BOOL => bool::from_sql(ty, raw)?.into(),
+            Type::INT2 => i16::from_sql(ty, raw)?.into(),
+            Type::INT4 => i32::from_sql(ty, raw)?.into(),
+            Type::INT8 => i64::from_sql(ty, raw)?.into(),
+            Type::FLOAT4 => f32::from_sql(ty, raw)?.into(),
+            Type::FLOAT8 => f64::from_sql(ty, raw)?.into(),
+            Type::TEXT | Type::VARCHAR | Type::BPCHAR | Type::NAME | Type::XML => {
+                String::from_sql(ty, raw)?.into()
+            }
+            Type::JSON | Type::JSONB => return serde_json::Value::from_sql(ty, raw).map(Self),
+            Type::BYTEA => Vec::<u8>::from_sql(ty, raw)?.into(),
+            Type::INET | Type::CIDR => format_inet(ty, raw)?.into(),
+            Type::MACADDR | Type::MACADDR8 => raw
+                .iter()
+                .map(|byte| format!("{byte:02x}"))
+                .collect::<Vec<_>>()
+                .join(":")
+                .into(),
+            #[cfg(feature = "with-chrono")]
+            Type::DATE => chrono::NaiveDate::from_sql(ty, raw)?.into(),
+            #[cfg(feature = "with-chrono")]
+            Type::TIME => chrono::NaiveTime::from_sql(ty, raw)?.into(),
+            #[cfg(feature = "with-chrono")]
+            Type::TIMESTAMP => chrono::NaiveDateTime::from_sql(ty, raw)?.into(),
+            #[cfg(feature = "with-chrono")]
+            Type::TIMESTAMPTZ => {
+                let value = chrono::DateTime::<chrono::Utc>::from_sql(ty, raw)?;
+                return Ok(Self(value.to_rfc3339().into()));
+            }
+            #[cfg(feature = "with-rust_decimal")]
+            Type::NUMERIC => Decimal::from_sql(ty, raw)?.into(),
+            #[cfg(feature = "with-uuid")]
+            Type::UUID => uuid::Uuid::from_sql(ty, raw)?.into(),
+            _ => match ty.kind() {
+                Kind::Array(member) => return array_to_json(member, raw).map(Self),
+                Kind::Domain(inner) => return Self::from_sql(inner, raw),
+                Kind::Enum(_) => EnumText::from_sql(ty, raw)?.0.into(),
+                _ => return Err(format!("Cannot read type {ty} into JSON, cast it to text").into()),
+            },
+        };
+        Ok(Self(pgorm_query::sea_value_to_json_value(&value)))
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+}
+
+/// Decode the binary value of an array of `member`, nesting one JSON array per dimension
+#[cfg(feature = "with-json")]
+fn array_to_json(
+    member: &Type,
+    mut raw: &[u8],
+) -> Result<serde_json::Value, Box<dyn Error + Sync + Send>> {
+    fn read_i32(raw: &mut &[u8]) -> Result<i32, Box<dyn Error + Sync + Send>> {
+        let Some((bytes, rest)) = raw.split_first_chunk::<4>() else {
+            return Err("array value is truncated".into());
+        };
+        *raw = rest;
+        Ok(i32::from_be_bytes(*bytes))
+    }
+
+    let ndim = read_i32(&mut raw)?;
+    // The null flag and the element type are not needed to read the elements
+    read_i32(&mut raw)?;
+    read_i32(&mut raw)?;
+    let mut dims = Vec::new();
+    for _ in 0..ndim {
+        dims.push(usize::try_from(read_i32(&mut raw)?)?);
+        read_i32(&mut raw)?;
+    }
+
+    let count = if dims.is_empty() {
+        0
+    } else {
+        dims.iter().product()
+    };
+    let mut items = Vec::with_capacity(count);
+    for _ in 0..count {
+        let len = read_i32(&mut raw)?;
+        if len < 0 {
+            items.push(serde_json::Value::Null);
+            continue;
+        }
+        let len = usize::try_from(len)?;
+        if raw.len() < len {
+            return Err("array value is truncated".into());
+        }
+        let (value, rest) = raw.split_at(len);
+        raw = rest;
+        items.push(JsonCell::from_sql(member, value)?.0);
+    }
+
+    // Group the innermost dimension first, up to the outermost
+    for dim in dims.iter().skip(1).rev() {
+        items = items
+            .chunks(*dim)
+            .map(|chunk| serde_json::Value::Array(chunk.to_vec()))
+            .collect();
+    }
+    Ok(serde_json::Value::Array(items))
+}
+
+/// Format the binary value of an `inet` or `cidr` the way Postgres prints it
+#[cfg(feature = "with-json")]
+fn format_inet(ty: &Type, raw: &[u8]) -> Result<String, Box<dyn Error + Sync + Send>> {
+    use std::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+
+    let [_, bits, _, len, addr @ ..] = raw else {
+        return Err(format!("invalid {ty} value").into());
+    };
+    let addr = match (*len, addr) {
+        (4, &[a, b, c, d]) => IpAddr::V4(Ipv4Addr::new(a, b, c, d)),
+        (16, addr) if addr.len() == 16 => {
+            let mut octets = [0; 16];
+            octets.copy_from_slice(addr);
+            IpAddr::V6(Ipv6Addr::from(octets))
+        }
+        _ => return Err(format!("invalid {ty} value").into()),
+    };
+    let full = if addr.is_ipv4() { 32 } else { 128 };
+    Ok(if *ty == Type::INET && *bits == full {
+        addr.to_string()
+    } else {
+        format!("{addr}/{bits}")
+    })
 }
 
 // TryGetable //
@@ -182,7 +407,6 @@ try_getable_all!(i32);
 try_getable_all!(i64);
 try_getable_all!(f32);
 try_getable_all!(f64);
-try_getable_all!(String);
 try_getable_all!(Vec<u8>);
 
 #[cfg(feature = "with-json")]
@@ -235,13 +459,53 @@ impl TryGetable for Decimal {
 
 #[cfg(feature = "with-bigdecimal")]
 use bigdecimal::BigDecimal;
-use pgorm_query::Values;
+use pgorm_query::{Value, Values};
 use tokio_postgres::{
     Row,
     row::RowIndex,
-    types::{Json, Oid, WasNull},
+    types::{FromSql, Json, Kind, Oid, Type, WasNull},
 };
 
+/// Text of a column, also accepting native Postgres enums, whose labels the driver
+/// refuses to decode into a `String` directly
+struct EnumText(String);
+
+impl<'a> FromSql<'a> for EnumText {
+    fn from_sql(ty: &Type, raw: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        match ty.kind() {
+            Kind::Enum(_) => Ok(Self(std::str::from_utf8(raw)?.to_owned())),
+            _ => String::from_sql(ty, raw).map(Self),
+        }
+    }
+
+    fn accepts(ty: &Type) -> bool {
+        matches!(ty.kind(), Kind::Enum(_)) || <String as FromSql>::accepts(ty)
+    }
+}
+
+/// A column of any type, decoded only far enough to tell whether it is `NULL`
+struct AnyValue;
+
+impl<'a> FromSql<'a> for AnyValue {
+    fn from_sql(_: &Type, _: &'a [u8]) -> Result<Self, Box<dyn Error + Sync + Send>> {
+        Ok(Self)
+    }
+
+    fn accepts(_: &Type) -> bool {
+        true
+    }
+}
+
+impl TryGetable for String {
+    fn try_get_by<I: RowIndex + std::fmt::Display>(
+        res: &QueryResult,
+        idx: I,
+    ) -> Result<Self, TryGetError> {
+        let result: EnumText = res.row.try_get(idx).map_err(TryGetError::postgres)?;
+        Ok(result.0)
+    }
+}
+
 #[cfg(feature = "with-bigdecimal")]
 impl TryGetable for BigDecimal {
     #[allow(unused_variables)]
@@ -332,7 +596,16 @@ mod postgres_array {
     try_getable_postgres_array!(i64);
     try_getable_postgres_array!(f32);
     try_getable_postgres_array!(f64);
-    try_getable_postgres_array!(String);
+
+    impl TryGetable for Vec<String> {
+        fn try_get_by<I: RowIndex + std::fmt::Display>(
+            res: &QueryResult,
+            idx: I,
+        ) -> Result<Self, TryGetError> {
+            let result: Vec<EnumText> = res.row.try_get(idx).map_err(TryGetError::postgres)?;
+            Ok(result.into_iter().map(|text| text.0).collect())
+        }
+    }
 
     #[cfg(feature = "with-json")]
     try_getable_postgres_array!(serde_json::Value);
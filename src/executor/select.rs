@@ -8,9 +8,6 @@ use pgorm_query::{QueryBuilder, SelectStatement, Value, Values};
 use std::collections::HashMap;
 use std::{hash::Hash, marker::PhantomData};
 use tokio_postgres::ToStatement;
-use tokio_postgres::types::ToSql;
-
-use super::ValueHolder;
 
 /// Defines a type to do `SELECT` operations through a [SelectStatement] on a Model
 #[derive(Clone, Debug)]
@@ -63,6 +60,11 @@ where
     model: PhantomData<T>,
 }
 
+/// Get each row as a JSON object keyed by column name
+#[cfg(feature = "with-json")]
+#[derive(Debug)]
+pub struct SelectGetableJson;
+
 /// Defines a type to get a Model
 #[derive(Debug)]
 pub struct SelectModel<M>
@@ -106,6 +108,15 @@ where
     }
 }
 
+#[cfg(feature = "with-json")]
+impl SelectorTrait for SelectGetableJson {
+    type Item = serde_json::Value;
+
+    fn from_raw_query_result(res: QueryResult) -> Result<Self::Item, DbErr> {
+        res.into_json()
+    }
+}
+
 impl<M> SelectorTrait for SelectModel<M>
 where
     M: FromQueryResult + Sized,
@@ -409,6 +420,16 @@ where
         Selector::<SelectGetableTuple<T>>::into_tuple(self.query)
     }
 
+    /// Get each row as a JSON object, keyed by the selected columns and their aliases.
+    /// See [`QueryResult::into_json`] for how each column is decoded.
+    #[cfg(feature = "with-json")]
+    pub fn into_json(self) -> Selector<SelectGetableJson> {
+        Selector {
+            query: self.query,
+            selector: SelectGetableJson,
+        }
+    }
+
     /// Get one Model from the SELECT query
     pub async fn one<'a, C>(self, db: &C) -> Result<E::Model, DbErr>
     where
@@ -856,14 +877,7 @@ where
     where
         C: ConnectionTrait,
     {
-        let values = self
-            .values
-            .0
-            .into_iter()
-            .map(|x| ValueHolder(x))
-            .collect::<Vec<_>>();
-        let values = values.iter().map(|x| x as _).collect::<Vec<_>>();
-        let row = db.query_opt(&self.stmt, &values).await?;
+        let row = db.query_opt_values(&self.stmt, self.values).await?;
         match row {
             Some(row) => Ok(S::from_raw_query_result(QueryResult { row })?),
             None => Err(DbErr::RecordNotFound),
@@ -914,14 +928,7 @@ where
     where
         C: ConnectionTrait,
     {
-        let values = self
-            .values
-            .0
-            .into_iter()
-            .map(|x| ValueHolder(x))
-            .collect::<Vec<_>>();
-        let values = values.iter().map(|x| x as _).collect::<Vec<_>>();
-        let row = db.query_opt(&self.stmt, &values).await?;
+        let row = db.query_opt_values(&self.stmt, self.values).await?;
         match row {
             Some(row) => Ok(Some(S::from_raw_query_result(QueryResult { row })?)),
             None => Ok(None),
@@ -973,14 +980,7 @@ where
         C: ConnectionTrait,
     {
         // tracing::warn!("Querying all");
-        let values = self
-            .values
-            .0
-            .into_iter()
-            .map(|x| ValueHolder(x))
-            .collect::<Vec<_>>();
-        let values = values.iter().map(|x| x as _).collect::<Vec<_>>();
-        let rows = db.query_all(&self.stmt, &values).await?;
+        let rows = db.query_all_values(&self.stmt, self.values).await?;
         // tracing::warn!("Got rows!");
         let mut models = Vec::new();
         for row in rows.into_iter() {
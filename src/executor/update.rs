@@ -3,9 +3,6 @@ use crate::{
     PrimaryKeyTrait, SelectModel, SelectorRaw, UpdateMany, UpdateOne, error::*,
 };
 use pgorm_query::{FromValueTuple, Query, QueryBuilder, UpdateStatement};
-use tokio_postgres::types::ToSql;
-
-use super::ValueHolder;
 
 /// Defines an update operation
 #[derive(Clone, Debug)]
@@ -21,12 +18,15 @@ pub struct UpdateResult {
     pub rows_affected: u64,
 }
 
-impl<'a, A: 'a> UpdateOne<A>
+impl<A> UpdateOne<A>
 where
     A: ActiveModelTrait,
 {
     /// Execute an update operation on an ActiveModel
-    pub async fn exec<'b, C>(self, db: &'b C) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
+    ///
+    /// The row is read back with `RETURNING`, so the model reflects values computed
+    /// by the database, such as those set by a trigger.
+    pub async fn exec<C>(self, db: &C) -> Result<<A::Entity as EntityTrait>::Model, DbErr>
     where
         <A::Entity as EntityTrait>::Model: IntoActiveModel<A>,
         C: ConnectionTrait,
@@ -88,13 +88,8 @@ impl Updater {
             return Ok(UpdateResult::default());
         }
         let (stmt, values) = self.query.build(QueryBuilder);
-        let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
-        let values = values
-            .iter()
-            .map(|x| &*x as _)
-            .collect::<Vec<&(dyn ToSql + Sync)>>();
 
-        let result = db.execute(&stmt, &values).await?;
+        let result = db.execute_values(&stmt, values).await?;
         if self.check_record_exists && result == 0 {
             return Err(DbErr::RecordNotUpdated);
         }
@@ -9,6 +9,9 @@
 )]
 
 mod database;
+/// Development-time helpers, such as index suggestions from `EXPLAIN`
+#[cfg(feature = "index-advisor")]
+mod dev;
 mod docs;
 /// Module for the Entity type and operations
 pub mod entity;
@@ -28,6 +31,8 @@ pub mod schema;
 mod util;
 
 pub use database::*;
+#[cfg(feature = "index-advisor")]
+pub use dev::*;
 pub use entity::*;
 pub use error::*;
 pub use executor::*;
@@ -285,6 +285,9 @@ pub trait QuerySelect: Sized {
     }
 
     /// Add an AND HAVING expression
+    ///
+    /// A [`group_by`](QuerySelect::group_by) isn't required: without one, an aggregate-only
+    /// select is treated as a single group, which the condition keeps or drops as a whole.
     /// ```
     /// use pgorm::{pgorm_query::{Alias, Expr}, entity::*, query::*, tests_cfg::cake, DbBackend};
     ///
@@ -318,6 +321,9 @@ pub trait QuerySelect: Sized {
     }
 
     /// Add a DISTINCT expression
+    ///
+    /// `distinct` and [`distinct_on`](QuerySelect::distinct_on) set the same clause,
+    /// so whichever is called last takes effect.
     /// ```
     /// use pgorm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     /// struct Input {
@@ -344,6 +350,9 @@ pub trait QuerySelect: Sized {
 
     /// Add a DISTINCT ON expression
     /// NOTE: this function is only supported by `sqlx-postgres`
+    ///
+    /// Replaces any previous [`distinct`](QuerySelect::distinct); passing no columns
+    /// removes the DISTINCT clause altogether.
     /// ```
     /// use pgorm::{entity::*, query::*, tests_cfg::cake, DbBackend};
     /// struct Input {
@@ -922,7 +931,9 @@ pub(crate) fn unpack_table_ref(table_ref: &TableRef) -> DynIden {
         | TableRef::DatabaseSchemaTableAlias(_, _, tbl, _)
         | TableRef::SubQuery(_, tbl)
         | TableRef::ValuesList(_, tbl)
+        | TableRef::ValuesExprList(_, tbl)
         | TableRef::FunctionCall(_, tbl) => SeaRc::clone(tbl),
+        TableRef::Only(table_ref) => unpack_table_ref(table_ref),
     }
 }
 
@@ -932,10 +943,12 @@ pub(crate) fn unpack_table_alias(table_ref: &TableRef) -> Option<DynIden> {
         | TableRef::SchemaTable(_, _)
         | TableRef::DatabaseSchemaTable(_, _, _)
         | TableRef::SubQuery(_, _)
-        | TableRef::ValuesList(_, _) => None,
+        | TableRef::ValuesList(_, _)
+        | TableRef::ValuesExprList(_, _) => None,
         TableRef::TableAlias(_, alias)
         | TableRef::SchemaTableAlias(_, _, alias)
         | TableRef::DatabaseSchemaTableAlias(_, _, _, alias)
         | TableRef::FunctionCall(_, alias) => Some(SeaRc::clone(alias)),
+        TableRef::Only(table_ref) => unpack_table_alias(table_ref),
     }
 }
@@ -1,9 +1,9 @@
 use crate::{
-    ActiveModelTrait, ActiveValue, ColumnTrait, EntityName, EntityTrait, IntoActiveModel, Iterable,
-    PrimaryKeyTrait, QueryTrait,
+    ActiveModelTrait, ActiveValue, ColumnTrait, DbErr, EntityName, EntityTrait, IdenStatic,
+    IntoActiveModel, Iterable, PrimaryKeyToColumn, PrimaryKeyTrait, QueryTrait,
 };
 use core::marker::PhantomData;
-use pgorm_query::{Expr, InsertStatement, OnConflict, ValueTuple};
+use pgorm_query::{Expr, InsertStatement, OnConflict, SimpleExpr, ValueTuple};
 
 /// Performs INSERT operations on a ActiveModel
 #[derive(Debug)]
@@ -13,7 +13,9 @@ where
 {
     pub(crate) query: InsertStatement,
     pub(crate) columns: Vec<bool>,
+    pub(crate) rows: Vec<Vec<bool>>,
     pub(crate) primary_key: Option<ValueTuple>,
+    pub(crate) returning: Vec<SimpleExpr>,
     pub(crate) model: PhantomData<A>,
 }
 
@@ -37,7 +39,9 @@ where
                 .or_default_values()
                 .to_owned(),
             columns: Vec::new(),
+            rows: Vec::new(),
             primary_key: None,
+            returning: Vec::new(),
             model: PhantomData,
         }
     }
@@ -110,6 +114,9 @@ where
 
     /// Add a Model to Self
     ///
+    /// Columns are listed in declaration order, so models with the same columns set
+    /// always produce the same SQL and can share a cached prepared statement.
+    ///
     /// # Panics
     ///
     /// Panics if the column value has discrepancy across rows
@@ -127,10 +134,17 @@ where
             };
         let mut columns = Vec::new();
         let mut values = Vec::new();
+        let mut row = Vec::new();
         let columns_empty = self.columns.is_empty();
         for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
             let av = am.take(col);
+            // a generated column can't be written
+            let av = match col.def().is_generated() {
+                true => ActiveValue::NotSet,
+                false => av,
+            };
             let av_has_val = av.is_set() || av.is_unchanged();
+            row.push(av_has_val);
             if columns_empty {
                 self.columns.push(av_has_val);
             } else if self.columns[idx] != av_has_val {
@@ -146,6 +160,7 @@ where
         }
         self.query.columns(columns);
         self.query.values_panic(values);
+        self.rows.push(row);
         self
     }
 
@@ -161,8 +176,46 @@ where
         self
     }
 
+    /// Check that every column which is neither nullable nor has a default is set on each
+    /// row, returning [`DbErr::Validation`] for the first one that is missing.
+    ///
+    /// An auto-increment primary key and generated columns may be left unset. Only the
+    /// [`ColumnDef`](crate::ColumnDef) of the entity is consulted, so defaults declared solely
+    /// in the database are not known here.
+    pub fn validate(self) -> Result<Self, DbErr> {
+        let auto_increment =
+            <<A::Entity as EntityTrait>::PrimaryKey as PrimaryKeyTrait>::auto_increment();
+        let required: Vec<_> = <A::Entity as EntityTrait>::Column::iter()
+            .enumerate()
+            .filter(|(_, col)| {
+                let def = col.def();
+                let optional = def.is_null()
+                    || def.default.is_some()
+                    || def.is_generated()
+                    || (auto_increment
+                        && <A::Entity as EntityTrait>::PrimaryKey::from_column(*col).is_some());
+                !optional
+            })
+            .collect();
+        for (row_idx, row) in self.rows.iter().enumerate() {
+            for (idx, col) in required.iter() {
+                if !row.get(*idx).copied().unwrap_or(false) {
+                    return Err(DbErr::Validation(format!(
+                        "Column `{}` is required but not set on row {row_idx}",
+                        col.as_str()
+                    )));
+                }
+            }
+        }
+        Ok(self)
+    }
+
     /// On conflict
     ///
+    /// Conflict targets on a partial unique index can carry the index predicate
+    /// through [`OnConflict::target_and_where`], and the update can be guarded
+    /// with [`OnConflict::action_and_where`].
+    ///
     /// on conflict do nothing
     /// ```
     /// use pgorm::{entity::*, query::*, pgorm_query::OnConflict, tests_cfg::cake, DbBackend};
@@ -209,6 +262,54 @@ where
         self
     }
 
+    /// Set ON CONFLICT on the first `unique` column of the entity, so the target need
+    /// not be repeated. Without a `unique` column, the primary key is the target. On
+    /// conflict, the other inserted columns except the primary key are updated from the
+    /// proposed row; with none left, the row is left alone and [`Insert::exec`] returns
+    /// [`DbErr::RecordNotInserted`] for it.
+    ///
+    /// Call it after adding the models, as the update is built from their columns.
+    pub fn on_conflict_auto(mut self) -> Self {
+        let mut unique = None;
+        let mut update = Vec::new();
+        for (idx, col) in <A::Entity as EntityTrait>::Column::iter().enumerate() {
+            if unique.is_none() && col.def().unique {
+                unique = Some(col);
+            } else if self.columns.get(idx).copied().unwrap_or(false)
+                && <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_none()
+            {
+                update.push(col);
+            }
+        }
+
+        let mut on_conflict = match unique {
+            Some(col) => OnConflict::column(col),
+            None => OnConflict::columns(
+                <A::Entity as EntityTrait>::PrimaryKey::iter().map(|key| key.into_column()),
+            ),
+        };
+        if update.is_empty() {
+            on_conflict.do_nothing();
+        } else {
+            on_conflict.update_columns(update);
+        }
+        self.query.on_conflict(on_conflict);
+        self
+    }
+
+    /// Add a computed expression to the `RETURNING` clause, after the entity's columns.
+    ///
+    /// The expressions are only sent by [`Insert::exec_with_returning_as`], which decodes
+    /// the returned row into any [`FromQueryResult`](crate::FromQueryResult) type. On an
+    /// upsert, `Expr::cust("(xmax = 0) AS inserted")` tells a fresh row from an updated one.
+    pub fn returning_expr<T>(mut self, expr: T) -> Self
+    where
+        T: Into<SimpleExpr>,
+    {
+        self.returning.push(expr.into());
+        self
+    }
+
     /// Allow insert statement return safely if inserting nothing.
     /// The database will not be affected.
     pub fn do_nothing(self) -> TryInsert<A>
@@ -389,6 +490,33 @@ where
         self.insert_struct.query
     }
 }
+
+/// Inserts Models or ActiveModels one row at a time, so that a row violating a constraint is
+/// skipped instead of aborting the whole batch, see [`EntityTrait::insert_many_lenient`].
+#[derive(Debug)]
+pub struct InsertManyLenient<A>
+where
+    A: ActiveModelTrait,
+{
+    pub(crate) inserts: Vec<Insert<A>>,
+}
+
+impl<A> InsertManyLenient<A>
+where
+    A: ActiveModelTrait,
+{
+    /// Insert many Model or ActiveModel, each row with its own statement
+    pub fn many<M, I>(models: I) -> Self
+    where
+        M: IntoActiveModel<A>,
+        I: IntoIterator<Item = M>,
+    {
+        Self {
+            inserts: models.into_iter().map(Insert::one).collect(),
+        }
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use pgorm_query::OnConflict;
@@ -3,7 +3,9 @@ use crate::{
     SelectB, SelectTwo, SelectTwoMany, join_tbl_on_condition, unpack_table_ref,
 };
 pub use pgorm_query::JoinType;
-use pgorm_query::{Alias, Condition, Expr, IntoIden, SeaRc, SelectExpr};
+use pgorm_query::{
+    Alias, Condition, Expr, IntoCondition, IntoIden, SeaRc, SelectExpr, SelectStatement,
+};
 
 impl<E> Select<E>
 where
@@ -44,6 +46,23 @@ where
         self.join_rev(JoinType::InnerJoin, R::to())
     }
 
+    /// Join a subquery under `alias`, `JOIN (SELECT ..) AS alias ON condition`, such as an
+    /// aggregate over another table. Refer to its columns through `alias`.
+    pub fn join_subquery<T, C>(
+        mut self,
+        join: JoinType,
+        query: SelectStatement,
+        alias: T,
+        condition: C,
+    ) -> Self
+    where
+        T: IntoIden,
+        C: IntoCondition,
+    {
+        self.query.join_subquery(join, query, alias, condition);
+        self
+    }
+
     /// Left Join with a Related Entity and select both Entity.
     pub fn find_also_related<R>(self, r: R) -> SelectTwo<E, R>
     where
@@ -434,6 +434,9 @@ fn table_column(tbl: &TableRef, col: &DynIden) -> ColumnRef {
     match tbl.to_owned() {
         TableRef::Table(tbl) => (tbl, col.clone()).into_column_ref(),
         TableRef::SchemaTable(sch, tbl) => (sch, tbl, col.clone()).into_column_ref(),
+        // the loader selects from the table itself, not the alias of a self-referencing relation
+        TableRef::TableAlias(tbl, _) => (tbl, col.clone()).into_column_ref(),
+        TableRef::SchemaTableAlias(sch, tbl, _) => (sch, tbl, col.clone()).into_column_ref(),
         val => unimplemented!("Unsupported TableRef {val:?}"),
     }
 }
@@ -1,7 +1,14 @@
-use crate::{ColumnTrait, EntityTrait, Iterable, QueryFilter, QueryOrder, QuerySelect, QueryTrait};
+use crate::{
+    ColumnTrait, EntityTrait, IdenStatic, Iterable, QueryFilter, QueryOrder, QuerySelect,
+    QueryTrait, RelationDef, SelectModel, SelectorRaw, join_condition,
+};
 use core::fmt::Debug;
 use core::marker::PhantomData;
-use pgorm_query::{Expr, IntoColumnRef, SelectStatement, SimpleExpr};
+use pgorm_query::{
+    Alias, Asterisk, ColumnRef, CommonTableExpression, Expr, Func, IntoColumnRef, IntoIden,
+    JoinType, NullOrdering, Order, Query, QueryBuilder, SampleMethod, SeaRc, SelectStatement,
+    SimpleExpr, TableRef, UnionType, Value, Values, WithClause,
+};
 
 /// Defines a structure to perform select operations
 #[derive(Clone, Debug)]
@@ -124,7 +131,15 @@ where
     }
 
     fn column_list(&self) -> Vec<SimpleExpr> {
+        self.column_list_where(|_| true)
+    }
+
+    fn column_list_where<F>(&self, keep: F) -> Vec<SimpleExpr>
+    where
+        F: Fn(&E::Column) -> bool,
+    {
         E::Column::iter()
+            .filter(keep)
             .map(|col| col.select_as(col.into_expr()))
             .collect()
     }
@@ -133,6 +148,169 @@ where
         self.query.from(E::default().table_ref());
         self
     }
+
+    /// Select only the listed columns of the Entity, in their declared order, replacing the
+    /// select list. Read the rows with [`into_json`](Select::into_json) or a partial model,
+    /// since the Model needs every column.
+    pub fn only_columns<I>(mut self, cols: I) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+    {
+        let cols: Vec<_> = cols.into_iter().collect();
+        let exprs = self.column_list_where(|col| cols.iter().any(|c| c.as_str() == col.as_str()));
+        self.query.clear_selects().exprs(exprs);
+        self
+    }
+
+    /// Select every column of the Entity except the listed ones, replacing the select list,
+    /// such as to leave out a large column that was not asked for, see
+    /// [`only_columns`](Select::only_columns).
+    pub fn except_columns<I>(mut self, cols: I) -> Self
+    where
+        I: IntoIterator<Item = E::Column>,
+    {
+        let cols: Vec<_> = cols.into_iter().collect();
+        let exprs = self.column_list_where(|col| cols.iter().all(|c| c.as_str() != col.as_str()));
+        self.query.clear_selects().exprs(exprs);
+        self
+    }
+
+    /// Add a group by expression, such as a function of a column. The same as
+    /// [`group_by`](QuerySelect::group_by), for an expression that is already built.
+    /// Select the same expression so each group's key is returned with it.
+    pub fn group_by_expr(mut self, expr: SimpleExpr) -> Self {
+        self.query.add_group_by([expr]);
+        self
+    }
+
+    /// Swap `ASC` and `DESC` on every order by expression, see
+    /// [`SelectStatement::reverse_order`]. Paired with a keyset condition, this fetches the
+    /// page before the current one, whose rows then come back reversed.
+    pub fn reverse_order(mut self) -> Self {
+        self.query.reverse_order();
+        self
+    }
+
+    /// Scan about `percentage` percent of the entity's table with `TABLESAMPLE SYSTEM`,
+    /// which picks whole pages of rows. `WHERE` conditions and `LIMIT` apply to the sample.
+    pub fn tablesample_system(mut self, percentage: f64) -> Self {
+        self.query.table_sample(SampleMethod::System, percentage);
+        self
+    }
+
+    /// Scan about `percentage` percent of the entity's table with `TABLESAMPLE BERNOULLI`,
+    /// which picks each row independently, see [`Select::tablesample_system`]
+    pub fn tablesample_bernoulli(mut self, percentage: f64) -> Self {
+        self.query.table_sample(SampleMethod::Bernoulli, percentage);
+        self
+    }
+
+    /// Order by an arbitrary expression, such as a function of a column
+    pub fn order_by_expr(mut self, expr: SimpleExpr, ord: Order) -> Self {
+        self.query.order_by_expr(expr, ord);
+        self
+    }
+
+    /// Order by an arbitrary expression, placing NULLs first or last
+    pub fn order_by_expr_with_nulls(
+        mut self,
+        expr: SimpleExpr,
+        ord: Order,
+        nulls: NullOrdering,
+    ) -> Self {
+        self.query.order_by_expr_with_nulls(expr, ord, nulls);
+        self
+    }
+
+    /// Order the rows by where the value of `col` appears in `values`, with rows whose value
+    /// is not listed last. Rendered as a `CASE` expression mapping each value to its position.
+    pub fn order_by_field<C, I, V>(mut self, col: C, values: I) -> Self
+    where
+        C: ColumnTrait,
+        I: IntoIterator<Item = V>,
+        V: Into<Value>,
+    {
+        let values = Values(values.into_iter().map(Into::into).collect());
+        self.query
+            .order_by_expr(col.into_simple_expr(), Order::Field(values));
+        self
+    }
+
+    /// Keep the groups with more than `n` rows, `HAVING COUNT(*) > n`
+    pub fn having_count_gt(self, n: i64) -> Self {
+        self.having(Expr::expr(Expr::col(Asterisk).count()).gt(n))
+    }
+
+    /// Keep the groups with fewer than `n` rows, `HAVING COUNT(*) < n`
+    pub fn having_count_lt(self, n: i64) -> Self {
+        self.having(Expr::expr(Expr::col(Asterisk).count()).lt(n))
+    }
+
+    /// Keep the groups where `col` sums to more than `v`, `HAVING SUM(col) > v`
+    ///
+    /// `v` must have the type of the sum, which Postgres widens from the column's: `i64` for a
+    /// `smallint` or `integer` column, `Decimal` for a `bigint` or `numeric` one.
+    pub fn having_sum_gt<C, V>(self, col: C, v: V) -> Self
+    where
+        C: ColumnTrait,
+        V: Into<Value>,
+    {
+        self.having(Expr::expr(Func::sum(col.into_expr())).gt(v))
+    }
+
+    /// Walk a relation of the Entity to itself with a `WITH RECURSIVE` query. The walk starts
+    /// from the rows this query selects and repeatedly adds the rows that `rel` relates to a row
+    /// already found, so a `belongs_to` relation to the parent finds the descendants of the
+    /// roots, and a `has_many` relation to the children finds their ancestors.
+    ///
+    /// Rows are returned depth first, each row followed by its subtree. A row reached again
+    /// through a cycle in the data is dropped instead of being walked forever.
+    pub fn with_recursive(self, rel: RelationDef) -> SelectorRaw<SelectModel<E::Model>> {
+        let tree = Alias::new(format!("{}_tree", E::default().table_name())).into_iden();
+        let order = Alias::new("tree_order").into_iden();
+        let is_cycle = Alias::new("tree_cycle").into_iden();
+        let path = Alias::new("tree_path").into_iden();
+
+        let keys: Vec<SimpleExpr> = rel
+            .to_col
+            .clone()
+            .into_iter()
+            .map(|key| SimpleExpr::Column(ColumnRef::Column(key)))
+            .collect();
+        let placeholders: Vec<String> = (1..=keys.len()).map(|i| format!("${i}")).collect();
+        let key = Expr::cust_with_exprs(placeholders.join(", "), keys);
+
+        let mut rel = rel;
+        rel.to_tbl = TableRef::Table(SeaRc::clone(&tree));
+        let step = Select::<E>::new()
+            .query
+            .join(
+                JoinType::InnerJoin,
+                SeaRc::clone(&tree),
+                join_condition(rel),
+            )
+            .to_owned();
+
+        let cte = CommonTableExpression::new()
+            .query(self.query.clone().union(UnionType::All, step).to_owned())
+            .columns(E::Column::iter())
+            .table_name(SeaRc::clone(&tree))
+            .search_depth_first(key.clone(), SeaRc::clone(&order))
+            .cycle(key, SeaRc::clone(&is_cycle), path)
+            .to_owned();
+        let with_clause = WithClause::new().recursive(true).cte(cte).to_owned();
+
+        let (sql, values) = Query::select()
+            .columns(E::Column::iter().map(|col| (SeaRc::clone(&tree), col)))
+            .from(SeaRc::clone(&tree))
+            .and_where(Expr::col((SeaRc::clone(&tree), is_cycle)).not())
+            .order_by((tree, order), Order::Asc)
+            .to_owned()
+            .with(with_clause)
+            .build(QueryBuilder);
+
+        self.from_raw_sql(sql, values)
+    }
 }
 
 impl<E> QueryTrait for Select<E>
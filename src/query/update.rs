@@ -1,9 +1,10 @@
 use crate::{
     ActiveModelTrait, ActiveValue, ColumnTrait, EntityTrait, Iterable, PrimaryKeyToColumn,
-    QueryFilter, QueryTrait,
+    QueryFilter, QueryTrait, ValueHolder,
 };
 use core::marker::PhantomData;
-use pgorm_query::{Expr, IntoIden, SimpleExpr, UpdateStatement};
+use pgorm_query::{Alias, Expr, IntoIden, SimpleExpr, TableRef, UpdateStatement, Value};
+use tokio_postgres::types::Type;
 
 /// Defines a structure to perform UPDATE query operations on a ActiveModel
 #[derive(Clone, Debug)]
@@ -83,6 +84,102 @@ impl Update {
             entity: PhantomData,
         }
     }
+
+    /// Set `col` to a different value on each row matched by `key`, in one statement
+    /// joining a `VALUES` list. Each row is given as `(key, value)`.
+    ///
+    /// The server would infer the parameters of the list as `text`, so those of the
+    /// first row are cast to the types of their values, which the other rows follow.
+    ///
+    /// ```
+    /// use pgorm::{entity::*, query::*};
+    /// # mod cake {
+    /// #     use pgorm::entity::prelude::*;
+    /// #
+    /// #     #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    /// #     #[pgorm(table_name = "cake")]
+    /// #     pub struct Model {
+    /// #         #[pgorm(primary_key)]
+    /// #         pub id: i32,
+    /// #         pub name: String,
+    /// #     }
+    /// #
+    /// #     #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    /// #     pub enum Relation {}
+    /// #
+    /// #     impl ActiveModelBehavior for ActiveModel {}
+    /// # }
+    ///
+    /// assert_eq!(
+    ///     Update::from_values(
+    ///         cake::Entity,
+    ///         cake::Column::Id,
+    ///         cake::Column::Name,
+    ///         [(1, "Apple Pie"), (2, "Orange Scone")]
+    ///     )
+    ///     .build()
+    ///     .0,
+    ///     [
+    ///         r#"UPDATE "cake" SET "name" = "v"."column2""#,
+    ///         r#"FROM (VALUES (CAST($1 AS int4), CAST($2 AS text)), ($3, $4)) AS "v""#,
+    ///         r#"WHERE "cake"."id" = "v"."column1""#,
+    ///     ]
+    ///     .join(" ")
+    /// );
+    /// ```
+    ///
+    /// Without rows the update matches no rows, even if values are set on it afterwards.
+    pub fn from_values<E, K, V, I>(
+        entity: E,
+        key: E::Column,
+        col: E::Column,
+        rows: I,
+    ) -> UpdateMany<E>
+    where
+        E: EntityTrait,
+        K: Into<Value>,
+        V: Into<Value>,
+        I: IntoIterator<Item = (K, V)>,
+    {
+        let rows: Vec<Vec<SimpleExpr>> = rows
+            .into_iter()
+            .enumerate()
+            .map(|(idx, (k, v))| {
+                [k.into(), v.into()]
+                    .into_iter()
+                    .map(|value| {
+                        let pg_type = ValueHolder(value.clone()).pg_type();
+                        match idx {
+                            0 if pg_type != Type::UNKNOWN => {
+                                Expr::val(value).cast_as(Alias::new(pg_type.name()))
+                            }
+                            _ => Expr::val(value).into(),
+                        }
+                    })
+                    .collect()
+            })
+            .collect();
+        if rows.is_empty() {
+            let mut update = Self::many(entity);
+            update.query.and_where(Expr::val(false).into());
+            return update;
+        }
+
+        let values = Alias::new("v");
+        let query = UpdateStatement::new()
+            .table(entity.table_ref())
+            .value(
+                col,
+                col.save_as(Expr::col((values.clone(), Alias::new("column2")))),
+            )
+            .from(TableRef::ValuesExprList(rows, values.clone().into_iden()))
+            .and_where(Expr::col(key.as_column_ref()).equals((values, Alias::new("column1"))))
+            .to_owned();
+        UpdateMany {
+            query,
+            entity: PhantomData,
+        }
+    }
 }
 
 impl<A> UpdateOne<A>
@@ -104,7 +201,9 @@ where
 
     fn prepare_values(mut self) -> Self {
         for col in <A::Entity as EntityTrait>::Column::iter() {
-            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some() {
+            if <A::Entity as EntityTrait>::PrimaryKey::from_column(col).is_some()
+                || col.def().is_generated()
+            {
                 continue;
             }
             match self.model.get(col) {
@@ -189,6 +288,9 @@ where
         A: ActiveModelTrait<Entity = E>,
     {
         for col in E::Column::iter() {
+            if col.def().is_generated() {
+                continue;
+            }
             match model.get(col) {
                 ActiveValue::Set(value) => {
                     let expr = col.save_as(Expr::val(value));
@@ -200,7 +302,8 @@ where
         self
     }
 
-    /// Creates a [SimpleExpr] from a column
+    /// Assign an expression to a column, e.g. `SET "count" = "count" + 1`.
+    /// The expression may refer to the current value of any column of the row.
     pub fn col_expr<T>(mut self, col: T, expr: SimpleExpr) -> Self
     where
         T: IntoIden,
@@ -214,7 +317,7 @@ where
 mod tests {
     use crate::tests_cfg::{cake, fruit, lunch_set, pgorm_active_enums::Tea};
     use crate::{DbBackend, entity::*, query::*};
-    use pgorm_query::{Expr, Value};
+    use pgorm_query::{Expr, Value, Values};
 
     #[test]
     fn update_1() {
@@ -311,7 +414,7 @@ mod tests {
                 .filter(lunch_set::Column::Tea.eq(Tea::BreakfastTea))
                 .build(DbBackend::Postgres)
                 .to_string(),
-            r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS tea) WHERE "lunch_set"."tea" = (CAST('BreakfastTea' AS tea))"#,
+            r#"UPDATE "lunch_set" SET "tea" = CAST('EverydayTea' AS tea) WHERE "lunch_set"."tea" = CAST('BreakfastTea' AS tea)"#,
         );
     }
 
@@ -87,6 +87,17 @@ impl Schema {
     {
         column_def_from_entity_column::<E>(column)
     }
+
+    /// Lists the columns of an Entity by name, in declaration order, along with
+    /// their [ColumnDef](crate::ColumnDef)
+    pub fn entity_columns<E>(&self) -> Vec<(String, crate::ColumnDef)>
+    where
+        E: EntityTrait,
+    {
+        E::Column::iter()
+            .map(|column| (column.to_string(), column.def()))
+            .collect()
+    }
 }
 
 pub(crate) fn create_enum_from_active_enum<A>() -> TypeCreateStatement
@@ -171,6 +182,24 @@ where
         stmt.primary_key(idx_pk.name(&format!("pk-{}", entity.to_string())).primary());
     }
 
+    let mut unique_keys: Vec<(String, IndexCreateStatement)> = Vec::new();
+    for column in E::Column::iter() {
+        for name in column.def().unique_keys {
+            match unique_keys.iter_mut().find(|(key, _)| *key == name) {
+                Some((_, idx)) => {
+                    idx.col(column);
+                }
+                None => {
+                    let idx = Index::create().name(&name).col(column).unique().to_owned();
+                    unique_keys.push((name, idx));
+                }
+            }
+        }
+    }
+    for (_, mut idx) in unique_keys {
+        stmt.index(&mut idx);
+    }
+
     for relation in E::Relation::iter() {
         let relation = relation.def();
         if relation.is_owner {
@@ -204,6 +233,9 @@ where
     if let Some(default) = orm_column_def.default {
         column_def.default(default);
     }
+    if let Some((expr, stored)) = orm_column_def.generated {
+        column_def.generated(expr, stored);
+    }
     if let Some(comment) = orm_column_def.comment {
         column_def.comment(comment);
     }
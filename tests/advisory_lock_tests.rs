@@ -0,0 +1,94 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ConnectionTrait, TransactionTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("advisory_lock_tests").await;
+    session_advisory_lock(&ctx.db).await?;
+    cancelled_advisory_lock(&ctx.db).await?;
+    transaction_advisory_lock(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+/// Whether another session could take the lock, releasing it straight away if so
+async fn lock_available(db: &DatabasePool, key: i64) -> Result<bool, DbErr> {
+    let conn = db.get().await?;
+    let available: bool = conn
+        .query_one("SELECT pg_try_advisory_lock($1)", &[&key])
+        .await?
+        .get(0);
+    if available {
+        conn.execute("SELECT pg_advisory_unlock($1)", &[&key])
+            .await?;
+    }
+    Ok(available)
+}
+
+pub async fn session_advisory_lock(db: &DatabasePool) -> Result<(), DbErr> {
+    let lock = db.advisory_lock(2500).await?;
+    assert_eq!(lock.key(), 2500);
+    assert!(!lock_available(db, 2500).await?);
+
+    let one: i32 = lock.query_one("SELECT 1", &[]).await?.get(0);
+    assert_eq!(one, 1);
+
+    lock.unlock().await?;
+    assert!(lock_available(db, 2500).await?);
+
+    // Dropping the guard releases the lock in the background
+    let lock = db.advisory_lock(2500).await?;
+    assert!(!lock_available(db, 2500).await?);
+    drop(lock);
+    tokio::time::sleep(Duration::from_millis(100)).await;
+    assert!(lock_available(db, 2500).await?);
+
+    // A lock that can't be released closes its connection rather than returning it
+    let lock = db.advisory_lock(2500).await?;
+    lock.execute("SELECT pg_advisory_unlock(2500)", &[]).await?;
+    let size = db.status().size;
+    assert!(lock.unlock().await.is_err());
+    assert_eq!(db.status().size, size - 1);
+
+    Ok(())
+}
+
+pub async fn cancelled_advisory_lock(db: &DatabasePool) -> Result<(), DbErr> {
+    let lock = db.advisory_lock(2502).await?;
+    let size = db.status().size;
+
+    // Giving up on a contended lock closes the connection waiting on it
+    let waiting = tokio::time::timeout(Duration::from_millis(100), db.advisory_lock(2502));
+    assert!(waiting.await.is_err());
+    assert_eq!(db.status().size, size);
+
+    // so the lock isn't taken behind our back once it is free
+    lock.unlock().await?;
+    let lock = tokio::time::timeout(Duration::from_secs(5), db.advisory_lock(2502))
+        .await
+        .expect("the key can be locked again")?;
+    assert!(!lock_available(db, 2502).await?);
+    lock.unlock().await?;
+    assert!(lock_available(db, 2502).await?);
+
+    Ok(())
+}
+
+pub async fn transaction_advisory_lock(db: &DatabasePool) -> Result<(), DbErr> {
+    let mut conn = db.get().await?;
+    let txn = conn.begin().await?;
+    txn.advisory_xact_lock(2501).await?;
+    assert!(!lock_available(db, 2501).await?);
+
+    txn.commit().await?;
+    assert!(lock_available(db, 2501).await?);
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ActiveModelTrait, ActiveValue, ConnectionTrait, IntoActiveModel, entity::prelude::*};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+
+mod post {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "post")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        #[pgorm(created_timestamp)]
+        pub created_at: DateTimeWithTimeZone,
+        #[pgorm(updated_timestamp)]
+        pub updated_at: Option<DateTimeUtc>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("auto_timestamp_tests").await;
+    set_auto_timestamps(&ctx.db).await?;
+    insert_then_update_timestamps(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn set_auto_timestamps(_db: &DatabasePool) -> Result<(), DbErr> {
+    let mut inserted = post::ActiveModel {
+        title: ActiveValue::set("Hello".to_owned()),
+        ..Default::default()
+    };
+    inserted.set_auto_timestamps(true);
+    assert!(inserted.id.is_not_set());
+    assert!(inserted.created_at.is_set());
+    assert!(inserted.updated_at.is_set());
+
+    let model = post::Model {
+        id: 1,
+        title: "Hello".to_owned(),
+        created_at: inserted.created_at.unwrap(),
+        updated_at: inserted.updated_at.unwrap(),
+    };
+
+    // Only the updated timestamp is touched on update
+    let mut updated = model.clone().into_active_model();
+    updated.title = ActiveValue::set("World".to_owned());
+    updated.set_auto_timestamps(false);
+    assert_eq!(updated.created_at, ActiveValue::Unchanged(model.created_at));
+    assert!(updated.updated_at.is_set());
+    assert!(updated.updated_at.unwrap() >= model.updated_at);
+
+    Ok(())
+}
+
+pub async fn insert_then_update_timestamps(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "post" (
+            "id" serial PRIMARY KEY,
+            "title" text NOT NULL,
+            "created_at" timestamptz NOT NULL,
+            "updated_at" timestamptz
+        )"#,
+        &[],
+    )
+    .await?;
+
+    let inserted = post::ActiveModel {
+        title: ActiveValue::set("Hello".to_owned()),
+        ..Default::default()
+    }
+    .insert(&&conn)
+    .await?;
+    assert!(inserted.updated_at.is_some());
+
+    tokio::time::sleep(Duration::from_millis(10)).await;
+
+    let mut post = inserted.clone().into_active_model();
+    post.title = ActiveValue::set("World".to_owned());
+    post.update(&&conn).await?;
+
+    let updated = post::Entity::find_by_id(inserted.id).one(&&conn).await?;
+    assert_eq!(updated.title, "World");
+    assert_eq!(updated.created_at, inserted.created_at);
+    assert!(updated.updated_at > inserted.updated_at);
+
+    Ok(())
+}
@@ -0,0 +1,66 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{CancellationToken, ConnectionTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+use std::time::{Duration, Instant};
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("cancellation_tests").await;
+    cancel_pg_sleep(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn cancel_pg_sleep(db: &DatabasePool) -> Result<(), DbErr> {
+    let token = CancellationToken::new();
+    let cancel = token.clone();
+    tokio::spawn(async move {
+        tokio::time::sleep(Duration::from_millis(200)).await;
+        cancel.cancel();
+    });
+
+    let started = Instant::now();
+    let res = db
+        .run_cancellable(&token, |conn| {
+            Box::pin(async move {
+                conn.execute("SELECT pg_sleep(30)", &[]).await?;
+                conn.execute(r#"CREATE TABLE "never_created" ("id" integer)"#, &[])
+                    .await
+            })
+        })
+        .await;
+
+    assert!(started.elapsed() < Duration::from_secs(10));
+    assert!(matches!(res, Err(DbErr::Cancelled)), "{res:?}");
+
+    // The sleep was cancelled server-side and the rest of the callback never ran
+    let conn = db.get().await?;
+    let sleeping: i64 = conn
+        .query_one(
+            "SELECT count(*) FROM pg_stat_activity WHERE query = 'SELECT pg_sleep(30)' AND state = 'active'",
+            &[],
+        )
+        .await?
+        .get(0);
+    assert_eq!(sleeping, 0);
+    let created: Option<String> = conn
+        .query_one("SELECT to_regclass('never_created')::text", &[])
+        .await?
+        .get(0);
+    assert_eq!(created, None);
+
+    // A token that is never cancelled leaves the query alone
+    let rows = db
+        .run_cancellable(&CancellationToken::new(), |conn| {
+            Box::pin(async move { conn.query_all("SELECT 1", &[]).await })
+        })
+        .await?;
+    assert_eq!(rows.len(), 1);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::cake};
+use pgorm::{
+    ConnectionTrait,
+    entity::prelude::*,
+    pgorm_query::{Query, QueryBuilder},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("column_refs_tests").await;
+    select_column_refs(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn select_column_refs(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    let refs: Vec<_> = Entity::column_refs()
+        .iter()
+        .map(|(table, column)| (table.to_string(), column.to_string()))
+        .collect();
+    assert_eq!(
+        refs,
+        [
+            ("cake".to_owned(), "id".to_owned()),
+            ("cake".to_owned(), "name".to_owned()),
+        ]
+    );
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Apple Pie')"#,
+        &[],
+    )
+    .await?;
+
+    // A projection built from the references reads every column of the model
+    let select = Query::select()
+        .columns(Entity::column_refs())
+        .from(Entity)
+        .to_string(QueryBuilder);
+    assert_eq!(select, r#"SELECT "cake"."id", "cake"."name" FROM "cake""#);
+    let row = db.query_one(&select, &[]).await?;
+    assert_eq!(
+        (row.get::<_, i32>("id"), row.get::<_, String>("name")),
+        (1, "Apple Pie".to_owned())
+    );
+
+    Ok(())
+}
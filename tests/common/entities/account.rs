@@ -0,0 +1,17 @@
+use pgorm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[pgorm(table_name = "account")]
+pub struct Model {
+    #[pgorm(primary_key)]
+    pub id: i32,
+    #[pgorm(unique)]
+    pub email: String,
+    pub name: String,
+    pub deleted_at: Option<DateTimeWithTimeZone>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
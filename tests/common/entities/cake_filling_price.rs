@@ -0,0 +1,16 @@
+use pgorm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[pgorm(table_name = "cake_filling_price")]
+pub struct Model {
+    #[pgorm(primary_key, auto_increment = false)]
+    pub cake_id: i32,
+    #[pgorm(primary_key, auto_increment = false)]
+    pub filling_id: i32,
+    pub price: i32,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
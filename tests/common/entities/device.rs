@@ -0,0 +1,16 @@
+use pgorm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[pgorm(table_name = "device")]
+pub struct Model {
+    #[pgorm(primary_key)]
+    pub id: i32,
+    // Reading a `macaddr` back isn't supported, only binding one
+    #[pgorm(column_type = "MacAddr")]
+    pub mac: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
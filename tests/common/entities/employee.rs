@@ -0,0 +1,18 @@
+use pgorm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[pgorm(table_name = "employee", alias = "mgr")]
+pub struct Model {
+    #[pgorm(primary_key)]
+    pub id: i32,
+    pub name: String,
+    pub manager_id: Option<i32>,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {
+    #[pgorm(belongs_to = "Entity", from = "Column::ManagerId", to = "Column::Id")]
+    Manager,
+}
+
+impl ActiveModelBehavior for ActiveModel {}
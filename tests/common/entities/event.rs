@@ -0,0 +1,15 @@
+use pgorm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[pgorm(table_name = "event")]
+pub struct Model {
+    #[pgorm(primary_key)]
+    pub id: i32,
+    pub created_at: DateTimeWithTimeZone,
+    pub created_on: Date,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
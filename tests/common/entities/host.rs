@@ -0,0 +1,16 @@
+use pgorm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[pgorm(table_name = "host")]
+pub struct Model {
+    #[pgorm(primary_key)]
+    pub id: i32,
+    // Reading an `inet` back isn't supported, only binding one
+    #[pgorm(column_type = "Inet")]
+    pub address: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,14 @@
+//! Entities shared by the integration tests
+pub mod account;
+pub mod cake;
+pub mod cake_filling_price;
+pub mod category;
+pub mod device;
+pub mod employee;
+pub mod event;
+pub mod fruit;
+pub mod host;
+pub mod item;
+pub mod note;
+pub mod person;
+pub mod profile;
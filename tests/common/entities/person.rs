@@ -0,0 +1,15 @@
+use pgorm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[pgorm(table_name = "person")]
+pub struct Model {
+    #[pgorm(primary_key)]
+    pub id: i32,
+    pub first_name: String,
+    pub last_name: String,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
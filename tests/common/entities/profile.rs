@@ -0,0 +1,15 @@
+use pgorm::entity::prelude::*;
+
+#[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+#[pgorm(table_name = "profile")]
+pub struct Model {
+    #[pgorm(primary_key)]
+    pub id: i32,
+    #[pgorm(column_type = "JsonBinary")]
+    pub data: Json,
+}
+
+#[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+pub enum Relation {}
+
+impl ActiveModelBehavior for ActiveModel {}
@@ -0,0 +1,60 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ConnectionTrait, DatabasePool, DbErr, ValueHolder,
+    pgorm_query::{Alias, Expr, Order, Query, QueryBuilder, QueryStatementWriter},
+    types::ToSql,
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("compiled_query_tests").await;
+    compile_once_bind_twice(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn compile_once_bind_twice(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "item" ("id" serial PRIMARY KEY, "name" text NOT NULL, "price" integer NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "item" ("name", "price") VALUES ('apple', 10), ('pear', 20), ('plum', 30)"#,
+        &[],
+    )
+    .await?;
+
+    let compiled = Query::select()
+        .column(Alias::new("name"))
+        .from(Alias::new("item"))
+        .and_where(Expr::col(Alias::new("price")).gt(0))
+        .order_by(Alias::new("id"), Order::Asc)
+        .compile(QueryBuilder);
+    let stmt = conn.prepare_typed(compiled.sql(), &[]).await?;
+
+    for (bound, expected) in [(15, vec!["pear", "plum"]), (25, vec!["plum"])] {
+        let values = compiled.bind([bound])?;
+        let values = values.into_iter().map(ValueHolder).collect::<Vec<_>>();
+        let params = values
+            .iter()
+            .map(|value| value as &(dyn ToSql + Sync))
+            .collect::<Vec<_>>();
+        let names: Vec<String> = conn
+            .query_all(&stmt, &params)
+            .await?
+            .iter()
+            .map(|row| row.get(0))
+            .collect();
+        assert_eq!(names, expected);
+    }
+
+    Ok(())
+}
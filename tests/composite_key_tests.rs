@@ -0,0 +1,54 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::cake_filling_price};
+use pgorm::{ConnectionTrait, QueryTrait, entity::prelude::*, pgorm_query::Values};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("composite_key_tests").await;
+    create_table(&ctx.db, cake_filling_price::Entity).await?;
+    ctx.db
+        .execute(
+            r#"INSERT INTO "cake_filling_price" ("cake_id", "filling_id", "price") VALUES
+                (1, 1, 10), (1, 2, 20), (2, 1, 30)"#,
+            &[],
+        )
+        .await?;
+    find_by_composite_id(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn find_by_composite_id(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake_filling_price::*;
+
+    let query = Entity::find_by_id((1, 2));
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "cake_filling_price"."cake_id", "cake_filling_price"."filling_id","#,
+                r#""cake_filling_price"."price" FROM "cake_filling_price""#,
+                r#"WHERE "cake_filling_price"."cake_id" = $1"#,
+                r#"AND "cake_filling_price"."filling_id" = $2"#,
+            ]
+            .join(" "),
+            Values(vec![1i32.into(), 2i32.into()])
+        )
+    );
+    assert_eq!(
+        query.one(db).await?,
+        Model {
+            cake_id: 1,
+            filling_id: 2,
+            price: 20,
+        }
+    );
+
+    Ok(())
+}
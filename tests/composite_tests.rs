@@ -0,0 +1,84 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ConnectionTrait, DatabasePool, DbErr, FromQueryResult, QueryResult};
+use postgres_types::FromSql;
+use pretty_assertions::assert_eq;
+
+#[derive(Debug, PartialEq, FromSql)]
+#[postgres(name = "pair")]
+struct Pair {
+    id: i32,
+    name: Option<String>,
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct Labelled {
+    label: String,
+    #[pgorm(composite)]
+    pair: Pair,
+    #[pgorm(composite)]
+    missing: Option<Pair>,
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("composite_tests").await;
+    create_pair_type(&ctx.db).await?;
+    decode_composite(&ctx.db).await?;
+    decode_composite_mismatch(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn create_pair_type(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(r#"CREATE TYPE "pair" AS ("id" integer, "name" text)"#, &[])
+        .await?;
+
+    Ok(())
+}
+
+pub async fn decode_composite(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    let row = conn
+        .query_one(
+            r#"SELECT
+                'first' AS "label",
+                ROW(1, 'one')::"pair" AS "pair",
+                NULL::"pair" AS "missing""#,
+            &[],
+        )
+        .await?;
+
+    assert_eq!(
+        Labelled::from_query_result(&QueryResult::from(row), "")?,
+        Labelled {
+            label: "first".to_owned(),
+            pair: Pair {
+                id: 1,
+                name: Some("one".to_owned()),
+            },
+            missing: None,
+        }
+    );
+
+    Ok(())
+}
+
+pub async fn decode_composite_mismatch(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    let row = conn
+        .query_one(r#"SELECT ROW('one', 1) AS "pair""#, &[])
+        .await?;
+
+    assert!(
+        QueryResult::from(row)
+            .try_get_composite::<Pair>("", "pair")
+            .is_err()
+    );
+
+    Ok(())
+}
@@ -0,0 +1,71 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::person;
+use pgorm::{
+    ActiveValue, ConnectionTrait, IntoSimpleExpr, QuerySelect, QueryTrait, Schema,
+    entity::prelude::*,
+    pgorm_query::{Expr, QueryBuilder},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("concat_tests").await;
+    concat_columns(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn concat_columns(db: &DatabasePool) -> Result<(), DbErr> {
+    use person::*;
+
+    let conn = db.get().await?;
+    let create = Schema::new()
+        .create_table_from_entity(Entity)
+        .to_string(QueryBuilder);
+    conn.execute(&create, &[]).await?;
+    Entity::insert(ActiveModel {
+        first_name: ActiveValue::set("Ada".to_owned()),
+        last_name: ActiveValue::set("Lovelace".to_owned()),
+        ..Default::default()
+    })
+    .exec(&&conn)
+    .await?;
+
+    let query = Entity::find().select_only().expr(
+        Column::FirstName
+            .concat(Expr::val(" "))
+            .concat(Column::LastName.into_simple_expr()),
+    );
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT "person"."first_name" || $1 || "person"."last_name""#,
+            r#"FROM "person""#,
+        ]
+        .join(" ")
+    );
+    let full_name: String = query.into_tuple().one(&&conn).await?;
+    assert_eq!(full_name, "Ada Lovelace");
+
+    let last_name = Expr::val(" ").concat(Column::LastName.into_simple_expr());
+    let query = Entity::find()
+        .select_only()
+        .expr(Column::FirstName.concat(last_name));
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT "person"."first_name" || ($1 || "person"."last_name")"#,
+            r#"FROM "person""#,
+        ]
+        .join(" ")
+    );
+    let full_name: String = query.into_tuple().one(&&conn).await?;
+    assert_eq!(full_name, "Ada Lovelace");
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ConnectionEvent, ConnectionTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+use std::{
+    sync::{Arc, Mutex},
+    time::Duration,
+};
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("connection_hooks_tests").await;
+    count_acquire_release(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn count_acquire_release(db: &DatabasePool) -> Result<(), DbErr> {
+    let acquired: Arc<Mutex<Vec<ConnectionEvent>>> = Default::default();
+    let released: Arc<Mutex<Vec<ConnectionEvent>>> = Default::default();
+    let db = db
+        .clone()
+        .on_acquire({
+            let acquired = acquired.clone();
+            move |event| acquired.lock().unwrap().push(event.clone())
+        })
+        .on_release({
+            let released = released.clone();
+            move |event| released.lock().unwrap().push(event.clone())
+        });
+
+    // Each query run on the pool checks out a connection
+    for _ in 0..3 {
+        db.execute("SELECT 1", &[]).await?;
+    }
+    assert_eq!(acquired.lock().unwrap().len(), 3);
+    assert_eq!(released.lock().unwrap().len(), 3);
+
+    let conn = db.get().await?;
+    conn.execute("SELECT 1", &[]).await?;
+    conn.execute("SELECT 2", &[]).await?;
+    assert_eq!(acquired.lock().unwrap().len(), 4);
+    assert_eq!(released.lock().unwrap().len(), 3);
+
+    tokio::time::sleep(Duration::from_millis(50)).await;
+    drop(conn);
+    assert_eq!(released.lock().unwrap().len(), 4);
+
+    let released = released.lock().unwrap();
+    assert!(released[3].elapsed >= Duration::from_millis(50));
+    assert!(
+        acquired
+            .lock()
+            .unwrap()
+            .iter()
+            .chain(released.iter())
+            .all(|event| event.tag == db.tag())
+    );
+
+    Ok(())
+}
@@ -0,0 +1,37 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ConnAcquireErr, DbErr};
+use pretty_assertions::assert_eq;
+use std::time::Duration;
+use tokio_postgres::Config;
+
+#[tokio::test]
+async fn connection_acquire_timeout() {
+    let ctx = TestContext::new("connection_acquire_timeout").await;
+
+    let mut config: Config = std::env::var("DATABASE_URL").unwrap().parse().unwrap();
+    config.dbname("connection_acquire_timeout");
+    let db = pgorm::connect_with_builder(config, |builder| {
+        builder
+            // The connection pool has a single connection only
+            .max_size(1)
+            // A controlled connection acquire timeout
+            .wait_timeout(Some(Duration::from_millis(200)))
+    });
+
+    // Occupy the only connection, thus forcing others fail to acquire connection
+    let conn = db.get().await.unwrap();
+    assert_eq!(
+        db.get().await.err().expect("should be a time out"),
+        DbErr::ConnectionAcquire(ConnAcquireErr::Timeout)
+    );
+
+    drop(conn);
+    db.get().await.unwrap();
+    drop(db);
+
+    ctx.delete().await;
+}
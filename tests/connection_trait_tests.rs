@@ -0,0 +1,56 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::note;
+use pgorm::{ConnectionTrait, TransactionTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("connection_trait_tests").await;
+    pool_and_transaction(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn add_note(db: &impl ConnectionTrait, id: i32, body: &str) -> Result<(), DbErr> {
+    db.execute(
+        r#"INSERT INTO "note" ("id", "body") VALUES ($1, $2)"#,
+        &[&id, &body],
+    )
+    .await?;
+    Ok(())
+}
+
+async fn count_notes(db: &impl ConnectionTrait) -> Result<usize, DbErr> {
+    let row = db.query_one(r#"SELECT COUNT(*) FROM "note""#, &[]).await?;
+    let count: i64 = row.get(0);
+    assert_eq!(note::Entity::find().all(db).await?.len(), count as usize);
+    Ok(count as usize)
+}
+
+pub async fn pool_and_transaction(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "note" ("id" integer PRIMARY KEY, "body" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+
+    add_note(db, 1, "pool").await?;
+    assert_eq!(count_notes(db).await?, 1);
+
+    let mut conn = db.get().await?;
+    let txn = conn.begin().await?;
+    add_note(&txn, 2, "transaction").await?;
+    assert_eq!(count_notes(&txn).await?, 2);
+    // The pool checks out another connection, which can't see the uncommitted row
+    assert_eq!(count_notes(db).await?, 1);
+    txn.commit().await?;
+
+    assert_eq!(count_notes(db).await?, 2);
+
+    Ok(())
+}
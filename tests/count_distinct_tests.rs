@@ -0,0 +1,40 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::cake};
+use pgorm::{ConnectionTrait, QuerySelect, QueryTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("count_distinct_tests").await;
+    count_distinct(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn count_distinct(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Cheese'), (2, 'Apple'), (3, 'Cheese')"#,
+        &[],
+    )
+    .await?;
+
+    let query = Entity::find()
+        .select_only()
+        .expr(Column::Name.count_distinct());
+    assert_eq!(
+        query.build().0,
+        r#"SELECT COUNT(DISTINCT "cake"."name") FROM "cake""#
+    );
+    let count: i64 = query.into_tuple().one(db).await?;
+    assert_eq!(count, 2);
+
+    Ok(())
+}
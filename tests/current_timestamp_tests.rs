@@ -0,0 +1,52 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::event};
+use pgorm::{ConnectionTrait, QueryOrder, QuerySelect, QueryTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("current_timestamp_tests").await;
+    filter_current_timestamp(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn filter_current_timestamp(db: &DatabasePool) -> Result<(), DbErr> {
+    use event::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "event" ("id", "created_at", "created_on") VALUES
+            (1, now() - interval '1 day', CURRENT_DATE),
+            (2, now() + interval '1 day', CURRENT_DATE),
+            (3, now() - interval '1 day', CURRENT_DATE - 1)"#,
+        &[],
+    )
+    .await?;
+
+    let query = Entity::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(Column::CreatedAt.into_expr().lt(Expr::current_timestamp()))
+        .filter(Column::CreatedOn.into_expr().eq(Expr::current_date()))
+        .order_by_asc(Column::Id);
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT "event"."id" FROM "event""#,
+            r#"WHERE "event"."created_at" < CURRENT_TIMESTAMP"#,
+            r#"AND "event"."created_on" = CURRENT_DATE"#,
+            r#"ORDER BY "event"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+    let ids: Vec<i32> = query.into_tuple().all(db).await?;
+    assert_eq!(ids, [1]);
+
+    Ok(())
+}
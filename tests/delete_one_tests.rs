@@ -0,0 +1,58 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::note;
+use pgorm::{ActiveValue, ConnectionTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("delete_one_tests").await;
+    delete_one(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn delete_one(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "note" ("id" integer PRIMARY KEY, "body" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "note" ("id", "body") VALUES (1, 'a'), (2, 'b'), (3, 'c')"#,
+        &[],
+    )
+    .await?;
+
+    let first = note::ActiveModel {
+        id: ActiveValue::unchanged(1),
+        body: ActiveValue::not_set(),
+    };
+
+    let res = note::Entity::delete(first.clone()).exec(&&conn).await?;
+    assert_eq!(res.rows_affected, 1);
+
+    // The record is gone, so deleting it again is an error
+    let res = note::Entity::delete(first).exec(&&conn).await;
+    assert_eq!(res, Err(DbErr::RecordNotDeleted));
+
+    // Deleting many rows still reports the count, even when it is zero
+    let res = note::Entity::delete_many()
+        .filter(note::Column::Id.gte(2))
+        .exec(&&conn)
+        .await?;
+    assert_eq!(res.rows_affected, 2);
+
+    let res = note::Entity::delete_many()
+        .filter(note::Column::Id.gte(2))
+        .exec(&&conn)
+        .await?;
+    assert_eq!(res.rows_affected, 0);
+
+    Ok(())
+}
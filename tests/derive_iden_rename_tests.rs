@@ -0,0 +1,110 @@
+use pgorm::entity::prelude::*;
+use pgorm::pgorm_query::{Query, QueryBuilder};
+use pretty_assertions::assert_eq;
+
+#[derive(DeriveIden)]
+#[pgorm(rename_all = "camelCase")]
+pub enum CamelCase {
+    Table,
+    FirstName,
+    #[pgorm(iden = "surname")]
+    LastName,
+}
+
+#[derive(DeriveIden)]
+#[pgorm(rename_all = "PascalCase")]
+pub enum PascalCase {
+    Table,
+    FirstName,
+    #[pgorm(iden = "surname")]
+    LastName,
+}
+
+#[derive(DeriveIden)]
+#[pgorm(rename_all = "kebab-case")]
+pub enum KebabCase {
+    Table,
+    FirstName,
+    #[pgorm(iden = "surname")]
+    LastName,
+}
+
+#[derive(DeriveIden)]
+#[pgorm(rename_all = "SCREAMING_SNAKE_CASE")]
+pub enum ScreamingSnakeCase {
+    Table,
+    FirstName,
+    #[pgorm(iden = "surname")]
+    LastName,
+}
+
+#[derive(DeriveIden)]
+#[pgorm(rename_all = "camelCase")]
+struct GlyphImage;
+
+#[test]
+fn rename_all() {
+    assert_eq!(CamelCase::Table.to_string(), "camelCase");
+    assert_eq!(CamelCase::FirstName.to_string(), "firstName");
+    assert_eq!(CamelCase::LastName.to_string(), "surname");
+
+    assert_eq!(PascalCase::Table.to_string(), "PascalCase");
+    assert_eq!(PascalCase::FirstName.to_string(), "FirstName");
+    assert_eq!(PascalCase::LastName.to_string(), "surname");
+
+    assert_eq!(KebabCase::Table.to_string(), "kebab-case");
+    assert_eq!(KebabCase::FirstName.to_string(), "first-name");
+    assert_eq!(KebabCase::LastName.to_string(), "surname");
+
+    assert_eq!(
+        ScreamingSnakeCase::Table.to_string(),
+        "SCREAMING_SNAKE_CASE"
+    );
+    assert_eq!(ScreamingSnakeCase::FirstName.to_string(), "FIRST_NAME");
+    assert_eq!(ScreamingSnakeCase::LastName.to_string(), "surname");
+
+    assert_eq!(GlyphImage.to_string(), "glyphImage");
+}
+
+#[test]
+fn rename_all_in_query() {
+    assert_eq!(
+        Query::select()
+            .column(KebabCase::FirstName)
+            .from(KebabCase::Table)
+            .to_string(QueryBuilder),
+        r#"SELECT "first-name" FROM "kebab-case""#
+    );
+}
+
+#[derive(DeriveIden)]
+pub enum User {
+    Table,
+    Id,
+    #[pgorm(iden = "order")]
+    Position,
+}
+
+#[derive(DeriveIden)]
+struct GlyphToken;
+
+#[test]
+fn unquoted_identifiers() {
+    let query = Query::select()
+        .column((User::Table, User::Id))
+        .column(User::Position)
+        .column(CamelCase::LastName)
+        .column(CamelCase::FirstName)
+        .from(User::Table)
+        .from(GlyphToken)
+        .to_owned();
+
+    assert_eq!(
+        query.to_string(QueryBuilder),
+        r#"SELECT "user"."id", "order", "surname", "firstName" FROM "user", "glyph_token""#
+    );
+    assert_eq!(
+        query.to_string(QueryBuilder::new().unquoted_identifiers(true)),
+        r#"SELECT "user".id, "order", surname, "firstName" FROM "user", glyph_token"#
+    );
+}
@@ -0,0 +1,73 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ColumnInfo, ConnectionTrait, ConstraintKind, DatabasePool, DbErr};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("discovery_tests").await;
+    discover_tables(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn discover_tables(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "bakery" (
+            "id" serial PRIMARY KEY,
+            "name" varchar(255) NOT NULL UNIQUE,
+            "profit_margin" double precision,
+            "opened_at" timestamp with time zone NOT NULL DEFAULT now()
+        )"#,
+        &[],
+    )
+    .await?;
+    drop(conn);
+
+    let tables = db.discover_tables().await?;
+    let bakery = tables
+        .iter()
+        .find(|table| table.name == "bakery")
+        .expect("bakery should be discovered");
+
+    assert_eq!(
+        bakery.columns,
+        [
+            ColumnInfo {
+                name: "id".to_owned(),
+                data_type: "integer".to_owned(),
+                nullable: false,
+                default: Some("nextval('bakery_id_seq'::regclass)".to_owned()),
+            },
+            ColumnInfo {
+                name: "name".to_owned(),
+                data_type: "character varying(255)".to_owned(),
+                nullable: false,
+                default: None,
+            },
+            ColumnInfo {
+                name: "profit_margin".to_owned(),
+                data_type: "double precision".to_owned(),
+                nullable: true,
+                default: None,
+            },
+            ColumnInfo {
+                name: "opened_at".to_owned(),
+                data_type: "timestamp with time zone".to_owned(),
+                nullable: false,
+                default: Some("now()".to_owned()),
+            },
+        ]
+    );
+    assert_eq!(bakery.primary_key(), ["id"]);
+    assert!(bakery.constraints.iter().any(
+        |constraint| constraint.kind == ConstraintKind::Unique && constraint.columns == ["name"]
+    ));
+
+    Ok(())
+}
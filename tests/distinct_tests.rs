@@ -0,0 +1,77 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::cake};
+use pgorm::{ConnectionTrait, QueryOrder, QuerySelect, QueryTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("distinct_tests").await;
+    create_table(&ctx.db, cake::Entity).await?;
+    ctx.db
+        .execute(
+            r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Cheese'), (2, 'Cheese'), (3, 'Apple')"#,
+            &[],
+        )
+        .await?;
+    select_distinct(&ctx.db).await?;
+    select_distinct_last_call_wins(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn select_distinct(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    let query = Entity::find().select_only().column(Column::Name).distinct();
+    assert_eq!(
+        query.build().0,
+        r#"SELECT DISTINCT "cake"."name" FROM "cake""#
+    );
+    let mut names: Vec<String> = query.into_tuple().all(db).await?;
+    names.sort();
+    assert_eq!(names, ["Apple", "Cheese"]);
+
+    Ok(())
+}
+
+pub async fn select_distinct_last_call_wins(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    let query = Entity::find()
+        .distinct_on([(Entity, Column::Name)])
+        .distinct()
+        .order_by_asc(Column::Id);
+    assert_eq!(
+        query.build().0,
+        r#"SELECT DISTINCT "cake"."id", "cake"."name" FROM "cake" ORDER BY "cake"."id" ASC"#
+    );
+    assert_eq!(query.all(db).await?.len(), 3);
+
+    let query = Entity::find()
+        .distinct()
+        .distinct_on([(Entity, Column::Name)])
+        .order_by_asc(Column::Name)
+        .order_by_asc(Column::Id);
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT DISTINCT ON ("cake"."name") "cake"."id", "cake"."name" FROM "cake""#,
+            r#"ORDER BY "cake"."name" ASC, "cake"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+    let ids: Vec<i32> = query
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|cake| cake.id)
+        .collect();
+    assert_eq!(ids, [3, 1]);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::employee};
+use pgorm::{
+    ConnectionTrait, IdenStatic, JoinType, QuerySelect, QueryTrait, entity::prelude::*,
+    pgorm_query::Values,
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("entity_alias_tests").await;
+    join_as_entity_alias(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn join_as_entity_alias(db: &DatabasePool) -> Result<(), DbErr> {
+    use employee::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "employee" ("id", "name", "manager_id") VALUES
+            (1, 'Alice', NULL), (2, 'Bob', 1), (3, 'Carol', 2)"#,
+        &[],
+    )
+    .await?;
+
+    assert_eq!(EntityAlias.as_str(), "mgr");
+    let query = Entity::find()
+        .join_as(JoinType::LeftJoin, Relation::Manager.def(), EntityAlias)
+        .filter(Expr::col((EntityAlias, Column::Name)).eq("Bob"));
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "employee"."id", "employee"."name", "employee"."manager_id" FROM "employee""#,
+                r#"LEFT JOIN "employee" AS "mgr" ON "employee"."manager_id" = "mgr"."id""#,
+                r#"WHERE "mgr"."name" = $1"#,
+            ]
+            .join(" "),
+            Values(vec!["Bob".into()])
+        )
+    );
+    let names: Vec<String> = query
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|employee| employee.name)
+        .collect();
+    assert_eq!(names, ["Carol"]);
+
+    Ok(())
+}
@@ -0,0 +1,58 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::account};
+use pgorm::{ColumnTypeTrait, ConnectionTrait, Schema, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("entity_columns_tests").await;
+    entity_columns(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn entity_columns(db: &DatabasePool) -> Result<(), DbErr> {
+    let columns = Schema::new().entity_columns::<account::Entity>();
+    assert_eq!(
+        columns,
+        [
+            ("id".to_owned(), ColumnType::Integer.def()),
+            (
+                "email".to_owned(),
+                ColumnType::String(StringLen::None).def().unique()
+            ),
+            ("name".to_owned(), ColumnType::String(StringLen::None).def()),
+            (
+                "deleted_at".to_owned(),
+                ColumnType::TimestampWithTimeZone.def().null()
+            ),
+        ]
+    );
+
+    // The columns come in the order of the table created from the entity
+    create_table(db, account::Entity).await?;
+    let table: Vec<(String, bool)> = db
+        .query_all(
+            "SELECT column_name::text, is_nullable = 'YES' FROM information_schema.columns
+            WHERE table_name = 'account' ORDER BY ordinal_position",
+            &[],
+        )
+        .await?
+        .iter()
+        .map(|row| (row.get(0), row.get(1)))
+        .collect();
+    assert_eq!(
+        columns
+            .into_iter()
+            .map(|(name, def)| (name, def.is_null()))
+            .collect::<Vec<_>>(),
+        table
+    );
+
+    Ok(())
+}
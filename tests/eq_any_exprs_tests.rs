@@ -0,0 +1,56 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::item};
+use pgorm::{
+    ConnectionTrait, QueryOrder, QuerySelect, QueryTrait, entity::prelude::*, pgorm_query::Values,
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("eq_any_exprs_tests").await;
+    eq_any_exprs(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn eq_any_exprs(db: &DatabasePool) -> Result<(), DbErr> {
+    use item::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "item" ("id", "price", "category_id") VALUES
+            (1, 10, 1), (2, 20, 1), (3, 30, 3), (4, 40, 1)"#,
+        &[],
+    )
+    .await?;
+
+    // Values and columns can be mixed in the array
+    let query = Entity::find()
+        .select_only()
+        .column(Column::Id)
+        .filter(
+            Column::Id.eq_any_exprs([Expr::val(2).into(), Expr::col(Column::CategoryId).into()]),
+        )
+        .order_by_asc(Column::Id);
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "item"."id" FROM "item""#,
+                r#"WHERE "item"."id" = ANY(ARRAY[$1, "category_id"])"#,
+                r#"ORDER BY "item"."id" ASC"#,
+            ]
+            .join(" "),
+            Values(vec![2.into()])
+        )
+    );
+    let ids: Vec<i32> = query.into_tuple().all(db).await?;
+    assert_eq!(ids, [1, 2, 3]);
+
+    Ok(())
+}
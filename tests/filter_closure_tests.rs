@@ -0,0 +1,70 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{
+    create_table,
+    entities::{cake, fruit},
+};
+use pgorm::{
+    ConnectionTrait, QueryOrder, QuerySelect, QueryTrait, entity::prelude::*,
+    pgorm_query::Condition,
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("filter_closure_tests").await;
+    filter_with_closure(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn filter_with_closure(db: &DatabasePool) -> Result<(), DbErr> {
+    use fruit::*;
+
+    create_table(db, cake::Entity).await?;
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Cherry Pie')"#,
+        &[],
+    )
+    .await?;
+    db.execute(
+        r#"INSERT INTO "fruit" ("id", "name", "cake_id") VALUES
+            (1, 'Apple', NULL), (2, 'Apricot', NULL), (3, 'Banana', NULL), (4, 'Cherry', 1)"#,
+        &[],
+    )
+    .await?;
+
+    let query = Entity::find()
+        .filter(|cond: Condition| {
+            cond.add(Column::Id.gt(1)).add(
+                Condition::any()
+                    .add(Column::Name.starts_with("Ap"))
+                    .add(Condition::all().not().add(Column::CakeId.is_null())),
+            )
+        })
+        .order_by_asc(Column::Id);
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT "fruit"."id", "fruit"."name", "fruit"."cake_id" FROM "fruit""#,
+            r#"WHERE "fruit"."id" > $1"#,
+            r#"AND ("fruit"."name" LIKE $2 OR (NOT "fruit"."cake_id" IS NULL))"#,
+            r#"ORDER BY "fruit"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+    let ids: Vec<i32> = query
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|fruit| fruit.id)
+        .collect();
+    assert_eq!(ids, [2, 4]);
+
+    Ok(())
+}
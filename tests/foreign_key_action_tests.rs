@@ -0,0 +1,71 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{
+    create_table,
+    entities::{cake, fruit},
+};
+use pgorm::{ConnectionTrait, QueryOrder, Schema, entity::prelude::*, pgorm_query::QueryBuilder};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("foreign_key_action_tests").await;
+    referential_actions(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn referential_actions(db: &DatabasePool) -> Result<(), DbErr> {
+    assert_eq!(
+        Schema::new()
+            .create_table_from_entity(fruit::Entity)
+            .to_string(QueryBuilder),
+        [
+            r#"CREATE TABLE "fruit" ("#,
+            r#""id" serial NOT NULL PRIMARY KEY,"#,
+            r#""name" varchar NOT NULL,"#,
+            r#""cake_id" integer,"#,
+            r#"CONSTRAINT "fk-fruit-cake_id" FOREIGN KEY ("cake_id") REFERENCES "cake" ("id")"#,
+            r#"ON DELETE CASCADE ON UPDATE RESTRICT"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+
+    create_table(db, cake::Entity).await?;
+    create_table(db, fruit::Entity).await?;
+    db.execute(
+        r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Apple Pie'), (2, 'Cheesecake')"#,
+        &[],
+    )
+    .await?;
+    db.execute(
+        r#"INSERT INTO "fruit" ("name", "cake_id") VALUES ('Apple', 1), ('Lemon', 2)"#,
+        &[],
+    )
+    .await?;
+
+    // A cake with fruits can't change its key
+    assert!(
+        db.execute(r#"UPDATE "cake" SET "id" = 3 WHERE "id" = 1"#, &[])
+            .await
+            .is_err()
+    );
+
+    // Deleting a cake deletes its fruits
+    cake::Entity::delete_by_id(1).exec(db).await?;
+    let fruits: Vec<String> = fruit::Entity::find()
+        .order_by_asc(fruit::Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|fruit| fruit.name)
+        .collect();
+    assert_eq!(fruits, ["Lemon"]);
+
+    Ok(())
+}
@@ -0,0 +1,98 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ActiveValue, ConnectionTrait, Insert, IntoActiveModel, QueryTrait, Schema, entity::prelude::*,
+    pgorm_query::QueryBuilder,
+};
+use pretty_assertions::assert_eq;
+
+mod line_item {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "line_item")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub price: i32,
+        pub quantity: i32,
+        #[pgorm(generated = "price * quantity", stored)]
+        pub total: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("generated_column_tests").await;
+    insert_and_update_generated(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_and_update_generated(db: &DatabasePool) -> Result<(), DbErr> {
+    use line_item::*;
+
+    let conn = db.get().await?;
+    let create = Schema::new()
+        .create_table_from_entity(Entity)
+        .to_string(QueryBuilder);
+    assert_eq!(
+        create,
+        [
+            r#"CREATE TABLE "line_item" ("#,
+            r#""id" serial NOT NULL PRIMARY KEY,"#,
+            r#""price" integer NOT NULL,"#,
+            r#""quantity" integer NOT NULL,"#,
+            r#""total" integer NOT NULL GENERATED ALWAYS AS (price * quantity) STORED"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+    conn.execute(&create, &[]).await?;
+
+    // every column of a model is set, including the generated one
+    let inserted = Entity::insert(
+        Model {
+            id: 1,
+            price: 3,
+            quantity: 2,
+            total: 0,
+        }
+        .into_active_model(),
+    )
+    .exec_with_returning(&&conn)
+    .await?;
+    assert_eq!(inserted.total, 6);
+
+    // The generated column isn't required, and isn't inserted
+    let insert = Insert::one(ActiveModel {
+        id: ActiveValue::set(2),
+        price: ActiveValue::set(4),
+        quantity: ActiveValue::set(2),
+        ..Default::default()
+    })
+    .validate()?;
+    assert_eq!(
+        insert.build().0,
+        r#"INSERT INTO "line_item" ("id", "price", "quantity") VALUES ($1, $2, $3)"#
+    );
+    insert.exec(&&conn).await?;
+    assert_eq!(Entity::find_by_id(2).one(&&conn).await?.total, 8);
+
+    let mut line = inserted.into_active_model();
+    line.quantity = ActiveValue::set(5);
+    line.total = ActiveValue::set(0);
+    let updated = line.update(&&conn).await?;
+    assert_eq!(updated.total, 15);
+
+    Ok(())
+}
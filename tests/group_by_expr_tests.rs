@@ -0,0 +1,64 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::event};
+use pgorm::{
+    ConnectionTrait, QueryOrder, QuerySelect, QueryTrait, entity::prelude::*, pgorm_query::Order,
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("group_by_expr_tests").await;
+    group_by_day(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn group_by_day(db: &DatabasePool) -> Result<(), DbErr> {
+    use event::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "event" ("id", "created_at", "created_on") VALUES
+            (1, '2024-03-01 09:00:00+00', '2024-03-01'),
+            (2, '2024-03-01 17:30:00+00', '2024-03-01'),
+            (3, '2024-03-02 08:15:00+00', '2024-03-02')"#,
+        &[],
+    )
+    .await?;
+
+    let day = Expr::cust_with_expr(
+        "date_trunc('day', $1 AT TIME ZONE 'UTC')::date",
+        Column::CreatedAt.into_expr(),
+    );
+    let query = Entity::find()
+        .select_only()
+        .column_as(day.clone(), "day")
+        .column_as(Column::Id.count(), "count")
+        .group_by_expr(day.clone())
+        .order_by(day, Order::Asc);
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT date_trunc('day', "event"."created_at" AT TIME ZONE 'UTC')::date AS "day","#,
+            r#"COUNT("event"."id") AS "count" FROM "event""#,
+            r#"GROUP BY date_trunc('day', "event"."created_at" AT TIME ZONE 'UTC')::date"#,
+            r#"ORDER BY date_trunc('day', "event"."created_at" AT TIME ZONE 'UTC')::date ASC"#,
+        ]
+        .join(" ")
+    );
+    let days: Vec<(Date, i64)> = query.into_tuple().all(db).await?;
+    assert_eq!(
+        days,
+        [
+            (Date::from_ymd_opt(2024, 3, 1).unwrap(), 2),
+            (Date::from_ymd_opt(2024, 3, 2).unwrap(), 1),
+        ]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,115 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::cake};
+use pgorm::{
+    ConnectionTrait, QueryOrder, QuerySelect, QueryTrait,
+    entity::prelude::*,
+    pgorm_query::{Asterisk, Values},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("having_tests").await;
+    create_table(&ctx.db, cake::Entity).await?;
+    ctx.db
+        .execute(
+            r#"INSERT INTO "cake" ("id", "name") VALUES
+                (1, 'Cheese'), (2, 'Apple'), (3, 'Lemon'), (5, 'Cheese'), (8, 'Apple')"#,
+            &[],
+        )
+        .await?;
+    having_without_group_by(&ctx.db).await?;
+    having_count(&ctx.db).await?;
+    having_sum_gt(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn having_without_group_by(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    // Without GROUP BY the whole table is one group, which HAVING keeps or drops.
+    // COUNT(*) is a bigint, so compare it with an i64 for the parameter to bind.
+    let count_over = |n: i64| {
+        Entity::find()
+            .select_only()
+            .expr(Expr::col(Asterisk).count())
+            .having(Expr::expr(Expr::col(Asterisk).count()).gt(n))
+    };
+    assert_eq!(
+        count_over(4).build().0,
+        r#"SELECT COUNT(*) FROM "cake" HAVING COUNT(*) > $1"#
+    );
+    let counts: Vec<i64> = count_over(4).into_tuple().all(db).await?;
+    assert_eq!(counts, [5]);
+    let counts: Vec<i64> = count_over(5).into_tuple().all(db).await?;
+    assert!(counts.is_empty());
+
+    Ok(())
+}
+
+pub async fn having_count(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    let names = || {
+        Entity::find()
+            .select_only()
+            .column(Column::Name)
+            .group_by(Column::Name)
+    };
+    let query = names().having_count_gt(1).order_by_asc(Column::Name);
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "cake"."name" FROM "cake" GROUP BY "cake"."name""#,
+                r#"HAVING COUNT(*) > $1 ORDER BY "cake"."name" ASC"#,
+            ]
+            .join(" "),
+            Values(vec![1i64.into()])
+        )
+    );
+    let repeated: Vec<String> = query.into_tuple().all(db).await?;
+    assert_eq!(repeated, ["Apple", "Cheese"]);
+
+    let query = names().having_count_lt(2);
+    assert_eq!(
+        query.build().0,
+        r#"SELECT "cake"."name" FROM "cake" GROUP BY "cake"."name" HAVING COUNT(*) < $1"#
+    );
+    let single: Vec<String> = query.into_tuple().all(db).await?;
+    assert_eq!(single, ["Lemon"]);
+
+    Ok(())
+}
+
+pub async fn having_sum_gt(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    // SUM of an integer column is a bigint, so the bound is an i64
+    let query = Entity::find()
+        .select_only()
+        .column(Column::Name)
+        .group_by(Column::Name)
+        .having_sum_gt(Column::Id, 6i64);
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "cake"."name" FROM "cake" GROUP BY "cake"."name""#,
+                r#"HAVING SUM("cake"."id") > $1"#,
+            ]
+            .join(" "),
+            Values(vec![6i64.into()])
+        )
+    );
+    let names: Vec<String> = query.into_tuple().all(db).await?;
+    assert_eq!(names, ["Apple"]);
+
+    Ok(())
+}
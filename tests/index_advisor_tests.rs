@@ -0,0 +1,114 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ConnectionTrait, IndexSuggestion, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+mod account {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "account")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub email: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod audit_event {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(schema_name = "audit", table_name = "Event")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub kind: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("index_advisor_tests").await;
+    suggest_indexes(&ctx.db).await?;
+    suggest_indexes_outside_search_path(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn suggest_indexes(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "account" ("id" serial PRIMARY KEY, "email" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    db.execute(
+        r#"INSERT INTO "account" ("email") SELECT 'user' || i || '@example.com' FROM generate_series(1, 10000) AS i"#,
+        &[],
+    )
+    .await?;
+    db.execute(r#"ANALYZE "account""#, &[]).await?;
+
+    let by_email = account::Entity::find().filter(account::Column::Email.eq("user42@example.com"));
+    assert_eq!(
+        by_email.suggest_indexes(db).await?,
+        [IndexSuggestion {
+            table: "account".to_owned(),
+            column: "email".to_owned(),
+        }]
+    );
+
+    // The primary key is already indexed
+    let by_id = account::Entity::find_by_id(42);
+    assert_eq!(by_id.suggest_indexes(db).await?, []);
+
+    db.execute(
+        r#"CREATE INDEX "idx-account-email" ON "account" ("email")"#,
+        &[],
+    )
+    .await?;
+    assert_eq!(by_email.suggest_indexes(db).await?, []);
+
+    Ok(())
+}
+
+pub async fn suggest_indexes_outside_search_path(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(r#"CREATE SCHEMA "audit""#, &[]).await?;
+    db.execute(
+        r#"CREATE TABLE "audit"."Event" ("id" serial PRIMARY KEY, "kind" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    db.execute(
+        r#"INSERT INTO "audit"."Event" ("kind") SELECT 'kind' || i FROM generate_series(1, 10000) AS i"#,
+        &[],
+    )
+    .await?;
+    db.execute(r#"ANALYZE "audit"."Event""#, &[]).await?;
+
+    // The table is found by its schema and exact name
+    let by_kind = audit_event::Entity::find().filter(audit_event::Column::Kind.eq("kind42"));
+    assert_eq!(
+        by_kind.suggest_indexes(db).await?,
+        [IndexSuggestion {
+            table: "Event".to_owned(),
+            column: "kind".to_owned(),
+        }]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,27 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+use common::entities::cake;
+use pgorm::{ActiveValue, Insert, QueryTrait};
+use pretty_assertions::assert_eq;
+
+#[test]
+fn insert_same_shape_same_sql() {
+    let (first, _) = Insert::one(cake::Model {
+        id: 1,
+        name: "Apple Pie".to_owned(),
+    })
+    .build();
+    let (second, _) = Insert::one(cake::ActiveModel {
+        name: ActiveValue::set("Orange Scone".to_owned()),
+        id: ActiveValue::set(2),
+    })
+    .build();
+
+    assert_eq!(
+        first,
+        r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2)"#
+    );
+    assert_eq!(first, second);
+}
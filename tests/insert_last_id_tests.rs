@@ -0,0 +1,89 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::note;
+use pgorm::{ActiveValue, ConnectionTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+mod ticket {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "ticket")]
+    pub struct Model {
+        #[pgorm(primary_key, auto_increment = false)]
+        pub project: String,
+        #[pgorm(primary_key, auto_increment = false)]
+        pub number: i32,
+        pub title: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("insert_last_id_tests").await;
+    insert_serial_primary_key(&ctx.db).await?;
+    insert_composite_primary_key(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_serial_primary_key(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "note" ("id" serial PRIMARY KEY, "body" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+
+    for expected in [1, 2] {
+        let res = note::Entity::insert(note::ActiveModel {
+            body: ActiveValue::set("hello".to_owned()),
+            ..Default::default()
+        })
+        .exec(db)
+        .await?;
+        let id: i32 = res.last_insert_id;
+        assert_eq!(id, expected);
+    }
+
+    Ok(())
+}
+
+pub async fn insert_composite_primary_key(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "ticket" ("project" text NOT NULL, "number" serial, "title" text NOT NULL, PRIMARY KEY ("project", "number"))"#,
+        &[],
+    )
+    .await?;
+
+    // Every key column is set, so the key is the one given
+    let res = ticket::Entity::insert(ticket::ActiveModel {
+        project: ActiveValue::set("core".to_owned()),
+        number: ActiveValue::set(100),
+        title: ActiveValue::set("Crash on start".to_owned()),
+    })
+    .exec(db)
+    .await?;
+    let key: (String, i32) = res.last_insert_id;
+    assert_eq!(key, ("core".to_owned(), 100));
+
+    // A key column filled in by the database is read back with `RETURNING`
+    let res = ticket::Entity::insert(ticket::ActiveModel {
+        project: ActiveValue::set("docs".to_owned()),
+        title: ActiveValue::set("Typo".to_owned()),
+        ..Default::default()
+    })
+    .exec(db)
+    .await?;
+    assert_eq!(res.last_insert_id, ("docs".to_owned(), 1));
+
+    Ok(())
+}
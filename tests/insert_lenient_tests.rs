@@ -0,0 +1,111 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ActiveValue, ConnectionTrait, QueryOrder, QuerySelect, TransactionTrait, entity::prelude::*,
+};
+use pretty_assertions::assert_eq;
+use tokio_postgres::error::SqlState;
+
+mod tag {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "tag")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        #[pgorm(unique)]
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("insert_lenient_tests").await;
+    insert_many_lenient(&ctx.db).await?;
+    insert_many_lenient_releases_savepoints(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn tag(name: &str) -> tag::ActiveModel {
+    tag::ActiveModel {
+        name: ActiveValue::set(name.to_owned()),
+        ..Default::default()
+    }
+}
+
+pub async fn insert_many_lenient(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "tag" ("id" serial PRIMARY KEY, "name" text NOT NULL UNIQUE)"#,
+        &[],
+    )
+    .await?;
+
+    let mut conn = db.get().await?;
+    let txn = conn.begin().await?;
+    let res = tag::Entity::insert_many_lenient([tag("red"), tag("green"), tag("red"), tag("blue")])
+        .exec(&txn)
+        .await?;
+    txn.commit().await?;
+
+    assert_eq!(res.inserted.len(), 3);
+    assert_eq!(res.failed.len(), 1);
+    match &res.failed[0] {
+        (2, DbErr::Postgres(err)) => assert_eq!(err.code(), Some(&SqlState::UNIQUE_VIOLATION)),
+        failed => panic!("expected the duplicate to fail, got {failed:?}"),
+    }
+
+    let names: Vec<String> = tag::Entity::find()
+        .select_only()
+        .column(tag::Column::Name)
+        .order_by_asc(tag::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await?;
+    assert_eq!(names, ["red", "green", "blue"]);
+
+    Ok(())
+}
+
+pub async fn insert_many_lenient_releases_savepoints(db: &DatabasePool) -> Result<(), DbErr> {
+    let mut conn = db.get().await?;
+    let txn = conn.begin().await?;
+    let res = tag::Entity::insert_many_lenient([
+        tag("red"),
+        tag("yellow"),
+        tag("green"),
+        tag("blue"),
+        tag("purple"),
+    ])
+    .exec(&txn)
+    .await?;
+    assert_eq!(res.inserted.len(), 2);
+    assert_eq!(
+        res.failed.iter().map(|(idx, _)| *idx).collect::<Vec<_>>(),
+        [0, 2, 3]
+    );
+
+    // Every savepoint was released, failed rows included
+    let released = txn
+        .execute("RELEASE SAVEPOINT pgorm_insert_lenient", &[])
+        .await;
+    match released {
+        Err(DbErr::Postgres(err)) => {
+            assert_eq!(err.code(), Some(&SqlState::S_E_INVALID_SPECIFICATION))
+        }
+        res => panic!("expected no savepoint left, got {res:?}"),
+    }
+    txn.rollback().await?;
+
+    Ok(())
+}
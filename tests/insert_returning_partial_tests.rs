@@ -0,0 +1,92 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ActiveValue, ConnectionTrait, DerivePartialModel, FromQueryResult, entity::prelude::*,
+};
+use pretty_assertions::assert_eq;
+
+mod article {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "article")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        pub body: String,
+        pub views: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[derive(DerivePartialModel, FromQueryResult, Debug, PartialEq)]
+#[pgorm(entity = "article::Entity")]
+struct ArticleHeader {
+    id: i32,
+    title: String,
+}
+
+#[derive(DerivePartialModel, FromQueryResult, Debug, PartialEq)]
+#[pgorm(entity = "article::Entity")]
+struct ArticleStats {
+    #[pgorm(from_col = "id")]
+    article_id: i32,
+    views: i32,
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("insert_returning_partial_tests").await;
+    insert_returning_partial(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn new_article(title: &str) -> article::ActiveModel {
+    article::ActiveModel {
+        title: ActiveValue::set(title.to_owned()),
+        body: ActiveValue::set("a long body".to_owned()),
+        ..Default::default()
+    }
+}
+
+pub async fn insert_returning_partial(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "article" ("id" serial PRIMARY KEY, "title" text NOT NULL, "body" text NOT NULL, "views" integer NOT NULL DEFAULT 0)"#,
+        &[],
+    )
+    .await?;
+
+    let header = article::Entity::insert(new_article("First"))
+        .exec_returning_partial::<ArticleHeader, _>(db)
+        .await?;
+    assert_eq!(
+        header,
+        ArticleHeader {
+            id: 1,
+            title: "First".to_owned(),
+        }
+    );
+
+    let stats = article::Entity::insert(new_article("Second"))
+        .exec_returning_partial::<ArticleStats, _>(db)
+        .await?;
+    assert_eq!(
+        stats,
+        ArticleStats {
+            article_id: 2,
+            views: 0,
+        }
+    );
+
+    Ok(())
+}
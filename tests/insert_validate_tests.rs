@@ -0,0 +1,88 @@
+use pgorm::{ActiveValue, DbErr, Insert, QueryTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+mod cake {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "cake")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        pub note: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[test]
+fn insert_validate_one() {
+    assert_eq!(
+        Insert::one(cake::ActiveModel {
+            id: ActiveValue::not_set(),
+            name: ActiveValue::not_set(),
+            note: ActiveValue::not_set(),
+        })
+        .validate()
+        .unwrap_err(),
+        DbErr::Validation("Column `name` is required but not set on row 0".to_owned()),
+    );
+
+    assert_eq!(
+        Insert::one(cake::ActiveModel {
+            id: ActiveValue::not_set(),
+            name: ActiveValue::set("Apple Pie".to_owned()),
+            note: ActiveValue::not_set(),
+        })
+        .validate()
+        .unwrap()
+        .build()
+        .0,
+        r#"INSERT INTO "cake" ("name") VALUES ($1)"#,
+    );
+}
+
+#[test]
+fn insert_validate_many() {
+    assert_eq!(
+        Insert::many([
+            cake::ActiveModel {
+                id: ActiveValue::set(1),
+                name: ActiveValue::not_set(),
+                note: ActiveValue::set(None),
+            },
+            cake::ActiveModel {
+                id: ActiveValue::set(2),
+                name: ActiveValue::not_set(),
+                note: ActiveValue::set(None),
+            },
+        ])
+        .validate()
+        .unwrap_err(),
+        DbErr::Validation("Column `name` is required but not set on row 0".to_owned()),
+    );
+
+    assert_eq!(
+        Insert::many([
+            cake::ActiveModel {
+                id: ActiveValue::set(1),
+                name: ActiveValue::set("Apple Pie".to_owned()),
+                note: ActiveValue::not_set(),
+            },
+            cake::ActiveModel {
+                id: ActiveValue::set(2),
+                name: ActiveValue::set("Orange Scone".to_owned()),
+                note: ActiveValue::not_set(),
+            },
+        ])
+        .validate()
+        .unwrap()
+        .build()
+        .0,
+        r#"INSERT INTO "cake" ("id", "name") VALUES ($1, $2), ($3, $4)"#,
+    );
+}
@@ -0,0 +1,76 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{
+    create_table,
+    entities::{cake, fruit},
+};
+use pgorm::{
+    ConnectionTrait, JoinType, QueryOrder, QuerySelect, QueryTrait,
+    entity::prelude::*,
+    pgorm_query::{Alias, Query},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("join_subquery_tests").await;
+    join_fruit_counts(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn join_fruit_counts(db: &DatabasePool) -> Result<(), DbErr> {
+    create_table(db, cake::Entity).await?;
+    create_table(db, fruit::Entity).await?;
+    db.execute(
+        r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Apple Pie'), (2, 'Fruit Salad'), (3, 'Cheesecake')"#,
+        &[],
+    )
+    .await?;
+    db.execute(
+        r#"INSERT INTO "fruit" ("name", "cake_id") VALUES
+            ('Apple', 1), ('Apple', 2), ('Banana', 2), ('Kiwi', 2), ('Lemon', NULL)"#,
+        &[],
+    )
+    .await?;
+
+    let fruit_counts = Query::select()
+        .column(fruit::Column::CakeId)
+        .expr_as(fruit::Column::Id.count(), Alias::new("fruits"))
+        .from(fruit::Entity)
+        .group_by_col(fruit::Column::CakeId)
+        .to_owned();
+    let query = cake::Entity::find()
+        .select_only()
+        .column(cake::Column::Name)
+        .expr(Expr::col((Alias::new("t"), Alias::new("fruits"))))
+        .join_subquery(
+            JoinType::InnerJoin,
+            fruit_counts,
+            Alias::new("t"),
+            Expr::col((Alias::new("t"), fruit::Column::CakeId))
+                .equals((cake::Entity, cake::Column::Id)),
+        )
+        .order_by_asc(cake::Column::Id);
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT "cake"."name", "t"."fruits" FROM "cake""#,
+            r#"INNER JOIN (SELECT "cake_id", COUNT("fruit"."id") AS "fruits" FROM "fruit" GROUP BY "cake_id") AS "t""#,
+            r#"ON "t"."cake_id" = "cake"."id""#,
+            r#"ORDER BY "cake"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+    let counts: Vec<(String, i64)> = query.into_tuple().all(db).await?;
+    assert_eq!(
+        counts,
+        [("Apple Pie".to_owned(), 1), ("Fruit Salad".to_owned(), 3)]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,102 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::profile;
+use pgorm::{
+    ActiveValue, ConnectionTrait, QueryTrait, Schema, Update,
+    entity::prelude::*,
+    pgorm_query::{QueryBuilder, Values},
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("json_operator_tests").await;
+    update_json(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn update_json(db: &DatabasePool) -> Result<(), DbErr> {
+    use profile::*;
+
+    let conn = db.get().await?;
+    let create = Schema::new()
+        .create_table_from_entity(Entity)
+        .to_string(QueryBuilder);
+    conn.execute(&create, &[]).await?;
+    Entity::insert(ActiveModel {
+        id: ActiveValue::set(1),
+        data: ActiveValue::set(json!({ "name": "ada", "settings": { "theme": "light" } })),
+    })
+    .exec(&&conn)
+    .await?;
+
+    // The server resolves `jsonb || $1` with a jsonb parameter
+    let merge = Update::many(Entity)
+        .col_expr(
+            Column::Data,
+            Column::Data.json_merge(json!({ "lang": "en" })),
+        )
+        .filter(Column::Id.eq(1));
+    assert_eq!(
+        merge.build(),
+        (
+            r#"UPDATE "profile" SET "data" = "profile"."data" || $1 WHERE "profile"."id" = $2"#
+                .to_owned(),
+            Values(vec![json!({ "lang": "en" }).into(), 1i32.into()])
+        )
+    );
+    merge.exec(&&conn).await?;
+    assert_eq!(
+        Entity::find_by_id(1).one(&&conn).await?.data,
+        json!({ "name": "ada", "lang": "en", "settings": { "theme": "light" } })
+    );
+
+    // `jsonb - $1` with a text parameter removes a key
+    let delete_key = Update::many(Entity)
+        .col_expr(Column::Data, Column::Data.json_delete_key("name"))
+        .filter(Column::Id.eq(1));
+    assert_eq!(
+        delete_key.build(),
+        (
+            r#"UPDATE "profile" SET "data" = "profile"."data" - $1 WHERE "profile"."id" = $2"#
+                .to_owned(),
+            Values(vec!["name".into(), 1i32.into()])
+        )
+    );
+    delete_key.exec(&&conn).await?;
+    assert_eq!(
+        Entity::find_by_id(1).one(&&conn).await?.data,
+        json!({ "lang": "en", "settings": { "theme": "light" } })
+    );
+
+    let delete_path = Update::many(Entity)
+        .col_expr(
+            Column::Data,
+            Column::Data.json_delete_path(["settings", "theme"]),
+        )
+        .filter(Column::Id.eq(1));
+    assert_eq!(
+        delete_path.build(),
+        (
+            r#"UPDATE "profile" SET "data" = "profile"."data" #- $1 WHERE "profile"."id" = $2"#
+                .to_owned(),
+            Values(vec![
+                vec!["settings".to_owned(), "theme".to_owned()].into(),
+                1i32.into()
+            ])
+        )
+    );
+    delete_path.exec(&&conn).await?;
+    assert_eq!(
+        Entity::find_by_id(1).one(&&conn).await?.data,
+        json!({ "lang": "en", "settings": {} })
+    );
+
+    Ok(())
+}
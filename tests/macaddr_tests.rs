@@ -0,0 +1,66 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::device;
+use pgorm::{
+    ConnectionTrait, QueryOrder, QuerySelect,
+    entity::prelude::*,
+    pgorm_query::{Func, SimpleExpr},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("macaddr_tests").await;
+    filter_by_mac(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn device_ids(db: &DatabasePool, filter: SimpleExpr) -> Result<Vec<i32>, DbErr> {
+    device::Entity::find()
+        .select_only()
+        .column(device::Column::Id)
+        .filter(filter)
+        .order_by_asc(device::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+}
+
+pub async fn filter_by_mac(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "device" ("id" integer PRIMARY KEY, "mac" macaddr NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    db.execute(
+        r#"INSERT INTO "device" ("id", "mac") VALUES (1, '08:00:2b:01:02:03'), (2, '08:00:2b:ff:00:01'), (3, '00:1a:2b:3c:4d:5e')"#,
+        &[],
+    )
+    .await?;
+
+    let mac = MacAddress::new([0x08, 0x00, 0x2b, 0x01, 0x02, 0x03]);
+    let vendor = MacAddress::new([0x08, 0x00, 0x2b, 0x00, 0x00, 0x00]);
+    let vendor_last = MacAddress::new([0x08, 0x00, 0x2b, 0xff, 0xff, 0xff]);
+
+    assert_eq!(device_ids(db, device::Column::Mac.eq(mac)).await?, [1]);
+    assert_eq!(
+        device_ids(db, device::Column::Mac.between(vendor, vendor_last)).await?,
+        [1, 2]
+    );
+    assert_eq!(device_ids(db, device::Column::Mac.lt(vendor)).await?, [3]);
+    assert_eq!(
+        device_ids(
+            db,
+            Expr::expr(Func::macaddr_trunc(device::Column::Mac.into_expr())).eq(vendor)
+        )
+        .await?,
+        [1, 2]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,107 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::entity::prelude::*;
+use pgorm::{ActiveValue, ConnectionTrait, NotSet, QueryTrait, pgorm_query::Values};
+use pretty_assertions::assert_eq;
+
+mod invoice {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "invoice")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        #[pgorm(column_type = "Money(None)")]
+        pub total: Decimal,
+        #[pgorm(column_type = "Money(None)")]
+        pub discount: Option<Decimal>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("money_tests").await;
+    money_round_trip(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn money_round_trip(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "invoice" ("id" serial PRIMARY KEY, "total" money NOT NULL, "discount" money)"#,
+        &[],
+    )
+    .await?;
+
+    // The text form of `money` depends on `lc_monetary` (currency symbol, group and
+    // decimal separators), so values must survive without going through it.
+    let rows = [
+        (Decimal::new(123456789, 2), Some(Decimal::new(-5, 2))),
+        (Decimal::new(42, 0), None),
+    ];
+    let mut ids = Vec::new();
+    for (total, discount) in rows {
+        let res = invoice::Entity::insert(invoice::ActiveModel {
+            id: NotSet,
+            total: ActiveValue::Set(total),
+            discount: ActiveValue::Set(discount),
+        })
+        .exec(&&conn)
+        .await?;
+        ids.push(res.last_insert_id);
+    }
+
+    assert_eq!(
+        invoice::Entity::find().all(&&conn).await?,
+        vec![
+            invoice::Model {
+                id: ids[0],
+                total: Decimal::new(123456789, 2),
+                discount: Some(Decimal::new(-5, 2)),
+            },
+            invoice::Model {
+                id: ids[1],
+                total: Decimal::new(4200, 2),
+                discount: None,
+            },
+        ]
+    );
+
+    let total = Decimal::new(100, 0);
+    let query = invoice::Entity::find().filter(invoice::Column::Total.gt(total));
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "invoice"."id", CAST("invoice"."total" AS numeric),"#,
+                r#"CAST("invoice"."discount" AS numeric) FROM "invoice""#,
+                r#"WHERE "invoice"."total" > CAST(CAST($1 AS numeric) AS money)"#,
+            ]
+            .join(" "),
+            Values(vec![total.into()])
+        )
+    );
+    assert_eq!(query.all(&&conn).await?.len(), 1);
+
+    let updated = invoice::ActiveModel {
+        id: ActiveValue::Unchanged(ids[1]),
+        total: ActiveValue::Set(Decimal::new(1999, 2)),
+        discount: NotSet,
+    }
+    .update(&&conn)
+    .await?;
+    assert_eq!(updated.total, Decimal::new(1999, 2));
+
+    Ok(())
+}
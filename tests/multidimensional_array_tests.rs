@@ -0,0 +1,68 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ConnectionTrait, DatabasePool, DbErr,
+    pgorm_query::{Alias, ArrayType, Query, QueryBuilder, Value},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("multidimensional_array_tests").await;
+    bind_nested_arrays(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn bind_nested_arrays(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "matrix" ("id" serial PRIMARY KEY, "cells" integer[] NOT NULL)"#,
+        &[],
+    )
+    .await?;
+
+    let with_null = Value::array(
+        ArrayType::Int,
+        vec![
+            Value::array(ArrayType::Int, vec![5.into(), Value::Int(None)]).unwrap(),
+            Value::array(ArrayType::Int, vec![7.into(), 8.into()]).unwrap(),
+        ],
+    )
+    .unwrap();
+    let (sql, values) = Query::insert()
+        .into_table(Alias::new("matrix"))
+        .columns([Alias::new("cells")])
+        .values_panic([vec![vec![1, 2], vec![3, 4]].into()])
+        .values_panic([with_null.into()])
+        .build(QueryBuilder);
+    assert_eq!(conn.execute_values(&sql, values).await?, 2);
+
+    let cells: Vec<String> = conn
+        .query_all(r#"SELECT "cells"::text FROM "matrix" ORDER BY "id""#, &[])
+        .await?
+        .iter()
+        .map(|row| row.get(0))
+        .collect();
+    assert_eq!(cells, ["{{1,2},{3,4}}", "{{5,NULL},{7,8}}"]);
+
+    // A ragged array is rejected before it is sent, leaving the connection usable
+    let (sql, values) = Query::insert()
+        .into_table(Alias::new("matrix"))
+        .columns([Alias::new("cells")])
+        .values_panic([vec![vec![1, 2], vec![3]].into()])
+        .build(QueryBuilder);
+    assert!(conn.execute_values(&sql, values).await.is_err());
+
+    let count: i64 = conn
+        .query_one(r#"SELECT count(*) FROM "matrix""#, &[])
+        .await?
+        .get(0);
+    assert_eq!(count, 2);
+
+    Ok(())
+}
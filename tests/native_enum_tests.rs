@@ -0,0 +1,146 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ActiveValue, ConnectionTrait, FromQueryResult, QueryOrder, QueryTrait, entity::prelude::*,
+    pgorm_query::Values,
+};
+use pretty_assertions::assert_eq;
+
+#[derive(Debug, Clone, PartialEq, Eq, EnumIter, DeriveActiveEnum)]
+#[pgorm(rs_type = "String", db_type = "Enum", enum_name = "tea")]
+pub enum Tea {
+    #[pgorm(string_value = "EverydayTea")]
+    EverydayTea,
+    #[pgorm(string_value = "BreakfastTea")]
+    BreakfastTea,
+}
+
+mod cup {
+    use super::Tea;
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "cup")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub tea: Option<Tea>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("native_enum_tests").await;
+    select_native_enum(&ctx.db).await?;
+    filter_native_enum(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn select_native_enum(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TYPE "tea" AS ENUM ('EverydayTea', 'BreakfastTea')"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"CREATE TABLE "cup" ("id" serial PRIMARY KEY, "tea" "tea")"#,
+        &[],
+    )
+    .await?;
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct TeaRow {
+        tea: Option<Tea>,
+    }
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct TeaLabels {
+        teas: Vec<String>,
+    }
+
+    let model = cup::ActiveModel {
+        tea: ActiveValue::set(Some(Tea::BreakfastTea)),
+        ..Default::default()
+    }
+    .insert(&&conn)
+    .await?;
+    cup::ActiveModel {
+        tea: ActiveValue::set(Some(Tea::EverydayTea)),
+        ..Default::default()
+    }
+    .insert(&&conn)
+    .await?;
+
+    // Selected without a cast, the column comes back as the native `tea` type
+    assert_eq!(
+        TeaRow::find_by_statement(
+            r#"SELECT "tea" FROM "cup" WHERE "id" = $1"#,
+            vec![model.id.into()],
+        )
+        .one(&&conn)
+        .await?,
+        TeaRow {
+            tea: Some(Tea::BreakfastTea),
+        }
+    );
+    assert_eq!(
+        TeaLabels::find_by_statement(
+            r#"SELECT array_agg("tea" ORDER BY "id") AS "teas" FROM "cup""#,
+            vec![],
+        )
+        .one(&&conn)
+        .await?,
+        TeaLabels {
+            teas: vec!["BreakfastTea".to_owned(), "EverydayTea".to_owned()],
+        }
+    );
+    assert_eq!(cup::Entity::find_by_id(model.id).one(&&conn).await?, model);
+
+    Ok(())
+}
+
+pub async fn filter_native_enum(db: &DatabasePool) -> Result<(), DbErr> {
+    let query = cup::Entity::find().filter(cup::Column::Tea.eq(Tea::BreakfastTea));
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "cup"."id", CAST("cup"."tea" AS text) FROM "cup""#,
+                r#"WHERE "cup"."tea" = CAST($1 AS tea)"#,
+            ]
+            .join(" "),
+            Values(vec!["BreakfastTea".into()])
+        )
+    );
+    assert_eq!(query.all(db).await?.len(), 1);
+
+    let query = cup::Entity::find()
+        .filter(cup::Column::Tea.is_in([Tea::EverydayTea, Tea::BreakfastTea]))
+        .order_by_asc(cup::Column::Id);
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "cup"."id", CAST("cup"."tea" AS text) FROM "cup""#,
+                r#"WHERE "cup"."tea" IN (CAST($1 AS tea), CAST($2 AS tea))"#,
+                r#"ORDER BY "cup"."id" ASC"#,
+            ]
+            .join(" "),
+            Values(vec!["EverydayTea".into(), "BreakfastTea".into()])
+        )
+    );
+    assert_eq!(query.all(db).await?.len(), 2);
+
+    Ok(())
+}
@@ -0,0 +1,164 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ConnectionTrait, DatabasePool, DbErr, FromQueryResult, QueryOrder, QueryResult, QuerySelect,
+    entity::prelude::*, pgorm_query::Expr,
+};
+use pretty_assertions::assert_eq;
+
+mod author {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "author")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod book {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "book")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        pub author_id: Option<i32>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[pgorm(
+            belongs_to = "super::author::Entity",
+            from = "Column::AuthorId",
+            to = "super::author::Column::Id"
+        )]
+        Author,
+    }
+
+    impl Related<super::author::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::Author.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct Author {
+    author_name: String,
+    pen_name: Option<String>,
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct BookWithAuthor {
+    title: String,
+    #[pgorm(nested)]
+    author: Option<Author>,
+}
+
+#[derive(Debug, PartialEq, FromQueryResult)]
+struct Skipped {
+    #[pgorm(skip)]
+    note: Option<String>,
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("nested_query_result_tests").await;
+    left_join_nested(&ctx.db).await?;
+    optional_results(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn left_join_nested(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "author" ("id" integer PRIMARY KEY, "name" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"CREATE TABLE "book" ("id" integer PRIMARY KEY, "title" text NOT NULL, "author_id" integer REFERENCES "author")"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "author" ("id", "name") VALUES (1, 'Ursula')"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "book" ("id", "title", "author_id") VALUES (1, 'Earthsea', 1), (2, 'Beowulf', NULL)"#,
+        &[],
+    )
+    .await?;
+
+    let books = book::Entity::find()
+        .select_only()
+        .column(book::Column::Title)
+        .column_as(author::Column::Name, "author_name")
+        .column_as(Expr::val(Option::<String>::None), "pen_name")
+        .left_join(author::Entity)
+        .order_by_asc(book::Column::Id)
+        .into_model::<BookWithAuthor>()
+        .all(&&conn)
+        .await?;
+
+    assert_eq!(
+        books,
+        [
+            BookWithAuthor {
+                title: "Earthsea".to_owned(),
+                author: Some(Author {
+                    author_name: "Ursula".to_owned(),
+                    pen_name: None,
+                }),
+            },
+            BookWithAuthor {
+                title: "Beowulf".to_owned(),
+                author: None,
+            },
+        ]
+    );
+
+    Ok(())
+}
+
+pub async fn optional_results(db: &DatabasePool) -> Result<(), DbErr> {
+    let row = db
+        .query_one(
+            r#"SELECT NULL::text AS "author_name", NULL::text AS "pen_name""#,
+            &[],
+        )
+        .await?;
+    let res = QueryResult::from(row);
+    assert_eq!(Author::from_query_result_optional(&res, "")?, None);
+    assert_eq!(
+        Skipped::from_query_result_optional(&res, "")?,
+        Some(Skipped { note: None })
+    );
+
+    let row = db
+        .query_one(r#"SELECT 'Ursula' AS "author_name""#, &[])
+        .await?;
+    let res = QueryResult::from(row);
+    assert!(Author::from_query_result_optional(&res, "").is_err());
+
+    Ok(())
+}
@@ -0,0 +1,83 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::host;
+use pgorm::{
+    ConnectionTrait, QueryOrder, QuerySelect, QueryTrait, entity::prelude::*,
+    pgorm_query::SimpleExpr,
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("network_operator_tests").await;
+    filter_by_network(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn host_ids(db: &DatabasePool, filter: SimpleExpr) -> Result<Vec<i32>, DbErr> {
+    host::Entity::find()
+        .select_only()
+        .column(host::Column::Id)
+        .filter(filter)
+        .order_by_asc(host::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+}
+
+pub async fn filter_by_network(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "host" ("id" integer PRIMARY KEY, "address" inet NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    db.execute(
+        r#"INSERT INTO "host" ("id", "address") VALUES (1, '10.1.2.3'), (2, '192.168.0.1'), (3, '10.0.0.0/8'), (4, '2001:db8::1')"#,
+        &[],
+    )
+    .await?;
+
+    let network: IpNetwork = "10.0.0.0/8".parse().unwrap();
+    let address: IpNetwork = "10.1.2.3".parse().unwrap();
+    let network_v6: IpNetwork = "2001:db8::/32".parse().unwrap();
+
+    assert_eq!(
+        host::Entity::find()
+            .filter(host::Column::Address.subnet_of(network))
+            .filter(host::Column::Address.supernet_of(network).not())
+            .filter(host::Column::Address.network_overlaps(network))
+            .build()
+            .0,
+        [
+            r#"SELECT "host"."id", "host"."address" FROM "host""#,
+            r#"WHERE "host"."address" << $1"#,
+            r#"AND (NOT "host"."address" >> $2)"#,
+            r#"AND ("host"."address" && $3)"#,
+        ]
+        .join(" ")
+    );
+
+    assert_eq!(
+        host_ids(db, host::Column::Address.subnet_of(network)).await?,
+        [1]
+    );
+    assert_eq!(
+        host_ids(db, host::Column::Address.supernet_of(address)).await?,
+        [3]
+    );
+    assert_eq!(
+        host_ids(db, host::Column::Address.network_overlaps(network)).await?,
+        [1, 3]
+    );
+    assert_eq!(
+        host_ids(db, host::Column::Address.subnet_of(network_v6)).await?,
+        [4]
+    );
+
+    Ok(())
+}
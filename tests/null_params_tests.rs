@@ -0,0 +1,129 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::entity::prelude::*;
+use pgorm::{ActiveValue, ConnectionTrait, NotSet, PaginatorTrait, QueryTrait, pgorm_query::Expr};
+use pretty_assertions::assert_eq;
+
+mod reading {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "reading")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub value: Option<i32>,
+        pub note: Option<String>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod clip {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "clip")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        #[pgorm(column_type = "Interval(None, None)")]
+        pub length: Option<String>,
+        #[pgorm(column_type = "Integer")]
+        pub frames: Option<i64>,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("null_params_tests").await;
+    insert_null_columns(&ctx.db).await?;
+    insert_null_into_other_column_types(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_null_columns(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "reading" ("id" serial PRIMARY KEY, "value" integer, "note" text)"#,
+        &[],
+    )
+    .await?;
+
+    let res = reading::Entity::insert(reading::ActiveModel {
+        id: NotSet,
+        value: ActiveValue::Set(None),
+        note: ActiveValue::Set(None),
+    })
+    .exec(&&conn)
+    .await?;
+
+    assert_eq!(
+        reading::Entity::find_by_id(res.last_insert_id)
+            .one(&&conn)
+            .await?,
+        reading::Model {
+            id: res.last_insert_id,
+            value: None,
+            note: None,
+        }
+    );
+
+    // Nothing in `$1 IS NULL` tells the server the type of `$1`
+    assert_eq!(
+        reading::Entity::find()
+            .filter(Expr::val(Option::<i32>::None).is_null())
+            .all(&&conn)
+            .await?
+            .len(),
+        1
+    );
+
+    Ok(())
+}
+
+pub async fn insert_null_into_other_column_types(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "clip" ("id" serial PRIMARY KEY, "length" interval, "frames" integer)"#,
+        &[],
+    )
+    .await?;
+
+    // A text `NULL` is typed by the server from the column, and a `NULL` of any other type
+    // is cast to the type of its column
+    let insert = clip::Entity::insert(clip::ActiveModel {
+        id: NotSet,
+        length: ActiveValue::Set(None),
+        frames: ActiveValue::Set(None),
+    });
+    assert_eq!(
+        insert.build().0,
+        r#"INSERT INTO "clip" ("length", "frames") VALUES ($1, CAST($2 AS integer))"#
+    );
+    insert.exec(db).await?;
+
+    // Reading an `interval` back as a `String` isn't supported
+    assert_eq!(
+        clip::Entity::find()
+            .filter(clip::Column::Length.is_null())
+            .filter(clip::Column::Frames.is_null())
+            .count(db)
+            .await?,
+        1
+    );
+
+    Ok(())
+}
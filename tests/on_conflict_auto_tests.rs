@@ -0,0 +1,111 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::{account, person};
+use pgorm::{ActiveValue, ConnectionTrait, QueryOrder, QueryTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("on_conflict_auto_tests").await;
+    upsert_on_unique_column(&ctx.db).await?;
+    upsert_on_primary_key(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn upsert_on_unique_column(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "account" ("id" serial PRIMARY KEY, "email" text NOT NULL UNIQUE, "name" text NOT NULL DEFAULT '', "deleted_at" timestamptz)"#,
+        &[],
+    )
+    .await?;
+
+    let account = |name: &str| account::ActiveModel {
+        email: ActiveValue::set("alice@example.com".to_owned()),
+        name: ActiveValue::set(name.to_owned()),
+        ..Default::default()
+    };
+
+    let insert = account::Entity::insert(account("Alice")).on_conflict_auto();
+    assert_eq!(
+        insert.build().0,
+        [
+            r#"INSERT INTO "account" ("email", "name") VALUES ($1, $2)"#,
+            r#"ON CONFLICT ("email") DO UPDATE SET "name" = "excluded"."name""#,
+        ]
+        .join(" ")
+    );
+    insert.exec(&&conn).await?;
+    // The same email conflicts and renames the existing account
+    account::Entity::insert(account("Alice Liddell"))
+        .on_conflict_auto()
+        .exec(&&conn)
+        .await?;
+
+    let accounts = account::Entity::find()
+        .order_by_asc(account::Column::Id)
+        .all(&&conn)
+        .await?;
+    assert_eq!(
+        accounts,
+        [account::Model {
+            id: 1,
+            email: "alice@example.com".to_owned(),
+            name: "Alice Liddell".to_owned(),
+            deleted_at: None,
+        }]
+    );
+
+    // Only the conflict target is inserted, so there is nothing to update
+    let res = account::Entity::insert(account::ActiveModel {
+        email: ActiveValue::set("alice@example.com".to_owned()),
+        ..Default::default()
+    })
+    .on_conflict_auto()
+    .exec(&&conn)
+    .await;
+    assert_eq!(res.err(), Some(DbErr::RecordNotInserted));
+
+    Ok(())
+}
+
+pub async fn upsert_on_primary_key(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "person" ("id" integer PRIMARY KEY, "first_name" text NOT NULL, "last_name" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+
+    let person = |last_name: &str| person::ActiveModel {
+        id: ActiveValue::set(1),
+        first_name: ActiveValue::set("Ada".to_owned()),
+        last_name: ActiveValue::set(last_name.to_owned()),
+    };
+
+    // Without a unique column the primary key is the conflict target
+    person::Entity::insert(person("Byron"))
+        .on_conflict_auto()
+        .exec(&&conn)
+        .await?;
+    person::Entity::insert(person("Lovelace"))
+        .on_conflict_auto()
+        .exec(&&conn)
+        .await?;
+
+    assert_eq!(
+        person::Entity::find().all(&&conn).await?,
+        [person::Model {
+            id: 1,
+            first_name: "Ada".to_owned(),
+            last_name: "Lovelace".to_owned(),
+        }]
+    );
+
+    Ok(())
+}
@@ -0,0 +1,77 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::account;
+use pgorm::{
+    ActiveValue, ConnectionTrait, QueryOrder, QueryTrait, entity::prelude::*,
+    pgorm_query::OnConflict,
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("on_conflict_partial_index_tests").await;
+    upsert_on_partial_index(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn upsert_on_partial_index(db: &DatabasePool) -> Result<(), DbErr> {
+    use account::*;
+
+    let conn = db.get().await?;
+    // An email is only unique among the accounts that aren't deleted
+    conn.execute(
+        r#"CREATE TABLE "account" ("id" serial PRIMARY KEY, "email" text NOT NULL, "name" text NOT NULL, "deleted_at" timestamptz)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"CREATE UNIQUE INDEX "account_email" ON "account" ("email") WHERE "deleted_at" IS NULL"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "account" ("email", "name", "deleted_at") VALUES
+            ('alice@example.com', 'Deleted', now()),
+            ('alice@example.com', 'Alice', NULL)"#,
+        &[],
+    )
+    .await?;
+
+    let insert = Entity::insert(ActiveModel {
+        email: ActiveValue::set("alice@example.com".to_owned()),
+        name: ActiveValue::set("Alice Liddell".to_owned()),
+        ..Default::default()
+    })
+    .on_conflict(
+        OnConflict::column(Column::Email)
+            .target_and_where(Expr::col(Column::DeletedAt).is_null())
+            .update_column(Column::Name)
+            .to_owned(),
+    );
+    assert_eq!(
+        insert.build().0,
+        [
+            r#"INSERT INTO "account" ("email", "name") VALUES ($1, $2)"#,
+            r#"ON CONFLICT ("email") WHERE "deleted_at" IS NULL"#,
+            r#"DO UPDATE SET "name" = "excluded"."name""#,
+        ]
+        .join(" ")
+    );
+    insert.exec(&&conn).await?;
+
+    let names: Vec<String> = Entity::find()
+        .order_by_asc(Column::Id)
+        .all(&&conn)
+        .await?
+        .into_iter()
+        .map(|account| account.name)
+        .collect();
+    assert_eq!(names, ["Deleted", "Alice Liddell"]);
+
+    Ok(())
+}
@@ -0,0 +1,105 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::cake};
+use pgorm::{
+    ConnectionTrait, QueryOrder, QuerySelect, QueryTrait,
+    entity::prelude::*,
+    pgorm_query::{NullOrdering, Order, Values},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("order_tests").await;
+    create_table(&ctx.db, cake::Entity).await?;
+    ctx.db
+        .execute(
+            r#"INSERT INTO "cake" ("id", "name") VALUES
+                (1, 'cheese'), (2, 'apple'), (3, 'cheese'), (4, 'lemon')"#,
+            &[],
+        )
+        .await?;
+    reverse_order(&ctx.db).await?;
+    order_by_expr(&ctx.db).await?;
+    order_by_field(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn cake_ids(db: &DatabasePool, query: Select<cake::Entity>) -> Result<Vec<i32>, DbErr> {
+    query
+        .select_only()
+        .column(cake::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+}
+
+pub async fn reverse_order(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    let query = Entity::find()
+        .order_by_asc(Column::Name)
+        .order_by_desc(Column::Id);
+    assert_eq!(cake_ids(db, query.clone()).await?, [2, 3, 1, 4]);
+
+    let query = query.reverse_order();
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+            r#"ORDER BY "cake"."name" DESC, "cake"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(cake_ids(db, query).await?, [4, 1, 3, 2]);
+
+    Ok(())
+}
+
+pub async fn order_by_expr(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    let query = Entity::find()
+        .order_by_expr(Func::upper(Expr::col(Column::Name)).into(), Order::Desc)
+        .order_by_expr_with_nulls(Expr::col(Column::Id).mul(2), Order::Asc, NullOrdering::Last);
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "cake"."id", "cake"."name" FROM "cake""#,
+                r#"ORDER BY UPPER("name") DESC, "id" * $1 ASC NULLS LAST"#,
+            ]
+            .join(" "),
+            Values(vec![2.into()])
+        )
+    );
+    assert_eq!(cake_ids(db, query).await?, [4, 1, 3, 2]);
+
+    Ok(())
+}
+
+pub async fn order_by_field(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    // The listed values come first, in the given order, then everything else
+    let query = Entity::find()
+        .order_by_field(Column::Name, ["lemon", "cheese"])
+        .order_by_asc(Column::Id);
+    assert_eq!(
+        query.build().0,
+        [
+            r#"SELECT "cake"."id", "cake"."name" FROM "cake" ORDER BY"#,
+            r#"CASE WHEN "cake"."name"='lemon' THEN 0 WHEN "cake"."name"='cheese' THEN 1 ELSE 2 END,"#,
+            r#""cake"."id" ASC"#,
+        ]
+        .join(" ")
+    );
+    assert_eq!(cake_ids(db, query).await?, [4, 1, 3, 2]);
+
+    Ok(())
+}
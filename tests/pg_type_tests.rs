@@ -0,0 +1,110 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ConnectionTrait, DbErr, column_type_to_pg_type,
+    pgorm_query::{Alias, ColumnDef, ColumnType, QueryBuilder, RcOrArc, SeaRc, StringLen, Table},
+};
+use pretty_assertions::assert_eq;
+use tokio_postgres::types::Type;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("pg_type_tests").await;
+    column_type_to_pg_type_mapping(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+/// Every column type with a built-in Postgres type maps to the type the schema builder
+/// creates the column with
+pub async fn column_type_to_pg_type_mapping(db: &pgorm::DatabasePool) -> Result<(), DbErr> {
+    let column_types = [
+        ColumnType::Char(Some(3)),
+        ColumnType::String(StringLen::N(255)),
+        ColumnType::Text,
+        ColumnType::Blob,
+        ColumnType::Binary(16),
+        ColumnType::VarBinary(StringLen::N(16)),
+        ColumnType::TinyInteger,
+        ColumnType::SmallInteger,
+        ColumnType::Integer,
+        ColumnType::BigInteger,
+        ColumnType::TinyUnsigned,
+        ColumnType::SmallUnsigned,
+        ColumnType::Unsigned,
+        ColumnType::BigUnsigned,
+        ColumnType::Float,
+        ColumnType::Double,
+        ColumnType::Decimal(Some((16, 4))),
+        ColumnType::DateTime,
+        ColumnType::Timestamp,
+        ColumnType::TimestampWithTimeZone,
+        ColumnType::Time,
+        ColumnType::Date,
+        ColumnType::Year,
+        ColumnType::Interval(None, None),
+        ColumnType::Bit(Some(8)),
+        ColumnType::VarBit(8),
+        ColumnType::Boolean,
+        ColumnType::Money(None),
+        ColumnType::Json,
+        ColumnType::JsonBinary,
+        ColumnType::Uuid,
+        ColumnType::Cidr,
+        ColumnType::Inet,
+        ColumnType::MacAddr,
+        ColumnType::Array(RcOrArc::new(ColumnType::Integer)),
+        ColumnType::Array(RcOrArc::new(ColumnType::Inet)),
+    ];
+
+    let mut create = Table::create();
+    create.table(Alias::new("typed"));
+    for (i, column_type) in column_types.iter().enumerate() {
+        create.col(ColumnDef::new_with_type(
+            Alias::new(format!("col_{i}")),
+            column_type.clone(),
+        ));
+    }
+    let conn = db.get().await?;
+    conn.execute(&create.to_string(QueryBuilder), &[]).await?;
+
+    let rows = conn
+        .query_all(
+            r#"SELECT atttypid FROM pg_attribute WHERE attrelid = '"typed"'::regclass AND attnum > 0 ORDER BY attnum"#,
+            &[],
+        )
+        .await?;
+    assert_eq!(rows.len(), column_types.len());
+    for (column_type, row) in column_types.iter().zip(rows) {
+        let oid: u32 = row.get(0);
+        assert_eq!(
+            column_type_to_pg_type(column_type),
+            Type::from_oid(oid).unwrap(),
+            "{column_type:?}"
+        );
+    }
+
+    // Extension, custom and enum types have no fixed OID
+    for column_type in [
+        ColumnType::Vector(Some(3)),
+        ColumnType::LTree,
+        ColumnType::Array(RcOrArc::new(ColumnType::LTree)),
+        ColumnType::Custom(SeaRc::new(Alias::new("citext"))),
+        ColumnType::Enum {
+            name: SeaRc::new(Alias::new("mood")),
+            variants: Vec::new(),
+        },
+    ] {
+        assert_eq!(
+            column_type_to_pg_type(&column_type),
+            Type::UNKNOWN,
+            "{column_type:?}"
+        );
+    }
+
+    Ok(())
+}
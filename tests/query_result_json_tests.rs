@@ -0,0 +1,223 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ConnectionTrait, DatabasePool, DbErr, QueryOrder, QueryResult, QuerySelect, entity::prelude::*,
+};
+use pretty_assertions::assert_eq;
+use serde_json::json;
+use tokio_postgres::types::Type;
+
+mod cake {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, DeriveEntityModel)]
+    #[pgorm(table_name = "cake")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        pub price: f64,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("query_result_json_tests").await;
+    rows_into_json(&ctx.db).await?;
+    rows_of_any_type_into_json(&ctx.db).await?;
+    select_into_json(&ctx.db).await?;
+    get_json_path(&ctx.db).await?;
+    column_metadata(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn rows_into_json(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    let rows = conn
+        .query_all(
+            r#"SELECT
+                1::int4 AS "id",
+                'Apple Pie'::varchar AS "name",
+                true AS "gluten_free",
+                2.5::float8 AS "price",
+                NULL::int8 AS "batch",
+                '{"layers": 3}'::jsonb AS "meta",
+                DATE '2024-03-01' AS "baked_on""#,
+            &[],
+        )
+        .await?;
+
+    let json = rows
+        .into_iter()
+        .map(|row| QueryResult::from(row).into_json())
+        .collect::<Result<Vec<_>, _>>()?;
+
+    assert_eq!(
+        json,
+        [json!({
+            "id": 1,
+            "name": "Apple Pie",
+            "gluten_free": true,
+            "price": 2.5,
+            "batch": null,
+            "meta": { "layers": 3 },
+            "baked_on": "2024-03-01",
+        })]
+    );
+
+    Ok(())
+}
+
+pub async fn rows_of_any_type_into_json(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(r#"CREATE TYPE "mood" AS ENUM ('happy', 'sad')"#, &[])
+        .await?;
+    conn.execute(r#"CREATE DOMAIN "score" AS integer"#, &[])
+        .await?;
+
+    let row = conn
+        .query_one(
+            r#"SELECT
+                'happy'::"mood" AS "mood",
+                ARRAY['sad', 'happy']::"mood"[] AS "moods",
+                '\x00ff10'::bytea AS "data",
+                ARRAY[1, NULL, 3]::int4[] AS "ids",
+                ARRAY[ARRAY[1, 2], ARRAY[3, 4]]::int4[] AS "grid",
+                '{}'::text[] AS "empty",
+                '10.0.0.1'::inet AS "host",
+                '10.0.0.0/8'::inet AS "subnet",
+                '10.0.0.0/8'::cidr AS "network",
+                '08:00:2b:01:02:03'::macaddr AS "mac",
+                7::"score" AS "score",
+                '<cake/>'::xml AS "xml",
+                TIMESTAMPTZ '2024-03-01 12:30:00+00' AS "baked_at",
+                INTERVAL '1 day'::text AS "shelf_life""#,
+            &[],
+        )
+        .await?;
+
+    assert_eq!(
+        QueryResult::from(row).into_json()?,
+        json!({
+            "mood": "happy",
+            "moods": ["sad", "happy"],
+            "data": "AP8Q",
+            "ids": [1, null, 3],
+            "grid": [[1, 2], [3, 4]],
+            "empty": [],
+            "host": "10.0.0.1",
+            "subnet": "10.0.0.0/8",
+            "network": "10.0.0.0/8",
+            "mac": "08:00:2b:01:02:03",
+            "score": 7,
+            "xml": "<cake/>",
+            "baked_at": "2024-03-01T12:30:00+00:00",
+            "shelf_life": "1 day",
+        })
+    );
+
+    let row = conn
+        .query_one(r#"SELECT INTERVAL '1 day' AS "shelf_life""#, &[])
+        .await?;
+    let err = QueryResult::from(row).into_json().unwrap_err();
+    assert!(
+        err.to_string()
+            .contains("Cannot read type interval into JSON")
+    );
+
+    Ok(())
+}
+
+pub async fn select_into_json(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "cake" ("id" integer PRIMARY KEY, "name" text NOT NULL, "price" double precision NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "cake" ("id", "name", "price") VALUES (1, 'Apple Pie', 2.5), (2, 'Lemon Tart', 4.0)"#,
+        &[],
+    )
+    .await?;
+
+    let cakes = cake::Entity::find()
+        .order_by_asc(cake::Column::Id)
+        .into_json()
+        .all(&&conn)
+        .await?;
+    assert_eq!(
+        cakes,
+        [
+            json!({ "id": 1, "name": "Apple Pie", "price": 2.5 }),
+            json!({ "id": 2, "name": "Lemon Tart", "price": 4.0 }),
+        ]
+    );
+
+    let cakes = cake::Entity::find()
+        .select_only()
+        .column_as(cake::Column::Name, "title")
+        .column_as(cake::Column::Price.into_expr().mul(2.0), "double_price")
+        .filter(cake::Column::Id.eq(2))
+        .into_json()
+        .all(&&conn)
+        .await?;
+    assert_eq!(
+        cakes,
+        [json!({ "title": "Lemon Tart", "double_price": 8.0 })]
+    );
+
+    Ok(())
+}
+
+pub async fn get_json_path(db: &DatabasePool) -> Result<(), DbErr> {
+    let row = db
+        .query_one(
+            r#"SELECT '{"size": {"layers": 3, "tiers": [10, 20]}, "name": "Apple Pie"}'::jsonb AS "meta""#,
+            &[],
+        )
+        .await?;
+    let res = QueryResult::from(row);
+
+    let layers: i32 = res.try_get_json_path("meta", &["size", "layers"])?;
+    assert_eq!(layers, 3);
+    let tier: i64 = res.try_get_json_path("meta", &["size", "tiers", "1"])?;
+    assert_eq!(tier, 20);
+    let name: String = res.try_get_json_path("meta", &["name"])?;
+    assert_eq!(name, "Apple Pie");
+
+    let missing: Option<i32> = res.try_get_json_path("meta", &["size", "width"])?;
+    assert_eq!(missing, None);
+    assert!(
+        res.try_get_json_path::<i32>("meta", &["size", "width"])
+            .is_err()
+    );
+    assert!(res.try_get_json_path::<i32>("meta", &["name"]).is_err());
+
+    Ok(())
+}
+
+pub async fn column_metadata(db: &DatabasePool) -> Result<(), DbErr> {
+    let row = db
+        .query_one(
+            r#"SELECT 1::int8 AS "id", 'Apple Pie'::text AS "name""#,
+            &[],
+        )
+        .await?;
+    let res = QueryResult::from(row);
+
+    assert_eq!(res.column_names(), ["id", "name"]);
+    assert_eq!(res.column_types(), [Type::INT8, Type::TEXT]);
+
+    Ok(())
+}
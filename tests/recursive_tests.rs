@@ -0,0 +1,58 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::category;
+use pgorm::ConnectionTrait;
+use pgorm::entity::prelude::*;
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("recursive_tests").await;
+    category_descendants(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn category_descendants(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "category" ("id" integer PRIMARY KEY, "name" text NOT NULL, "parent_id" integer)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "category" ("id", "name", "parent_id") VALUES
+            (1, 'Books', NULL), (2, 'Fiction', 1), (3, 'Poetry', 1), (4, 'Sci-fi', 2),
+            (5, 'Music', NULL), (6, 'Loop A', 7), (7, 'Loop B', 6)"#,
+        &[],
+    )
+    .await?;
+
+    let names = |models: Vec<category::Model>| {
+        models
+            .into_iter()
+            .map(|model| model.name)
+            .collect::<Vec<_>>()
+    };
+
+    let descendants = category::Entity::find()
+        .filter(category::Column::Name.eq("Books"))
+        .with_recursive(category::Relation::Parent.def())
+        .all(&&conn)
+        .await?;
+    assert_eq!(names(descendants), ["Books", "Fiction", "Sci-fi", "Poetry"]);
+
+    // Each category of the loop is its own descendant, but is only returned once
+    let descendants = category::Entity::find()
+        .filter(category::Column::Id.eq(6))
+        .with_recursive(category::Relation::Parent.def())
+        .all(&&conn)
+        .await?;
+    assert_eq!(names(descendants), ["Loop A", "Loop B"]);
+
+    Ok(())
+}
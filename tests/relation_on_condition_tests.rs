@@ -0,0 +1,152 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ConnectionTrait, JoinType, QueryOrder, QuerySelect, QueryTrait,
+    entity::prelude::*,
+    pgorm_query::{Alias, Expr, Values},
+};
+use pretty_assertions::assert_eq;
+
+mod user {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "user")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub name: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[pgorm(
+            has_one = "super::address::Entity",
+            on_condition = r#"|_, right| Expr::col((right, super::address::Column::Kind)).eq("primary")"#
+        )]
+        PrimaryAddress,
+        #[pgorm(
+            has_many = "super::address::Entity",
+            on_condition = r#"Expr::col(super::address::Column::Kind).ne("old")"#
+        )]
+        CurrentAddress,
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+mod address {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "address")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub user_id: i32,
+        pub kind: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {
+        #[pgorm(
+            belongs_to = "super::user::Entity",
+            from = "Column::UserId",
+            to = "super::user::Column::Id"
+        )]
+        User,
+    }
+
+    impl Related<super::user::Entity> for Entity {
+        fn to() -> RelationDef {
+            Relation::User.def()
+        }
+    }
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("relation_on_condition_tests").await;
+    join_on_condition(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn join_on_condition(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "user" ("id" integer PRIMARY KEY, "name" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"CREATE TABLE "address" ("id" integer PRIMARY KEY, "user_id" integer NOT NULL, "kind" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(r#"INSERT INTO "user" VALUES (1, 'ada'), (2, 'alan')"#, &[])
+        .await?;
+    conn.execute(
+        r#"INSERT INTO "address" VALUES (1, 1, 'primary'), (2, 1, 'old'), (3, 2, 'old')"#,
+        &[],
+    )
+    .await?;
+
+    // The closure follows the alias of the joined table
+    let primary = user::Entity::find()
+        .join_as(
+            JoinType::LeftJoin,
+            user::Relation::PrimaryAddress.def(),
+            Alias::new("primary_address"),
+        )
+        .select_only()
+        .column(user::Column::Name)
+        .expr(Expr::col((
+            Alias::new("primary_address"),
+            address::Column::Id,
+        )))
+        .order_by_asc(user::Column::Id);
+    assert_eq!(
+        primary.build(),
+        (
+            [
+                r#"SELECT "user"."name", "primary_address"."id" FROM "user""#,
+                r#"LEFT JOIN "address" AS "primary_address""#,
+                r#"ON "user"."id" = "primary_address"."user_id" AND "primary_address"."kind" = $1"#,
+                r#"ORDER BY "user"."id" ASC"#,
+            ]
+            .join(" "),
+            Values(vec!["primary".into()])
+        )
+    );
+    let rows: Vec<(String, Option<i32>)> = primary.into_tuple().all(&&conn).await?;
+    assert_eq!(
+        rows,
+        [("ada".to_owned(), Some(1)), ("alan".to_owned(), None)]
+    );
+
+    // A plain expression is added to the ON clause as is
+    let current = user::Entity::find()
+        .join(JoinType::InnerJoin, user::Relation::CurrentAddress.def())
+        .select_only()
+        .column(user::Column::Name)
+        .column(address::Column::Id);
+    assert_eq!(
+        current.build().0,
+        [
+            r#"SELECT "user"."name", "address"."id" FROM "user""#,
+            r#"INNER JOIN "address" ON "user"."id" = "address"."user_id" AND "kind" <> $1"#,
+        ]
+        .join(" ")
+    );
+    let rows: Vec<(String, i32)> = current.into_tuple().all(&&conn).await?;
+    assert_eq!(rows, [("ada".to_owned(), 1)]);
+
+    Ok(())
+}
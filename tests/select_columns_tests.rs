@@ -0,0 +1,52 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::item};
+use pgorm::{ConnectionTrait, QuerySelect, QueryTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("select_columns_tests").await;
+    only_and_except_columns(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn only_and_except_columns(db: &DatabasePool) -> Result<(), DbErr> {
+    use item::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "item" ("id", "price", "category_id") VALUES (1, 10, 2)"#,
+        &[],
+    )
+    .await?;
+
+    let query = Entity::find().except_columns([Column::CategoryId]);
+    assert_eq!(
+        query.build().0,
+        r#"SELECT "item"."id", "item"."price" FROM "item""#
+    );
+    let row: (i32, i32) = query.into_tuple().one(db).await?;
+    assert_eq!(row, (1, 10));
+
+    // The columns keep the order they are declared in
+    let query = Entity::find().only_columns([Column::CategoryId, Column::Id]);
+    assert_eq!(
+        query.build().0,
+        r#"SELECT "item"."id", "item"."category_id" FROM "item""#
+    );
+    let row: (i32, i32) = query.into_tuple().one(db).await?;
+    assert_eq!(row, (1, 2));
+
+    assert_eq!(
+        Entity::find().except_columns([]).build().0,
+        Entity::find().build().0
+    );
+
+    Ok(())
+}
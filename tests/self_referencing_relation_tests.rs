@@ -0,0 +1,59 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::employee};
+use pgorm::{
+    ConnectionTrait, JoinType, QuerySelect, QueryTrait,
+    entity::prelude::*,
+    pgorm_query::{Alias, Values},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("self_referencing_relation_tests").await;
+    join_manager(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn join_manager(db: &DatabasePool) -> Result<(), DbErr> {
+    use employee::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "employee" ("id", "name", "manager_id") VALUES
+            (1, 'Alice', NULL), (2, 'Bob', 1), (3, 'Carol', 2)"#,
+        &[],
+    )
+    .await?;
+
+    // The related table is aliased by the relation, so it doesn't clash with the base table
+    let query = Entity::find()
+        .join(JoinType::LeftJoin, Relation::Manager.def())
+        .filter(Expr::col((Alias::new("manager"), Column::Name)).eq("Alice"));
+    assert_eq!(
+        query.build(),
+        (
+            [
+                r#"SELECT "employee"."id", "employee"."name", "employee"."manager_id" FROM "employee""#,
+                r#"LEFT JOIN "employee" AS "manager" ON "employee"."manager_id" = "manager"."id""#,
+                r#"WHERE "manager"."name" = $1"#,
+            ]
+            .join(" "),
+            Values(vec!["Alice".into()])
+        )
+    );
+    let names: Vec<String> = query
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|employee| employee.name)
+        .collect();
+    assert_eq!(names, ["Bob"]);
+
+    Ok(())
+}
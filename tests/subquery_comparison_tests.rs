@@ -0,0 +1,85 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::item};
+use pgorm::{
+    ConnectionTrait, QueryOrder, QuerySelect, QueryTrait,
+    entity::prelude::*,
+    pgorm_query::{Query, Values},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("subquery_comparison_tests").await;
+    create_table(&ctx.db, item::Entity).await?;
+    ctx.db
+        .execute(
+            r#"INSERT INTO "item" ("id", "price", "category_id") VALUES
+                (1, 10, 1), (2, 20, 2), (3, 30, 2), (4, 40, 1)"#,
+            &[],
+        )
+        .await?;
+    compare_with_subquery(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn item_ids(db: &DatabasePool, filter: SimpleExpr) -> Result<Vec<i32>, DbErr> {
+    item::Entity::find()
+        .select_only()
+        .column(item::Column::Id)
+        .filter(filter)
+        .order_by_asc(item::Column::Id)
+        .into_tuple()
+        .all(db)
+        .await
+}
+
+pub async fn compare_with_subquery(db: &DatabasePool) -> Result<(), DbErr> {
+    use item::*;
+
+    let prices = Query::select()
+        .column(Column::Price)
+        .from(Entity)
+        .and_where(Column::CategoryId.eq(2))
+        .to_owned();
+
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Price.gt_all(prices.clone()))
+            .build(),
+        (
+            [
+                r#"SELECT "item"."id", "item"."price", "item"."category_id" FROM "item""#,
+                r#"WHERE "item"."price" > ALL(SELECT "price" FROM "item" WHERE "item"."category_id" = $1)"#,
+            ]
+            .join(" "),
+            Values(vec![2.into()])
+        )
+    );
+    assert_eq!(
+        Entity::find()
+            .filter(Column::Price.eq_any(prices.clone()))
+            .build(),
+        (
+            [
+                r#"SELECT "item"."id", "item"."price", "item"."category_id" FROM "item""#,
+                r#"WHERE "item"."price" = ANY(SELECT "price" FROM "item" WHERE "item"."category_id" = $1)"#,
+            ]
+            .join(" "),
+            Values(vec![2.into()])
+        )
+    );
+
+    assert_eq!(
+        item_ids(db, Column::Price.gt_all(prices.clone())).await?,
+        [4]
+    );
+    assert_eq!(item_ids(db, Column::Price.eq_any(prices)).await?, [2, 3]);
+
+    Ok(())
+}
@@ -0,0 +1,86 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::entities::person;
+use pgorm::{
+    ConnectionTrait, QuerySelect, QueryTrait,
+    entity::prelude::*,
+    pgorm_query::{Query, QueryBuilder, SampleMethod},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("tablesample_tests").await;
+    select_tablesample(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn select_tablesample(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "person" ("id" integer PRIMARY KEY, "first_name" text NOT NULL, "last_name" text NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "person" SELECT i, 'Ada', 'Lovelace' FROM generate_series(1, 100) i"#,
+        &[],
+    )
+    .await?;
+
+    assert_eq!(
+        person::Entity::find()
+            .tablesample_system(10.0)
+            .filter(person::Column::FirstName.starts_with("A"))
+            .limit(5)
+            .build()
+            .0,
+        [
+            r#"SELECT "person"."id", "person"."first_name", "person"."last_name""#,
+            r#"FROM "person" TABLESAMPLE SYSTEM ($1)"#,
+            r#"WHERE "person"."first_name" LIKE $2 LIMIT $3"#,
+        ]
+        .join(" ")
+    );
+
+    let all = person::Entity::find()
+        .tablesample_bernoulli(100.0)
+        .all(&&conn)
+        .await?;
+    assert_eq!(all.len(), 100);
+    let none = person::Entity::find()
+        .tablesample_system(0.0)
+        .all(&&conn)
+        .await?;
+    assert_eq!(none, []);
+
+    let (sql, values) = Query::select()
+        .column(person::Column::Id)
+        .from(person::Entity)
+        .table_sample_repeatable(SampleMethod::Bernoulli, 50.0, 42.0)
+        .build(QueryBuilder);
+    let first = conn.query_all_values(&sql, values.clone()).await?;
+    let second = conn.query_all_values(&sql, values).await?;
+    let ids =
+        |rows: Vec<tokio_postgres::Row>| rows.iter().map(|row| row.get(0)).collect::<Vec<i32>>();
+    assert_eq!(ids(first), ids(second));
+
+    // The percentage is a parameter, so the server rejects NaN and infinity
+    for percentage in [f64::NAN, f64::INFINITY] {
+        let res = person::Entity::find()
+            .tablesample_bernoulli(percentage)
+            .all(&&conn)
+            .await;
+        assert!(
+            matches!(&res, Err(err) if err.to_string().contains("sample percentage must be between 0 and 100")),
+            "{res:?}"
+        );
+    }
+
+    Ok(())
+}
@@ -0,0 +1,65 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ConnectionTrait, TransactionCallback, TransactionError, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("transaction_closure_tests").await;
+    transaction_boxed(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+async fn count_ledger(db: &impl ConnectionTrait) -> Result<i64, DbErr> {
+    let row = db
+        .query_one(r#"SELECT COUNT(*) FROM "ledger""#, &[])
+        .await?;
+    Ok(row.get(0))
+}
+
+fn record(amount: i32) -> TransactionCallback<'static, (), DbErr> {
+    Box::new(move |txn| {
+        Box::pin(async move {
+            txn.execute(r#"INSERT INTO "ledger" ("amount") VALUES ($1)"#, &[&amount])
+                .await?;
+            if amount < 0 {
+                return Err(DbErr::Custom("negative amount".to_owned()));
+            }
+            Ok(())
+        })
+    })
+}
+
+pub async fn transaction_boxed(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(
+        r#"CREATE TABLE "ledger" ("id" serial PRIMARY KEY, "amount" integer NOT NULL)"#,
+        &[],
+    )
+    .await?;
+
+    db.transaction_boxed(record(10))
+        .await
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+    assert_eq!(count_ledger(db).await?, 1);
+
+    // The callback fails after inserting, so the insert is rolled back
+    let res = db.transaction_boxed(record(-10)).await;
+    assert!(matches!(
+        res,
+        Err(TransactionError::Transaction(DbErr::Custom(_)))
+    ));
+    assert_eq!(count_ledger(db).await?, 1);
+
+    let count = db
+        .transaction(|txn| Box::pin(async move { count_ledger(txn).await }))
+        .await
+        .map_err(|e| DbErr::Custom(e.to_string()))?;
+    assert_eq!(count, 1);
+
+    Ok(())
+}
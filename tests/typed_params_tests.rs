@@ -0,0 +1,67 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ConnectionTrait, DatabasePool, DbErr, ValueHolder,
+    pgorm_query::{Value, Values},
+    typed_values,
+    types::{ToSql, Type},
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("typed_params_tests").await;
+    insert_typed_null(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn insert_typed_null(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "reading" ("id" serial PRIMARY KEY, "value" integer, "note" text)"#,
+        &[],
+    )
+    .await?;
+
+    let stmt = r#"INSERT INTO "reading" ("value", "note")
+        SELECT "value", "note" FROM (SELECT $1 AS "value", $2 AS "note") AS "input"
+        RETURNING "id""#;
+    let values = Values(vec![Value::Int(None), Value::String(None)]);
+
+    // Left to the server, both parameters are inferred as text inside the subquery
+    let untyped = values
+        .clone()
+        .into_iter()
+        .map(ValueHolder)
+        .collect::<Vec<_>>();
+    let untyped = untyped
+        .iter()
+        .map(|value| value as &(dyn ToSql + Sync))
+        .collect::<Vec<_>>();
+    assert!(conn.query_all(stmt, &untyped).await.is_err());
+
+    let typed = typed_values(values).collect::<Vec<_>>();
+    assert_eq!(
+        typed.iter().map(|(_, ty)| ty.clone()).collect::<Vec<_>>(),
+        [Type::INT4, Type::TEXT]
+    );
+    let typed = typed
+        .iter()
+        .map(|(value, ty)| (value as &(dyn ToSql + Sync), ty.clone()))
+        .collect::<Vec<_>>();
+    let rows = conn.query_typed(stmt, &typed).await?;
+    assert_eq!(rows.len(), 1);
+
+    let row = conn
+        .query_one(r#"SELECT "value", "note" FROM "reading""#, &[])
+        .await?;
+    assert_eq!(row.get::<_, Option<i32>>(0), None);
+    assert_eq!(row.get::<_, Option<String>>(1), None);
+
+    Ok(())
+}
@@ -0,0 +1,73 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::create_table;
+use pgorm::{ActiveValue, ConnectionTrait, Schema, entity::prelude::*, pgorm_query::QueryBuilder};
+use pretty_assertions::assert_eq;
+
+mod membership {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "membership")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        #[pgorm(unique_key = "uniq-membership-user-org")]
+        pub user_id: i32,
+        #[pgorm(unique_key = "uniq-membership-user-org")]
+        pub org_id: i32,
+        pub role: String,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("unique_key_tests").await;
+    composite_unique_key(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn composite_unique_key(db: &DatabasePool) -> Result<(), DbErr> {
+    use membership::*;
+
+    assert_eq!(
+        Schema::new()
+            .create_table_from_entity(Entity)
+            .to_string(QueryBuilder),
+        [
+            r#"CREATE TABLE "membership" ("#,
+            r#""id" serial NOT NULL PRIMARY KEY,"#,
+            r#""user_id" integer NOT NULL,"#,
+            r#""org_id" integer NOT NULL,"#,
+            r#""role" varchar NOT NULL,"#,
+            r#"CONSTRAINT "uniq-membership-user-org" UNIQUE ("user_id", "org_id")"#,
+            r#")"#,
+        ]
+        .join(" ")
+    );
+    create_table(db, Entity).await?;
+
+    let membership = |user_id: i32, org_id: i32| ActiveModel {
+        user_id: ActiveValue::set(user_id),
+        org_id: ActiveValue::set(org_id),
+        role: ActiveValue::set("member".to_owned()),
+        ..Default::default()
+    };
+    membership(1, 1).insert(db).await?;
+    // Either column may repeat on its own, only the pair is unique
+    membership(1, 2).insert(db).await?;
+    membership(2, 1).insert(db).await?;
+    assert!(membership(1, 1).insert(db).await.is_err());
+
+    Ok(())
+}
@@ -0,0 +1,106 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{
+    create_table,
+    entities::{cake, cake_filling_price},
+};
+use pgorm::{
+    ActiveValue, ConnectionTrait, QueryOrder, QueryTrait, entity::prelude::*, pgorm_query::Values,
+};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("update_by_id_tests").await;
+    update_by_id(&ctx.db).await?;
+    update_by_composite_id(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn update_by_id(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "cake" ("id", "name") VALUES (1, 'Apple'), (2, 'Cheese')"#,
+        &[],
+    )
+    .await?;
+
+    let update = Entity::update_by_id(
+        1,
+        ActiveModel {
+            name: ActiveValue::set("Apple Pie".to_owned()),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        update.build(),
+        (
+            r#"UPDATE "cake" SET "name" = $1 WHERE "cake"."id" = $2"#.to_owned(),
+            Values(vec!["Apple Pie".into(), 1i32.into()])
+        )
+    );
+    assert_eq!(update.exec(db).await?.rows_affected, 1);
+
+    let names: Vec<String> = Entity::find()
+        .order_by_asc(Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|cake| cake.name)
+        .collect();
+    assert_eq!(names, ["Apple Pie", "Cheese"]);
+
+    Ok(())
+}
+
+pub async fn update_by_composite_id(db: &DatabasePool) -> Result<(), DbErr> {
+    use cake_filling_price::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "cake_filling_price" ("cake_id", "filling_id", "price") VALUES
+            (1, 1, 10), (1, 2, 20), (2, 1, 30)"#,
+        &[],
+    )
+    .await?;
+
+    let update = Entity::update_by_id(
+        (1, 2),
+        ActiveModel {
+            price: ActiveValue::set(3),
+            ..Default::default()
+        },
+    );
+    assert_eq!(
+        update.build(),
+        (
+            [
+                r#"UPDATE "cake_filling_price" SET "price" = $1"#,
+                r#"WHERE "cake_filling_price"."cake_id" = $2"#,
+                r#"AND "cake_filling_price"."filling_id" = $3"#,
+            ]
+            .join(" "),
+            Values(vec![3i32.into(), 1i32.into(), 2i32.into()])
+        )
+    );
+    assert_eq!(update.exec(db).await?.rows_affected, 1);
+
+    let prices: Vec<i32> = Entity::find()
+        .order_by_asc(Column::CakeId)
+        .order_by_asc(Column::FillingId)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|price| price.price)
+        .collect();
+    assert_eq!(prices, [10, 3, 30]);
+
+    Ok(())
+}
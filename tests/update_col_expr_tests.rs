@@ -0,0 +1,61 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use common::{create_table, entities::item};
+use pgorm::{ConnectionTrait, QueryOrder, QueryTrait, entity::prelude::*, pgorm_query::Values};
+use pretty_assertions::assert_eq;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("update_col_expr_tests").await;
+    increment_in_place(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn increment_in_place(db: &DatabasePool) -> Result<(), DbErr> {
+    use item::*;
+
+    create_table(db, Entity).await?;
+    db.execute(
+        r#"INSERT INTO "item" ("id", "price", "category_id") VALUES (1, 10, 1), (2, 20, 1)"#,
+        &[],
+    )
+    .await?;
+
+    let update = Entity::update_many()
+        .col_expr(Column::Price, Expr::col(Column::Price).add(1))
+        .filter(Column::Id.eq(1));
+    assert_eq!(
+        update.build(),
+        (
+            r#"UPDATE "item" SET "price" = "price" + $1 WHERE "item"."id" = $2"#.to_owned(),
+            Values(vec![1i32.into(), 1i32.into()])
+        )
+    );
+
+    // Concurrent increments each read the current value, so none of them is lost
+    let increments = (0..3).map(|_| {
+        Entity::update_many()
+            .col_expr(Column::Price, Expr::col(Column::Price).add(1))
+            .filter(Column::Id.eq(1))
+            .exec(db)
+    });
+    for res in futures::future::try_join_all(increments).await? {
+        assert_eq!(res.rows_affected, 1);
+    }
+
+    let prices: Vec<i32> = Entity::find()
+        .order_by_asc(Column::Id)
+        .all(db)
+        .await?
+        .into_iter()
+        .map(|item| item.price)
+        .collect();
+    assert_eq!(prices, [13, 20]);
+
+    Ok(())
+}
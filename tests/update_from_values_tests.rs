@@ -0,0 +1,108 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ConnectionTrait, QueryOrder, QueryTrait, Update, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+mod product {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "product")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub name: String,
+        pub stock: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("update_from_values_tests").await;
+    update_from_values(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn update_from_values(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "product" ("id" integer PRIMARY KEY, "name" text NOT NULL, "stock" integer NOT NULL)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "product" ("id", "name", "stock") VALUES (1, 'a', 0), (2, 'b', 0), (3, 'c', 0), (4, 'd', 0)"#,
+        &[],
+    )
+    .await?;
+
+    let res = product::Entity::update_from_values(
+        product::Column::Id,
+        product::Column::Stock,
+        [(1, 10), (2, 20), (3, 30)],
+    )
+    .exec(&&conn)
+    .await?;
+    assert_eq!(res.rows_affected, 3);
+
+    let stock: Vec<(i32, i32)> = product::Entity::find()
+        .order_by_asc(product::Column::Id)
+        .all(&&conn)
+        .await?
+        .into_iter()
+        .map(|product| (product.id, product.stock))
+        .collect();
+    assert_eq!(stock, [(1, 10), (2, 20), (3, 30), (4, 0)]);
+
+    let res = product::Entity::update_from_values(
+        product::Column::Id,
+        product::Column::Stock,
+        Vec::<(i32, i32)>::new(),
+    )
+    .exec(&&conn)
+    .await?;
+    assert_eq!(res.rows_affected, 0);
+
+    let res = product::Entity::update_from_values(
+        product::Column::Id,
+        product::Column::Stock,
+        Vec::<(i32, i32)>::new(),
+    )
+    .col_expr(product::Column::Stock, Expr::value(99))
+    .exec(&&conn)
+    .await?;
+    assert_eq!(res.rows_affected, 0);
+    assert_eq!(product::Entity::find_by_id(4).one(&&conn).await?.stock, 0);
+
+    Ok(())
+}
+
+#[test]
+fn update_from_values_untyped() {
+    assert_eq!(
+        Update::from_values(
+            product::Entity,
+            product::Column::Id,
+            product::Column::Name,
+            [(1, Value::Vector(None))]
+        )
+        .build()
+        .0,
+        [
+            r#"UPDATE "product" SET "name" = "v"."column2""#,
+            r#"FROM (VALUES (CAST($1 AS int4), $2)) AS "v""#,
+            r#"WHERE "product"."id" = "v"."column1""#,
+        ]
+        .join(" ")
+    );
+}
@@ -0,0 +1,83 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ActiveValue, ConnectionTrait, Update, entity::prelude::*};
+use pretty_assertions::assert_eq;
+
+mod document {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "document")]
+    pub struct Model {
+        #[pgorm(primary_key)]
+        pub id: i32,
+        pub title: String,
+        pub revision: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("update_returning_tests").await;
+    update_one_with_returning(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn update_one_with_returning(db: &DatabasePool) -> Result<(), DbErr> {
+    let conn = db.get().await?;
+    conn.execute(
+        r#"CREATE TABLE "document" ("id" integer PRIMARY KEY, "title" text NOT NULL, "revision" integer NOT NULL DEFAULT 1)"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"CREATE FUNCTION "bump_revision"() RETURNS trigger AS $$
+        BEGIN
+            NEW."revision" := OLD."revision" + 1;
+            RETURN NEW;
+        END;
+        $$ LANGUAGE plpgsql"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"CREATE TRIGGER "document_revision" BEFORE UPDATE ON "document"
+            FOR EACH ROW EXECUTE FUNCTION "bump_revision"()"#,
+        &[],
+    )
+    .await?;
+    conn.execute(
+        r#"INSERT INTO "document" ("id", "title") VALUES (1, 'Draft')"#,
+        &[],
+    )
+    .await?;
+
+    let updated = Update::one(document::ActiveModel {
+        id: ActiveValue::unchanged(1),
+        title: ActiveValue::set("Final".to_owned()),
+        revision: ActiveValue::not_set(),
+    })
+    .exec(&&conn)
+    .await?;
+
+    assert_eq!(
+        updated,
+        document::Model {
+            id: 1,
+            title: "Final".to_owned(),
+            revision: 2,
+        }
+    );
+
+    Ok(())
+}
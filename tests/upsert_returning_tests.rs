@@ -0,0 +1,91 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{
+    ActiveValue, ConnectionTrait, FromQueryResult, entity::prelude::*,
+    pgorm_query::{Expr, OnConflict},
+};
+use pretty_assertions::assert_eq;
+
+mod counter {
+    use pgorm::entity::prelude::*;
+
+    #[derive(Clone, Debug, PartialEq, Eq, DeriveEntityModel)]
+    #[pgorm(table_name = "counter")]
+    pub struct Model {
+        #[pgorm(primary_key, auto_increment = false)]
+        pub id: i32,
+    }
+
+    #[derive(Copy, Clone, Debug, EnumIter, DeriveRelation)]
+    pub enum Relation {}
+
+    impl ActiveModelBehavior for ActiveModel {}
+}
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("upsert_returning_tests").await;
+    upsert_returning_inserted(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+pub async fn upsert_returning_inserted(db: &DatabasePool) -> Result<(), DbErr> {
+    use counter::*;
+
+    let conn = db.get().await?;
+    conn.execute(r#"CREATE TABLE "counter" ("id" integer PRIMARY KEY)"#, &[])
+        .await?;
+
+    #[derive(Debug, PartialEq, FromQueryResult)]
+    struct Upserted {
+        id: i32,
+        inserted: bool,
+    }
+
+    let upsert = || {
+        Entity::insert(ActiveModel {
+            id: ActiveValue::set(10),
+        })
+        .on_conflict(
+            OnConflict::column(Column::Id)
+                .update_column(Column::Id)
+                .to_owned(),
+        )
+        .returning_expr(Expr::cust("(xmax = 0) AS inserted"))
+    };
+
+    assert_eq!(
+        upsert()
+            .exec_with_returning_as::<Upserted, _>(&&conn)
+            .await?,
+        Upserted {
+            id: 10,
+            inserted: true,
+        }
+    );
+    assert_eq!(
+        upsert()
+            .exec_with_returning_as::<Upserted, _>(&&conn)
+            .await?,
+        Upserted {
+            id: 10,
+            inserted: false,
+        }
+    );
+
+    assert!(matches!(
+        upsert().exec(&&conn).await,
+        Err(DbErr::Validation(_))
+    ));
+    assert!(matches!(
+        upsert().exec_with_returning(&&conn).await,
+        Err(DbErr::Validation(_))
+    ));
+
+    Ok(())
+}
@@ -0,0 +1,70 @@
+#![allow(unused_imports, dead_code)]
+
+pub mod common;
+
+pub use common::TestContext;
+use pgorm::{ConnectionTrait, TransactionTrait, entity::prelude::*};
+use pretty_assertions::assert_eq;
+use tokio_postgres::SimpleQueryMessage;
+
+#[tokio::test]
+async fn main() -> Result<(), DbErr> {
+    let ctx = TestContext::new("with_client_tests").await;
+    simple_query_with_client(&ctx.db).await?;
+    with_client_in_transaction(&ctx.db).await?;
+    ctx.delete().await;
+
+    Ok(())
+}
+
+fn first_value(messages: &[SimpleQueryMessage]) -> Option<String> {
+    messages.iter().find_map(|message| match message {
+        SimpleQueryMessage::Row(row) => row.get(0).map(ToOwned::to_owned),
+        _ => None,
+    })
+}
+
+pub async fn simple_query_with_client(db: &DatabasePool) -> Result<(), DbErr> {
+    let messages = db
+        .with_client(|client| {
+            Box::pin(async move { Ok(client.simple_query("SELECT 1 + 1").await?) })
+        })
+        .await?;
+    assert_eq!(first_value(&messages).as_deref(), Some("2"));
+
+    // The connection went back to the pool once the callback finished
+    assert_eq!(db.status().available, db.status().size);
+
+    Ok(())
+}
+
+pub async fn with_client_in_transaction(db: &DatabasePool) -> Result<(), DbErr> {
+    db.execute(r#"CREATE TABLE "counter" ("n" integer NOT NULL)"#, &[])
+        .await?;
+
+    let mut conn = db.get().await?;
+    let txn = conn.begin().await?;
+    txn.with_client(|client| {
+        Box::pin(async move {
+            client
+                .simple_query(r#"INSERT INTO "counter" VALUES (1)"#)
+                .await?;
+            Ok(())
+        })
+    })
+    .await?;
+    txn.rollback().await?;
+
+    let messages = conn
+        .with_client(|client| {
+            Box::pin(async move {
+                Ok(client
+                    .simple_query(r#"SELECT count(*) FROM "counter""#)
+                    .await?)
+            })
+        })
+        .await?;
+    assert_eq!(first_value(&messages).as_deref(), Some("0"));
+
+    Ok(())
+}